@@ -0,0 +1,296 @@
+use anyhow::{anyhow, Result};
+use reqwest::Response;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// On-disk cache of API responses that no longer change (e.g. boxscores of finished games).
+///
+/// Entries are the raw response bodies, each stored under the SHA-256 of its URL (see
+/// `entry_name`). Once they grow past `max_bytes`, the least recently used
+/// entries (oldest modification time; reads refresh it) are removed. The directory is whatever the
+/// user configured, so only files named like the cache's own entries are ever counted or removed.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+impl DiskCache {
+    /// Create a cache in `dir` holding at most `max_bytes`
+    pub fn new(dir: impl Into<PathBuf>, max_bytes: u64) -> Self {
+        Self { dir: dir.into(), max_bytes }
+    }
+
+    /// Create a cache using the configured directory and size cap, if a directory is configured
+    pub fn from_config() -> Option<Self> {
+        let config = crate::config::get_config();
+        let dir = config.cache_dir.as_ref()?;
+        Some(Self::new(dir, config.cache_max_mb * 1024 * 1024))
+    }
+
+    /// Directory the cache stores its entries in
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Read the response stored for `url`, marking it as recently used. Missing or unreadable
+    /// entries are a miss.
+    pub fn get(&self, url: &str) -> Option<String> {
+        let path = self.dir.join(entry_name(url));
+        let contents = fs::read_to_string(&path).ok()?;
+        if let Ok(file) = File::options().write(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(contents)
+    }
+
+    /// Store the response for `url`, then evict the least recently used entries if the cache is
+    /// over its cap
+    pub fn put(&self, url: &str, contents: &str) -> Result<()> {
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| anyhow!("Failed to create cache directory {}: {}", self.dir.display(), e))?;
+        let path = self.dir.join(entry_name(url));
+        fs::write(&path, contents).map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+        self.evict()
+    }
+
+    /// Remove every entry, returning how many were removed. Other files in the directory are left
+    /// alone, and a missing directory is already empty.
+    pub fn clear(&self) -> Result<usize> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(anyhow!("Failed to read cache directory {}: {}", self.dir.display(), e)),
+        };
+
+        let mut removed = 0;
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_file() && path.file_name().and_then(|name| name.to_str()).is_some_and(is_entry) {
+                fs::remove_file(&path).map_err(|e| anyhow!("Failed to remove {}: {}", path.display(), e))?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    // Remove the oldest entries until the total size is within the cap
+    fn evict(&self) -> Result<()> {
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if metadata.is_file() && entry.file_name().to_str().is_some_and(is_entry) {
+                entries.push((metadata.modified()?, metadata.len(), entry.path()));
+            }
+        }
+
+        let mut total: u64 = entries.iter().map(|(_, len, _)| len).sum();
+        entries.sort();
+        for (_, len, path) in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            fs::remove_file(&path)?;
+            total -= len;
+        }
+        Ok(())
+    }
+}
+
+/// File name the response for `url` is stored under: `{sha256(url)}.json`
+pub fn entry_name(url: &str) -> String {
+    let digest = Sha256::digest(url.as_bytes());
+    let hex: String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}.json", hex)
+}
+
+/// Whether a file name is one the cache writes its entries under
+pub fn is_entry(name: &str) -> bool {
+    name.strip_suffix(".json")
+        .is_some_and(|hash| hash.len() == 64 && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')))
+}
+
+/// Fetch a finished game's resource, answering from the cache when it's stored there.
+///
+/// `fetch` is only awaited on a miss. Its response is stored as is, but only when it's a success
+/// and `is_final` says the game it describes has finished: a game that a listing called final
+/// too early is fetched again next time rather than cached mid-game. The cache is best-effort,
+/// so an entry that can't be written just means fetching again.
+pub async fn fetch_final(
+    cache: Option<&DiskCache>,
+    url: &str,
+    is_final: fn(&Value) -> bool,
+    fetch: impl Future<Output = Result<Response>>,
+) -> Result<Response> {
+    let Some(cache) = cache else {
+        return fetch.await;
+    };
+    // An entry that no longer parses is a miss, and is replaced by the fresh response
+    if let Some(body) = cache.get(url).filter(|body| serde_json::from_str::<Value>(body).is_ok()) {
+        return Ok(Response::from(http::Response::new(body)));
+    }
+
+    let response = fetch.await?;
+    if !response.status().is_success() {
+        return Ok(response);
+    }
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = response.text().await?;
+    if serde_json::from_str::<Value>(&body).is_ok_and(|data| is_final(&data)) {
+        let _ = cache.put(url, &body);
+    }
+
+    let mut rebuilt = http::Response::new(body);
+    *rebuilt.status_mut() = status;
+    *rebuilt.headers_mut() = headers;
+    Ok(Response::from(rebuilt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const BOXSCORE_1: &str = "https://statsapi.mlb.com/api/v1/game/1/boxscore";
+    const BOXSCORE_2: &str = "https://statsapi.mlb.com/api/v1/game/2/boxscore";
+    const FEED_1: &str = "https://statsapi.mlb.com/api/v1/game/1/feed/live";
+
+    fn temp_cache(name: &str, max_bytes: u64) -> DiskCache {
+        let dir = std::env::temp_dir().join(format!("plaintext-sports-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        DiskCache::new(dir, max_bytes)
+    }
+
+    // Give an entry an explicit age so eviction order doesn't depend on timer resolution
+    fn age(cache: &DiskCache, name: &str, secs_ago: u64) {
+        let file = File::options().write(true).open(cache.dir().join(name)).unwrap();
+        file.set_modified(SystemTime::now() - Duration::from_secs(secs_ago)).unwrap();
+    }
+
+    fn json_response(status: u16, body: &str) -> Response {
+        let mut response = http::Response::new(body.to_string());
+        *response.status_mut() = http::StatusCode::from_u16(status).unwrap();
+        Response::from(response)
+    }
+
+    fn says_final(data: &Value) -> bool {
+        data["state"] == "Final"
+    }
+
+    #[test]
+    fn test_entry_name() {
+        assert_eq!(
+            entry_name("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad.json"
+        );
+        assert_ne!(entry_name(BOXSCORE_1), entry_name(BOXSCORE_2));
+        assert!(is_entry(&entry_name(BOXSCORE_1)));
+    }
+
+    #[test]
+    fn test_is_entry() {
+        assert!(is_entry(&format!("{}.json", "0".repeat(64))));
+        assert!(!is_entry(&format!("{}.json", "0".repeat(63))));
+        assert!(!is_entry(&format!("{}.json", "A".repeat(64))));
+        assert!(!is_entry(&format!("{}.json.bak", "0".repeat(64))));
+        assert!(!is_entry("mlb-boxscore-745444.json"));
+        assert!(!is_entry("notes.txt"));
+    }
+
+    #[test]
+    fn test_cache_round_trip() {
+        let cache = temp_cache("cache-round-trip", 1024);
+        assert_eq!(cache.get(BOXSCORE_1), None);
+
+        cache.put(BOXSCORE_1, "{}").unwrap();
+        assert_eq!(cache.get(BOXSCORE_1).as_deref(), Some("{}"));
+        assert_eq!(cache.get(BOXSCORE_2), None);
+        assert!(cache.dir().join(entry_name(BOXSCORE_1)).exists());
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_cache_evicts_oldest_over_cap() {
+        let cache = temp_cache("cache-evict", 25);
+        cache.put(BOXSCORE_1, "0123456789").unwrap();
+        cache.put(BOXSCORE_2, "0123456789").unwrap();
+        age(&cache, &entry_name(BOXSCORE_1), 300);
+        age(&cache, &entry_name(BOXSCORE_2), 200);
+
+        // Reading 1 makes it the most recently used, so 2 is evicted first
+        assert!(cache.get(BOXSCORE_1).is_some());
+        cache.put(FEED_1, "0123456789").unwrap();
+
+        assert!(cache.get(BOXSCORE_1).is_some());
+        assert!(cache.get(BOXSCORE_2).is_none());
+        assert!(cache.get(FEED_1).is_some());
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_cache_evicts_only_its_own_entries() {
+        // Someone else's file in the directory is neither counted against the cap nor removed,
+        // however old or large it is
+        let cache = temp_cache("cache-evict-own", 25);
+        fs::create_dir_all(cache.dir()).unwrap();
+        fs::write(cache.dir().join("notes.txt"), "x".repeat(100)).unwrap();
+        age(&cache, "notes.txt", 1000);
+
+        cache.put(BOXSCORE_1, "0123456789").unwrap();
+        cache.put(BOXSCORE_2, "0123456789").unwrap();
+        assert!(cache.dir().join("notes.txt").exists());
+        assert!(cache.get(BOXSCORE_1).is_some());
+        assert!(cache.get(BOXSCORE_2).is_some());
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_cache_clear() {
+        let cache = temp_cache("cache-clear", 1024);
+        assert_eq!(cache.clear().unwrap(), 0);
+
+        cache.put(BOXSCORE_1, "{}").unwrap();
+        cache.put(FEED_1, "{}").unwrap();
+        fs::write(cache.dir().join("notes.txt"), "keep me").unwrap();
+        assert_eq!(cache.clear().unwrap(), 2);
+        assert!(cache.get(BOXSCORE_1).is_none());
+        assert_eq!(fs::read_to_string(cache.dir().join("notes.txt")).unwrap(), "keep me");
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fetch_final() {
+        let cache = temp_cache("cache-fetch-final", 1024);
+        let fetch = |status, body: &'static str| async move { Ok(json_response(status, body)) };
+
+        // A game still in progress is fetched every time
+        let live = r#"{"state": "Live"}"#;
+        let response = fetch_final(Some(&cache), FEED_1, says_final, fetch(200, live)).await.unwrap();
+        assert_eq!(response.text().await.unwrap(), live);
+        assert_eq!(cache.get(FEED_1), None);
+
+        // So are errors, even when they say the game is final
+        let response = fetch_final(Some(&cache), FEED_1, says_final, fetch(500, r#"{"state": "Final"}"#)).await.unwrap();
+        assert_eq!(response.status(), 500);
+        assert_eq!(cache.get(FEED_1), None);
+
+        // A finished game's raw body is stored, then answered without fetching
+        let finished = r#"{"state":"Final", "innings": []}"#;
+        fetch_final(Some(&cache), FEED_1, says_final, fetch(200, finished)).await.unwrap();
+        assert_eq!(cache.get(FEED_1).as_deref(), Some(finished));
+        let unreachable = async { panic!("fetched a cached response") };
+        let response = fetch_final(Some(&cache), FEED_1, says_final, unreachable).await.unwrap();
+        assert_eq!(response.text().await.unwrap(), finished);
+
+        // Without a cache every call is fetched
+        let response = fetch_final(None, FEED_1, says_final, fetch(200, live)).await.unwrap();
+        assert_eq!(response.text().await.unwrap(), live);
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+}
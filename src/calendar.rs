@@ -0,0 +1,105 @@
+use chrono::Utc;
+
+use crate::utils::parse_iso8601_date;
+
+// Games don't have a scheduled end, so calendar events are given a typical game length
+const EVENT_HOURS: i64 = 3;
+
+/// A game that can be exported as an iCalendar event
+pub trait CalendarEvent {
+    /// Identifier that stays the same across exports, so re-importing updates the event
+    fn uid(&self) -> String;
+    /// Start as an ISO8601 date-time, or a plain YYYY-MM-DD date if the time isn't known
+    fn start(&self) -> String;
+    /// Event title, e.g. the matchup
+    fn summary(&self) -> String;
+    /// Where the game is played, if known
+    fn location(&self) -> Option<String>;
+}
+
+/// Serialize events as an iCalendar (RFC 5545) file with one VEVENT each. Start times are
+/// emitted in UTC; events without a start time become all-day events.
+pub fn to_ics<E: CalendarEvent>(events: &[E]) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ");
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//plaintext-sports//Schedule//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", event.uid()));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        let start = event.start();
+        match parse_iso8601_date(&start) {
+            Ok(start) => {
+                let end = start + chrono::Duration::hours(EVENT_HOURS);
+                lines.push(format!("DTSTART:{}", start.format("%Y%m%dT%H%M%SZ")));
+                lines.push(format!("DTEND:{}", end.format("%Y%m%dT%H%M%SZ")));
+            }
+            Err(_) => {
+                let day = start.split('T').next().unwrap_or(&start).replace('-', "");
+                lines.push(format!("DTSTART;VALUE=DATE:{}", day));
+            }
+        }
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary())));
+        if let Some(location) = event.location() {
+            lines.push(format!("LOCATION:{}", escape_text(&location)));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    // iCalendar lines end with CRLF
+    let mut ics = lines.join("\r\n");
+    ics.push_str("\r\n");
+    ics
+}
+
+// Escape the characters that have special meaning in iCalendar text values
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Event(&'static str, &'static str);
+
+    impl CalendarEvent for Event {
+        fn uid(&self) -> String {
+            format!("{}@test", self.0)
+        }
+
+        fn start(&self) -> String {
+            self.1.to_string()
+        }
+
+        fn summary(&self) -> String {
+            "Away; Team @ Home, Team".to_string()
+        }
+
+        fn location(&self) -> Option<String> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_to_ics() {
+        let ics = to_ics(&[Event("1", "2024-03-28T23:30:00Z"), Event("2", "2024-03-29")]);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("\r\nDTSTART:20240328T233000Z\r\nDTEND:20240329T023000Z\r\n"));
+        assert!(ics.contains("\r\nDTSTART;VALUE=DATE:20240329\r\n"));
+        assert!(ics.contains("\r\nSUMMARY:Away\\; Team @ Home\\, Team\r\n"));
+        assert!(!ics.contains("LOCATION:"));
+    }
+}
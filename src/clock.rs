@@ -0,0 +1,40 @@
+use chrono::{Local, NaiveDate};
+
+/// Source of the current date, so "today" and "yesterday" can be fixed in tests
+pub trait Clock: Send + Sync {
+    /// The current local date
+    fn today(&self) -> NaiveDate;
+}
+
+/// Clock backed by the system's local time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn today(&self) -> NaiveDate {
+        Local::now().date_naive()
+    }
+}
+
+/// Clock that always reports the same date
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub NaiveDate);
+
+impl Clock for FixedClock {
+    fn today(&self) -> NaiveDate {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_clock() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 28).unwrap();
+        let clock = FixedClock(date);
+        assert_eq!(clock.today(), date);
+        assert_eq!(clock.today(), date);
+    }
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::config::get_config;
+
+/// When to color the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color only when stdout is a terminal
+    Auto,
+    Always,
+    Never,
+}
+
+impl FromStr for ColorChoice {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            other => Err(format!("unknown color choice '{}' (expected auto, always or never)", other)),
+        }
+    }
+}
+
+impl ColorChoice {
+    /// Whether to color, given whether stdout is a terminal
+    pub fn enabled(&self, is_terminal: bool) -> bool {
+        match self {
+            ColorChoice::Auto => is_terminal,
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+/// How a piece of output is highlighted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// The winning team's score
+    Winner,
+    /// The losing team
+    Dimmed,
+    /// The status of a game in progress
+    Live,
+}
+
+impl Style {
+    // SGR parameters for the style: green, faint and yellow
+    fn code(&self) -> &'static str {
+        match self {
+            Style::Winner => "32",
+            Style::Dimmed => "2",
+            Style::Live => "33",
+        }
+    }
+}
+
+/// Highlight text in a style, if color output is enabled
+pub fn paint(text: impl fmt::Display, style: Style) -> String {
+    paint_if(get_config().color, text, style)
+}
+
+// Wrap text in the style's escape codes, or leave it as it is
+fn paint_if(enabled: bool, text: impl fmt::Display, style: Style) -> String {
+    if enabled {
+        format!("\x1b[{}m{}\x1b[0m", style.code(), text)
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_choice() {
+        assert_eq!("AUTO".parse::<ColorChoice>(), Ok(ColorChoice::Auto));
+        assert!("sometimes".parse::<ColorChoice>().is_err());
+
+        assert!(ColorChoice::Auto.enabled(true));
+        assert!(!ColorChoice::Auto.enabled(false));
+        assert!(ColorChoice::Always.enabled(false));
+        assert!(!ColorChoice::Never.enabled(true));
+    }
+
+    #[test]
+    fn test_paint_if() {
+        assert_eq!(paint_if(true, 5, Style::Winner), "\x1b[32m5\x1b[0m");
+        assert_eq!(paint_if(true, "Live", Style::Live), "\x1b[33mLive\x1b[0m");
+        assert_eq!(paint_if(false, "Chicago Cubs", Style::Dimmed), "Chicago Cubs");
+    }
+}
@@ -1,4 +1,11 @@
+use anyhow::{anyhow, Result};
+use chrono_tz::Tz;
+use serde::Deserialize;
 use std::env;
+use std::fs;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::OnceLock;
 
 /// Configuration for the application
@@ -8,31 +15,282 @@ pub struct Config {
     pub mlb_api_base_url: String,
     /// Base URL for the NBA API
     pub nba_api_base_url: String,
-    /// API key for the NBA API
+    /// API key for the NBA API from the environment, a key file or the config file. Use
+    /// `nba_api_key()` to also fall back to the system keyring.
     pub nba_api_key: String,
+    /// Base URL for the NHL API
+    pub nhl_api_base_url: String,
+    /// List the home team first when displaying games
+    pub home_first: bool,
+    /// Directory for cached API responses; nothing is cached unless one is set
+    pub cache_dir: Option<PathBuf>,
+    /// Maximum size of the response cache in megabytes
+    pub cache_max_mb: u64,
+    /// Whether finished games' responses are read from and written to the cache
+    pub cache_enabled: bool,
+    /// Width of the player name column in box scores
+    pub name_width: usize,
+    /// Highlight winners, losers and live games with ANSI colors
+    pub color: bool,
+    /// Directory of recorded API responses to read instead of the network
+    pub replay_dir: Option<PathBuf>,
+    /// Directory to write every fetched API response body to, for debugging
+    pub dump_raw_dir: Option<PathBuf>,
+    /// How many times to retry a request that failed with a server error or network error
+    pub http_retries: u32,
+    /// Seconds to wait for an API response before giving up on the request
+    pub request_timeout_secs: u64,
+    /// How many games' details to fetch at once when listing games
+    pub detail_concurrency: usize,
+    /// Leagues to show when --leagues isn't given; all of them if unset
+    pub leagues: Option<Vec<String>>,
+    /// IDs of the user's favorite MLB teams
+    pub favorite_mlb_teams: Vec<u32>,
+    /// IDs of the user's favorite NBA teams
+    pub favorite_nba_teams: Vec<u32>,
+    /// IDs of the user's favorite NHL teams
+    pub favorite_nhl_teams: Vec<u32>,
+    /// Timezone game times are shown in; the system's local timezone if unset
+    pub timezone: Option<Tz>,
 }
 
-static CONFIG: OnceLock<Config> = OnceLock::new();
+/// Settings read from the config file; anything left out falls back to the environment or defaults
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    pub nba_api_key: Option<String>,
+    pub mlb_api_base_url: Option<String>,
+    pub nba_api_base_url: Option<String>,
+    pub nhl_api_base_url: Option<String>,
+    pub cache_dir: Option<PathBuf>,
+    pub cache_max_mb: Option<u64>,
+    pub home_first: Option<bool>,
+    pub name_width: Option<usize>,
+    pub http_retries: Option<u32>,
+    pub request_timeout_secs: Option<u64>,
+    pub leagues: Option<Vec<String>>,
+    pub favorite_mlb_teams: Vec<u32>,
+    pub favorite_nba_teams: Vec<u32>,
+    pub favorite_nhl_teams: Vec<u32>,
+    pub timezone: Option<Tz>,
+}
 
-/// Get the application configuration
-pub fn get_config() -> &'static Config {
-    CONFIG.get_or_init(|| {
-        // Default values
+impl ConfigFile {
+    /// Read a config file, treating a missing file as an empty one
+    pub fn read(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|e| anyhow!("Invalid config file {}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(ConfigFile::default()),
+            Err(e) => Err(anyhow!("Failed to read config file {}: {}", path.display(), e)),
+        }
+    }
+}
+
+impl Config {
+    /// Load the configuration. Environment variables take precedence over the config file,
+    /// which takes precedence over the built-in defaults.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&config_file_path())
+    }
+
+    /// Load the configuration, reading the config file at `path`
+    pub fn load_from(path: &Path) -> Result<Self> {
+        Ok(Self::from_file_and_env(ConfigFile::read(path)?))
+    }
+
+    /// Build the configuration from environment variables, using defaults for anything unset
+    pub fn from_env() -> Self {
+        Self::from_file_and_env(ConfigFile::default())
+    }
+
+    // Environment variables override the file's settings, which override the defaults
+    fn from_file_and_env(file: ConfigFile) -> Self {
         let mlb_api_base_url = env::var("MLB_API_BASE_URL")
-            .unwrap_or_else(|_| "https://statsapi.mlb.com/api/v1".to_string());
+            .ok()
+            .or(file.mlb_api_base_url)
+            .unwrap_or_else(|| "https://statsapi.mlb.com/api/v1".to_string());
         
         let nba_api_base_url = env::var("NBA_API_BASE_URL")
-            .unwrap_or_else(|_| "https://api.balldontlie.io/v1".to_string());
+            .ok()
+            .or(file.nba_api_base_url)
+            .unwrap_or_else(|| "https://api.balldontlie.io/v1".to_string());
+        
+        let nhl_api_base_url = env::var("NHL_API_BASE_URL")
+            .ok()
+            .or(file.nhl_api_base_url)
+            .unwrap_or_else(|| "https://statsapi.web.nhl.com/api/v1".to_string());
+        
+        // The file's key is used after either environment variable. The keyring is left until the
+        // NBA client needs a key (see `nba_api_key`), so other runs never spawn the keyring tool.
+        let file_key = file.nba_api_key.filter(|key| !key.trim().is_empty());
+        let nba_api_key = resolve_nba_api_key(
+            env::var("NBA_API_KEY").ok(),
+            env::var_os("NBA_API_KEY_FILE").map(PathBuf::from).as_deref(),
+            || file_key,
+        );
+        
+        let home_first = env::var("PLAINTEXT_SPORTS_HOME_FIRST")
+            .ok()
+            .map(|value| matches!(value.as_str(), "1" | "true"))
+            .or(file.home_first)
+            .unwrap_or(false);
+        
+        // Caching is opt-in: only with a directory to keep the responses in
+        let cache_dir = env::var_os("PLAINTEXT_SPORTS_CACHE_DIR")
+            .map(PathBuf::from)
+            .or(file.cache_dir);
+        
+        let cache_max_mb = env::var("PLAINTEXT_SPORTS_CACHE_MAX_MB")
+            .ok()
+            .and_then(|mb| mb.parse().ok())
+            .or(file.cache_max_mb)
+            .unwrap_or(100);
         
-        let nba_api_key = env::var("NBA_API_KEY")
-            .unwrap_or_else(|_| "".to_string());
+        let name_width = env::var("PLAINTEXT_SPORTS_NAME_WIDTH")
+            .ok()
+            .and_then(|width| width.parse().ok())
+            .or(file.name_width)
+            .unwrap_or_else(|| name_width_for_terminal(terminal_columns()))
+            .max(MIN_NAME_WIDTH);
+        
+        let replay_dir = env::var_os("PLAINTEXT_SPORTS_REPLAY_DIR").map(PathBuf::from);
+        
+        let http_retries = env::var("PLAINTEXT_SPORTS_HTTP_RETRIES")
+            .ok()
+            .and_then(|retries| retries.parse().ok())
+            .or(file.http_retries)
+            .unwrap_or(3);
+        
+        let request_timeout_secs = env::var("PLAINTEXT_SPORTS_REQUEST_TIMEOUT_SECS")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .or(file.request_timeout_secs)
+            .unwrap_or(15);
+        
+        let detail_concurrency = env::var("PLAINTEXT_SPORTS_DETAIL_CONCURRENCY")
+            .ok()
+            .and_then(|n| n.parse().ok())
+            .unwrap_or(4)
+            .max(1);
         
         Config {
             mlb_api_base_url,
             nba_api_base_url,
             nba_api_key,
+            nhl_api_base_url,
+            home_first,
+            cache_dir,
+            cache_max_mb,
+            cache_enabled: true,
+            name_width,
+            color: std::io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none(),
+            replay_dir,
+            dump_raw_dir: None,
+            http_retries,
+            request_timeout_secs,
+            detail_concurrency,
+            leagues: file.leagues,
+            favorite_mlb_teams: file.favorite_mlb_teams,
+            favorite_nba_teams: file.favorite_nba_teams,
+            favorite_nhl_teams: file.favorite_nhl_teams,
+            timezone: file.timezone,
         }
-    })
+    }
+}
+
+/// Where the config file is read from: $PLAINTEXT_SPORTS_CONFIG, or config.toml under
+/// $XDG_CONFIG_HOME/plaintext-sports, falling back to ~/.config/plaintext-sports
+pub fn config_file_path() -> PathBuf {
+    if let Some(path) = env::var_os("PLAINTEXT_SPORTS_CONFIG") {
+        return PathBuf::from(path);
+    }
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(env::temp_dir)
+        .join("plaintext-sports")
+        .join("config.toml")
+}
+
+/// Name column width used when the terminal width isn't known
+pub const DEFAULT_NAME_WIDTH: usize = 25;
+
+// Narrowest name column that still leaves room for the "..." of a truncated name
+const MIN_NAME_WIDTH: usize = 10;
+
+// Width of the terminal stdout is attached to, if it is one. Piped output keeps the default
+// widths so it doesn't depend on the terminal it happened to be produced in.
+fn terminal_columns() -> Option<usize> {
+    if !std::io::stdout().is_terminal() {
+        return None;
+    }
+    env::var("COLUMNS").ok().and_then(|columns| columns.parse().ok())
+}
+
+// Box score rows fit an 80-column terminal at the default width; wider terminals give the extra
+// space to the name column, up to a point
+fn name_width_for_terminal(columns: Option<usize>) -> usize {
+    match columns {
+        Some(columns) => (DEFAULT_NAME_WIDTH + columns.saturating_sub(80)).min(40),
+        None => DEFAULT_NAME_WIDTH,
+    }
+}
+
+// Keyring entry the NBA API key is looked up under
+const KEYRING_SERVICE: &str = "plaintext-sports";
+const KEYRING_ACCOUNT: &str = "nba-api-key";
+
+/// Pick the NBA API key from, in order: the key itself, a file containing it, the system keyring.
+/// The keyring is only consulted if neither of the others yields a key; blank values are skipped.
+pub fn resolve_nba_api_key(
+    env_key: Option<String>,
+    key_file: Option<&Path>,
+    keyring: impl FnOnce() -> Option<String>,
+) -> String {
+    let from_file = || key_file.and_then(|path| fs::read_to_string(path).ok());
+    [env_key, from_file()]
+        .into_iter()
+        .flatten()
+        .map(|key| key.trim().to_string())
+        .find(|key| !key.is_empty())
+        .or_else(|| keyring().map(|key| key.trim().to_string()).filter(|key| !key.is_empty()))
+        .unwrap_or_default()
+}
+
+/// The NBA API key: the configured one, or else the one in the system keyring. The keyring is
+/// asked at most once, when the NBA client is first built, since unlocking it may prompt the user.
+pub fn nba_api_key() -> &'static str {
+    static NBA_API_KEY: OnceLock<String> = OnceLock::new();
+    NBA_API_KEY.get_or_init(|| resolve_nba_api_key(Some(get_config().nba_api_key.clone()), None, keyring_nba_api_key))
+}
+
+// Look the key up with the platform's keyring tool: `security` on macOS, libsecret's
+// `secret-tool` elsewhere. A missing tool or entry just means there's no key.
+fn keyring_nba_api_key() -> Option<String> {
+    let mut command = if cfg!(target_os = "macos") {
+        let mut command = Command::new("security");
+        command.args(["find-generic-password", "-s", KEYRING_SERVICE, "-a", KEYRING_ACCOUNT, "-w"]);
+        command
+    } else {
+        let mut command = Command::new("secret-tool");
+        command.args(["lookup", "service", KEYRING_SERVICE, "account", KEYRING_ACCOUNT]);
+        command
+    };
+    let output = command.output().ok().filter(|output| output.status.success())?;
+    String::from_utf8(output.stdout).ok()
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Install the application configuration. Only takes effect if called before the first `get_config`.
+pub fn init_config(config: Config) -> &'static Config {
+    CONFIG.get_or_init(|| config)
+}
+
+/// Get the application configuration, ignoring a config file that can't be read
+pub fn get_config() -> &'static Config {
+    CONFIG.get_or_init(|| Config::load().unwrap_or_else(|_| Config::from_env()))
 }
 
 #[cfg(test)]
@@ -46,4 +304,98 @@ mod tests {
         assert!(!config.nba_api_base_url.is_empty());
         // Note: NBA API key might be empty in tests
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_name_width_for_terminal() {
+        assert_eq!(name_width_for_terminal(None), DEFAULT_NAME_WIDTH);
+        assert_eq!(name_width_for_terminal(Some(60)), DEFAULT_NAME_WIDTH);
+        assert_eq!(name_width_for_terminal(Some(85)), 30);
+        assert_eq!(name_width_for_terminal(Some(200)), 40);
+    }
+
+    #[test]
+    fn test_resolve_nba_api_key() {
+        let key_file = env::temp_dir().join(format!("plaintext-sports-key-{}", std::process::id()));
+        fs::write(&key_file, "file-key\n").unwrap();
+        let no_keyring = || -> Option<String> { panic!("keyring consulted") };
+        
+        // The file is read and trimmed, and only used when the variable isn't set
+        assert_eq!(resolve_nba_api_key(None, Some(&key_file), no_keyring), "file-key");
+        assert_eq!(resolve_nba_api_key(Some("env-key".to_string()), Some(&key_file), no_keyring), "env-key");
+        assert_eq!(resolve_nba_api_key(Some(" ".to_string()), Some(&key_file), no_keyring), "file-key");
+        fs::remove_file(&key_file).unwrap();
+        
+        // A missing file falls through to the keyring
+        assert_eq!(resolve_nba_api_key(None, Some(&key_file), || Some("ring-key\n".to_string())), "ring-key");
+        assert_eq!(resolve_nba_api_key(None, None, || None), "");
+    }
+
+    #[test]
+    fn test_load_config_file() {
+        let dir = env::temp_dir().join(format!("plaintext-sports-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        fs::write(&path, r#"
+            nba_api_base_url = "http://localhost:8080/nba"
+            leagues = ["MLB"]
+            favorite_mlb_teams = [145, 112]
+            favorite_nhl_teams = [18]
+            timezone = "America/Chicago"
+            cache_dir = "/tmp/sports-cache"
+        "#).unwrap();
+        
+        let config = Config::load_from(&path).unwrap();
+        assert_eq!(config.leagues, Some(vec!["MLB".to_string()]));
+        assert_eq!(config.favorite_mlb_teams, vec![145, 112]);
+        assert!(config.favorite_nba_teams.is_empty());
+        assert_eq!(config.favorite_nhl_teams, vec![18]);
+        assert_eq!(config.timezone, Some(chrono_tz::America::Chicago));
+        // The environment wins over the file, for the cache directory as for the API URLs
+        match env::var_os("PLAINTEXT_SPORTS_CACHE_DIR") {
+            Some(dir) => assert_eq!(config.cache_dir, Some(PathBuf::from(dir))),
+            None => assert_eq!(config.cache_dir, Some(PathBuf::from("/tmp/sports-cache"))),
+        }
+        match env::var("NBA_API_BASE_URL") {
+            Ok(url) => assert_eq!(config.nba_api_base_url, url),
+            Err(_) => assert_eq!(config.nba_api_base_url, "http://localhost:8080/nba"),
+        }
+        
+        // Misspelled settings are reported rather than ignored
+        fs::write(&path, "favourite_mlb_teams = [145]").unwrap();
+        let err = Config::load_from(&path).unwrap_err().to_string();
+        assert!(err.starts_with(&format!("Invalid config file {}", path.display())), "{}", err);
+        
+        // So are timezones that don't exist
+        fs::write(&path, "timezone = \"America/Gotham\"").unwrap();
+        assert!(Config::load_from(&path).is_err());
+        
+        // The settings that also have environment variables are read from the file too
+        fs::write(&path, r#"
+            home_first = true
+            cache_max_mb = 20
+            name_width = 32
+            http_retries = 1
+            request_timeout_secs = 5
+        "#).unwrap();
+        let config = Config::load_from(&path).unwrap();
+        if env::var_os("PLAINTEXT_SPORTS_HOME_FIRST").is_none() {
+            assert!(config.home_first);
+        }
+        if env::var_os("PLAINTEXT_SPORTS_CACHE_MAX_MB").is_none() {
+            assert_eq!(config.cache_max_mb, 20);
+        }
+        if env::var_os("PLAINTEXT_SPORTS_NAME_WIDTH").is_none() {
+            assert_eq!(config.name_width, 32);
+        }
+        if env::var_os("PLAINTEXT_SPORTS_HTTP_RETRIES").is_none() {
+            assert_eq!(config.http_retries, 1);
+        }
+        if env::var_os("PLAINTEXT_SPORTS_REQUEST_TIMEOUT_SECS").is_none() {
+            assert_eq!(config.request_timeout_secs, 5);
+        }
+        
+        // A missing file is the same as an empty one
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(ConfigFile::read(&path).unwrap(), ConfigFile::default());
+    }
+}
@@ -12,6 +12,17 @@ pub enum SportError {
     DateError(String),
     #[error("Failed to fetch data: {0}")]
     FetchError(String),
+    #[error("Request timed out")]
+    Timeout,
+    #[error("{}", match retry_after {
+        Some(seconds) => format!("Rate limited, retry in {} seconds", seconds),
+        None => "Rate limited, try again later".to_string(),
+    })]
+    RateLimited { retry_after: Option<u64> },
+    #[error("{0} not found")]
+    NotFound(String),
+    #[error("NBA features require NBA_API_KEY; set it in the environment or config.")]
+    MissingApiKey,
 }
 
 impl From<anyhow::Error> for SportError {
@@ -28,7 +39,11 @@ impl From<serde_json::Error> for SportError {
 
 impl From<reqwest::Error> for SportError {
     fn from(err: reqwest::Error) -> Self {
-        SportError::FetchError(format!("Network error: {}", err))
+        if err.is_timeout() {
+            SportError::Timeout
+        } else {
+            SportError::FetchError(format!("Network error: {}", err))
+        }
     }
 }
 
@@ -0,0 +1,135 @@
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::fmt;
+use std::future::Future;
+
+use crate::config::Config;
+use crate::mlb::GameState;
+use crate::utils::edit_distance;
+
+/// Options that control how much detail is fetched and how it's rendered
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DetailOptions {
+    /// Render each section on a single line where it has a compact form
+    pub compact: bool,
+    /// Include the full box score / extended stat columns
+    pub detailed_stats: bool,
+}
+
+/// One block of detail for a finished game, e.g. the linescore or the box score
+#[derive(Debug)]
+pub struct DetailSection {
+    /// Heading printed above the section (e.g. "Inning-by-Inning Breakdown")
+    pub heading: &'static str,
+    /// What was fetched, for error messages (e.g. "innings data")
+    pub what: &'static str,
+    /// The rendered section, or why it couldn't be fetched
+    pub body: Result<String>,
+}
+
+/// A league backend, so game listings can be driven the same way for every league
+pub trait League {
+    /// The league's game type
+    type Game: fmt::Display + Serialize;
+
+    /// Short league name used in headings and error reports (e.g. "MLB")
+    const NAME: &'static str;
+
+    /// Shown in place of details for games that haven't finished
+    const PENDING_MESSAGE: &'static str;
+
+    /// The game's ID
+    fn game_id(game: &Self::Game) -> u32;
+
+    /// Where the game stands
+    fn game_state(game: &Self::Game) -> GameState;
+
+    /// The game's result on a single line
+    fn summary_line(game: &Self::Game) -> String;
+
+    /// The away and home teams' IDs
+    fn team_ids(game: &Self::Game) -> (u32, u32);
+
+    /// IDs of the user's favorite teams in this league
+    fn favorite_teams(config: &Config) -> &[u32];
+
+    /// Get all games for a specific date
+    fn games_by_date(&self, date: NaiveDate) -> impl Future<Output = Result<Vec<Self::Game>>> + Send;
+
+    /// Get all games for today
+    fn todays_games(&self) -> impl Future<Output = Result<Vec<Self::Game>>> + Send;
+
+    /// Get all games from yesterday
+    fn yesterdays_games(&self) -> impl Future<Output = Result<Vec<Self::Game>>> + Send;
+
+    /// Get a team's games, for the current month unless dates are given
+    fn team_schedule(
+        &self,
+        team_id: u32,
+        start_date: Option<String>,
+        end_date: Option<String>,
+    ) -> impl Future<Output = Result<Vec<Self::Game>>> + Send;
+
+    /// Fetch the detail sections shown beneath a finished game
+    fn game_detail(&self, game: &Self::Game, options: DetailOptions) -> impl Future<Output = Vec<DetailSection>> + Send;
+
+    /// Every team's ID and abbreviation
+    fn team_abbreviations(&self) -> impl Future<Output = Result<Vec<(u32, String)>>> + Send;
+}
+
+/// Resolve a team given either as its numeric ID or as its abbreviation (in any case)
+pub async fn resolve_team_id<L: League>(league: &L, input: &str) -> Result<u32> {
+    if let Ok(id) = input.trim().parse() {
+        return Ok(id);
+    }
+    let teams = league.team_abbreviations().await?;
+    find_team_id(L::NAME, input, &teams)
+}
+
+// Look an abbreviation up among a league's teams. An unknown one is an error that suggests the
+// abbreviations it's a typo away from.
+fn find_team_id(league: &str, input: &str, teams: &[(u32, String)]) -> Result<u32> {
+    let input = input.trim().to_uppercase();
+    if let Some((id, _)) = teams.iter().find(|(_, abbreviation)| abbreviation.to_uppercase() == input) {
+        return Ok(*id);
+    }
+
+    let close: Vec<&str> = teams
+        .iter()
+        .map(|(_, abbreviation)| abbreviation.as_str())
+        .filter(|abbreviation| edit_distance(&input, &abbreviation.to_uppercase()) <= 1)
+        .collect();
+    if close.is_empty() {
+        Err(anyhow!(
+            "Unknown {} team '{}'. Run `plaintext-sports {} teams` to list team IDs and abbreviations.",
+            league, input, league.to_lowercase()
+        ))
+    } else {
+        Err(anyhow!("Unknown {} team '{}'. Did you mean: {}?", league, input, close.join(", ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn teams() -> Vec<(u32, String)> {
+        vec![(121, "NYM".to_string()), (147, "NYY".to_string()), (145, "CWS".to_string())]
+    }
+
+    #[test]
+    fn test_find_team_id() {
+        assert_eq!(find_team_id("MLB", "NYY", &teams()).unwrap(), 147);
+        assert_eq!(find_team_id("MLB", " cws ", &teams()).unwrap(), 145);
+    }
+
+    #[test]
+    fn test_find_team_id_suggests_close_matches() {
+        let error = find_team_id("MLB", "NYX", &teams()).unwrap_err().to_string();
+        assert_eq!(error, "Unknown MLB team 'NYX'. Did you mean: NYM, NYY?");
+
+        let error = find_team_id("MLB", "ZZZ", &teams()).unwrap_err().to_string();
+        assert!(error.contains("plaintext-sports mlb teams"));
+    }
+}
@@ -1,7 +1,20 @@
+pub mod cache;
+pub mod calendar;
+pub mod clock;
+pub mod color;
 pub mod config;
 pub mod error;
+pub mod league;
 pub mod mlb;
 pub mod nba;
+pub mod nhl;
+pub mod order;
+pub mod output;
+pub mod pagination;
+pub mod progress;
+pub mod replay;
+pub mod retry;
+pub mod scorebug;
 pub mod types;
 pub mod utils;
 
@@ -9,7 +22,8 @@ pub use config::*;
 pub use error::{Result, SportError};
 pub use mlb::{Game as MlbGame, Team as MlbTeam, GameState, GameTeam, Venue};
 pub use nba::{Game as NbaGame, Team as NbaTeam};
-pub use types::{Average, GameId, InningsPitched, Score, TeamId};
+pub use nhl::{Game as NhlGame, Team as NhlTeam};
+pub use types::{Average, GameId, InningsPitched, RateStat, Score, Streak, StreakKind, TeamId};
 pub use utils::{
     format_decimal_stat,
     format_score,
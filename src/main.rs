@@ -1,12 +1,26 @@
-use anyhow::Result;
-use clap::{Parser, Subcommand};
-use tracing::{info, Level};
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, Days, NaiveDate};
+use chrono_tz::Tz;
+use futures::{future, stream, StreamExt};
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+use clap::{error::ErrorKind, CommandFactory, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use tracing::{info, warn, Level};
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
 use tracing_subscriber::FmtSubscriber;
 
 // Import our modules
-mod mlb;
-mod nba;
-mod config;
+use plaintext_sports::{calendar, mlb, nba, nhl};
+use plaintext_sports::cache::DiskCache;
+use plaintext_sports::color::ColorChoice;
+use plaintext_sports::mlb::GameState;
+use plaintext_sports::clock::{Clock, SystemClock};
+use plaintext_sports::config::{self, Config};
+use plaintext_sports::league::{resolve_team_id, DetailOptions, DetailSection, League};
+use plaintext_sports::output::{render, DayReport, GamesReport, OutputFormat, OutputSpec};
+use plaintext_sports::progress::{Progress, ProgressMode};
 
 /// CLI application for plaintext sports
 #[derive(Parser, Debug)]
@@ -20,26 +34,131 @@ struct Args {
     #[clap(long)]
     detailed_stats: bool,
 
-    /// Get all games being played today for both MLB and NBA
+    /// Get all games being played today for every league
     #[clap(long)]
     todays_games: bool,
 
-    /// Get all games played yesterday for both MLB and NBA
+    /// Get all games played yesterday for every league
     #[clap(long)]
     yesterday_games: bool,
 
-    /// Filter games by leagues (e.g., --leagues MLB NBA)
-    #[clap(long, value_delimiter = ' ')]
+    /// Get all games played N days ago for every league (0 = today)
+    #[clap(long, value_name = "N")]
+    days_ago: Option<u32>,
+
+    /// Get all games on a specific date for every league (YYYY-MM-DD)
+    #[clap(long, value_parser = parse_date, value_name = "YYYY-MM-DD")]
+    date: Option<NaiveDate>,
+
+    /// Keep today's games on screen, refreshing every SECONDS (default 30) until they're all final
+    #[clap(
+        long,
+        value_name = "SECONDS",
+        num_args = 0..=1,
+        default_missing_value = "30",
+        requires = "todays_games",
+        conflicts_with_all = ["yesterday_games", "days_ago", "date", "also"]
+    )]
+    watch: Option<u64>,
+
+    /// Print each game on a single compact line
+    #[clap(long)]
+    compact: bool,
+
+    /// Print only a one-line result for each game, e.g. "NYY 5 @ BOS 3 (Final)"
+    #[clap(long, global = true)]
+    summary: bool,
+
+    /// List the home team first instead of the away team
+    #[clap(long)]
+    home_first: bool,
+
+    /// Cache the API responses for finished games in this directory
+    #[clap(long, value_name = "DIR")]
+    cache_dir: Option<std::path::PathBuf>,
+
+    /// Fetch everything from the API, neither reading nor writing cached responses
+    #[clap(long)]
+    no_cache: bool,
+
+    /// Remove every cached response, then carry on with anything else requested
+    #[clap(long)]
+    clear_cache: bool,
+
+    /// Read API responses from recorded fixtures in this directory instead of the network
+    #[clap(long, global = true, value_name = "DIR")]
+    replay_dir: Option<std::path::PathBuf>,
+
+    /// Save the untouched body of every API response to this directory, e.g. for a bug report
+    #[clap(long, global = true, value_name = "DIR")]
+    dump_raw: Option<std::path::PathBuf>,
+
+    /// Only show completed games
+    #[clap(long, group = "status")]
+    only_final: bool,
+
+    /// Only show games in progress
+    #[clap(long, group = "status")]
+    only_live: bool,
+
+    /// Only show games that haven't started yet
+    #[clap(long, group = "status")]
+    only_scheduled: bool,
+
+    /// Only show games involving one of the favorite teams from the config file
+    #[clap(long, requires = "todays_games")]
+    favorites: bool,
+
+    /// Output format (text, json, csv or markdown); json prints a single JSON document per command,
+    /// and csv and markdown print box scores (mlb game) as tables
+    #[clap(long, default_value = "text", value_name = "FORMAT")]
+    format: OutputFormat,
+
+    /// Show game times in this IANA timezone (e.g. America/Los_Angeles) instead of the local one
+    #[clap(long, global = true, value_parser = parse_timezone, value_name = "TZ")]
+    timezone: Option<Tz>,
+
+    /// Color the output: auto (only on a terminal), always or never
+    #[clap(long, default_value = "auto", value_name = "WHEN")]
+    color: ColorChoice,
+
+    /// Only log warnings and errors, leaving out the greeting and progress messages
+    #[clap(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Report progress fetching game details even when stderr isn't a terminal
+    #[clap(long, global = true)]
+    progress: bool,
+
+    /// Log more detail: -v for debug messages, -vv for everything
+    #[clap(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Also write the listed games to a file in another format (e.g. json:games.json)
+    #[clap(long, value_name = "FORMAT:PATH")]
+    also: Option<OutputSpec>,
+
+    /// Filter games by leagues (e.g., --leagues MLB NBA or --leagues mlb,nba). MLB and NBA by
+    /// default; the NHL only when it's listed here or in the config file's leagues
+    #[clap(long, value_delimiter = ',', num_args = 1..)]
     leagues: Option<Vec<String>>,
 
     /// Start date for schedule (YYYY-MM-DD)
-    #[clap(long)]
+    #[clap(long, global = true)]
     start_date: Option<String>,
 
     /// End date for schedule (YYYY-MM-DD)
-    #[clap(long)]
+    #[clap(long, global = true)]
     end_date: Option<String>,
 
+    /// Show the schedule for the next N days, starting today
+    #[clap(long, global = true, value_name = "N", conflicts_with_all = ["start_date", "end_date", "last"])]
+    next: Option<u32>,
+
+    /// Show the schedule for the last N days, up to yesterday
+    #[clap(long, global = true, value_name = "N", conflicts_with_all = ["start_date", "end_date"])]
+    last: Option<u32>,
+
     #[clap(subcommand)]
     command: Option<Command>,
 }
@@ -56,6 +175,26 @@ enum Command {
         #[clap(subcommand)]
         command: NBACommand,
     },
+    /// NHL related commands
+    NHL {
+        #[clap(subcommand)]
+        command: NHLCommand,
+    },
+    /// Print a compact score bug for a single game
+    Scorebug {
+        /// League of the game (mlb or nba)
+        #[clap(long)]
+        league: String,
+        
+        /// Game ID
+        #[clap(short, long)]
+        id: u64,
+    },
+    /// Print a tab completion script for a shell (bash, zsh, fish, powershell or elvish)
+    #[clap(hide = true)]
+    Completions {
+        shell: clap_complete::Shell,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -65,50 +204,245 @@ enum MLBCommand {
         /// MLB player ID
         #[clap(short, long)]
         id: u32,
+        
+        /// Also show the player's hitting and pitching stats for this season
+        #[clap(long)]
+        season: Option<u32>,
+        
+        /// Also show the player's splits against left- and right-handed opponents (this season unless --season is given)
+        #[clap(long)]
+        splits: bool,
     },
     /// Get MLB team stats
     Team {
-        /// MLB team ID
-        #[clap(short, long)]
-        id: u32,
+        /// MLB team ID or abbreviation (e.g. 147 or NYY)
+        #[clap(short, long, visible_alias = "abbr", value_name = "ID|ABBR")]
+        id: String,
         
         /// Get schedule for the specified team
         #[clap(long)]
         schedule: bool,
+        
+        /// Also list the team's roster for this season
+        #[clap(long)]
+        roster: bool,
+        
+        /// Schedule output format; ics prints only an iCalendar file for importing into a calendar app
+        #[clap(long, value_enum, default_value = "text", requires = "schedule")]
+        format: ScheduleFormat,
     },
-    /// Get MLB game results
+    /// List all MLB teams with their IDs
+    Teams,
+    /// Get MLB game results, by game ID or by team and date
     Game {
         /// MLB game ID
-        #[clap(short, long)]
-        id: u64,
+        #[clap(short, long, required_unless_present_any = ["team", "from_file"], conflicts_with = "team")]
+        id: Option<u64>,
+        
+        /// Team whose game to show (requires --date)
+        #[clap(long, requires = "date")]
+        team: Option<u32>,
+        
+        /// Date of the team's game (YYYY-MM-DD)
+        #[clap(long, requires = "team", value_parser = parse_date)]
+        date: Option<NaiveDate>,
+        
+        /// Which game of a doubleheader to show
+        #[clap(long, requires = "team")]
+        game_number: Option<u32>,
         
         /// Show detailed pitching and hitting statistics
         #[clap(long)]
         detailed_stats: bool,
+        
+        /// List the plays on which runs scored
+        #[clap(long)]
+        plays: bool,
+        
+        /// Show the inning, count, outs, runners and matchup of a game being played
+        #[clap(long)]
+        live: bool,
+        
+        /// Show each team's top 3 batters by a stat: avg, hr, rbi, ops or sb
+        #[clap(long, value_name = "STAT")]
+        leaders: Option<mlb::BattingMetric>,
+        
+        /// Read the game from a saved feed/live JSON file instead of the API
+        #[clap(long, conflicts_with_all = ["id", "team"])]
+        from_file: Option<std::path::PathBuf>,
     },
     /// Get all MLB games being played today
     TodaysGames,
     /// Get all MLB games played yesterday
     YesterdayGames,
+    /// Get a batter's career line against a pitcher
+    Matchup {
+        /// MLB batter player ID
+        #[clap(long)]
+        batter: u32,
+        
+        /// MLB pitcher player ID
+        #[clap(long)]
+        pitcher: u32,
+    },
+    /// Get two teams' season series record and the games between them
+    #[clap(name = "h2h")]
+    HeadToHead {
+        /// First team's MLB ID
+        #[clap(long)]
+        team_a: u32,
+        
+        /// Second team's MLB ID
+        #[clap(long)]
+        team_b: u32,
+        
+        /// Season year (defaults to this season)
+        #[clap(long)]
+        season: Option<u32>,
+    },
+    /// Get division standings with run differential and Pythagorean record
+    Standings {
+        /// Only this league (103 = American League, 104 = National League)
+        #[clap(long)]
+        league: Option<u32>,
+        
+        /// Season year (defaults to this season)
+        #[clap(long)]
+        season: Option<u32>,
+    },
+    /// Get a team's pitching staff with season W-L, saves and ERA
+    PitchingStaff {
+        /// MLB team ID
+        #[clap(short, long)]
+        id: u32,
+        
+        /// Season to show (defaults to the current season)
+        #[clap(long)]
+        season: Option<u32>,
+    },
+}
+
+/// Parse a YYYY-MM-DD date argument
+fn parse_date(s: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|e| format!("invalid date '{}' ({}), expected YYYY-MM-DD", s, e))
+}
+
+/// Parse an IANA timezone name argument
+fn parse_timezone(s: &str) -> Result<Tz, String> {
+    s.parse().map_err(|_| format!("unknown timezone '{}', expected an IANA name such as America/New_York", s))
+}
+
+/// The schedule's date range: --next or --last counted from today, or else --start-date and --end-date
+fn schedule_window(args: &Args, today: NaiveDate) -> Result<(Option<String>, Option<String>)> {
+    // clap catches most conflicts, but not between global flags given on either side of a subcommand
+    let explicit = args.start_date.is_some() || args.end_date.is_some();
+    let (start, end) = match (args.next, args.last) {
+        (Some(_), _) | (_, Some(_)) if explicit => {
+            return Err(anyhow!("--next and --last can't be combined with --start-date or --end-date"))
+        }
+        (Some(_), Some(_)) => return Err(anyhow!("--next and --last can't be used together")),
+        (Some(0), _) | (_, Some(0)) => return Err(anyhow!("--next and --last need at least 1 day")),
+        (Some(days), _) => (Some(today), today.checked_add_days(Days::new(u64::from(days) - 1))),
+        (_, Some(days)) => (today.checked_sub_days(Days::new(u64::from(days))), today.pred_opt()),
+        (None, None) => return Ok((args.start_date.clone(), args.end_date.clone())),
+    };
+    let (start, end) = start.zip(end).ok_or_else(|| anyhow!("Schedule window is out of range"))?;
+    Ok((Some(start.format("%Y-%m-%d").to_string()), Some(end.format("%Y-%m-%d").to_string())))
+}
+
+/// Whether to report progress fetching game details: never with --quiet or output other than
+/// text, otherwise on a terminal, or wherever stderr goes with --progress
+fn progress_mode(args: &Args) -> ProgressMode {
+    if args.quiet || args.format != OutputFormat::Text {
+        ProgressMode::Never
+    } else if args.progress {
+        ProgressMode::Always
+    } else {
+        ProgressMode::Auto
+    }
+}
+
+/// Most detailed log level to show: --quiet leaves only warnings and errors, and each --verbose
+/// adds a level of detail beyond the default informational messages
+fn log_level(args: &Args) -> Level {
+    match (args.quiet, args.verbose) {
+        (true, _) => Level::WARN,
+        (false, 0) => Level::INFO,
+        (false, 1) => Level::DEBUG,
+        (false, _) => Level::TRACE,
+    }
+}
+
+/// Leagues that --leagues can select
+const LEAGUES: [&str; 3] = ["MLB", "NBA", "NHL"];
+
+/// Split --leagues values on commas and spaces into upper-case league names, rejecting unknown ones
+fn parse_leagues(values: &[String]) -> Result<Vec<String>, String> {
+    let names = values
+        .iter()
+        .flat_map(|value| value.split(|c: char| c == ',' || c.is_whitespace()))
+        .filter(|name| !name.is_empty());
+    let (known, unknown): (Vec<&str>, Vec<&str>) = names.partition(|name| LEAGUES.contains(&name.to_uppercase().as_str()));
+    if !unknown.is_empty() {
+        return Err(format!(
+            "unknown league{} '{}' for --leagues, valid leagues are {}",
+            if unknown.len() == 1 { "" } else { "s" },
+            unknown.join("', '"),
+            LEAGUES.join(", ")
+        ));
+    }
+    Ok(known.iter().map(|name| name.to_uppercase()).collect())
+}
+
+/// Output formats for a team schedule
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+enum ScheduleFormat {
+    Text,
+    Ics,
 }
 
 #[derive(Subcommand, Debug)]
 enum NBACommand {
-    /// Get NBA player stats
+    /// Get NBA player stats, or find a player's ID by name
     Player {
         /// NBA player ID
-        #[clap(short, long)]
-        id: u32,
+        #[clap(short, long, required_unless_present = "search")]
+        id: Option<u32>,
+        
+        /// Also show the player's per-game averages for this season
+        #[clap(long, requires = "id")]
+        season: Option<u32>,
+        
+        /// List the players whose name matches, with their IDs
+        #[clap(long, value_name = "NAME", conflicts_with = "id")]
+        search: Option<String>,
     },
     /// Get NBA team stats
     Team {
-        /// NBA team ID
-        #[clap(short, long)]
-        id: u32,
+        /// NBA team ID or abbreviation (e.g. 14 or LAL)
+        #[clap(short, long, visible_alias = "abbr", value_name = "ID|ABBR")]
+        id: String,
         
         /// Get schedule for the specified team
         #[clap(long)]
         schedule: bool,
+        
+        /// Schedule output format; ics prints only an iCalendar file for importing into a calendar app
+        #[clap(long, value_enum, default_value = "text", requires = "schedule")]
+        format: ScheduleFormat,
+        
+        /// Stop after fetching this many games of the schedule
+        #[clap(long, value_name = "N", requires = "schedule")]
+        limit: Option<usize>,
+    },
+    /// List all NBA teams with their IDs
+    Teams,
+    /// Get the conference standings with win percentage and games back
+    Standings {
+        /// Season, named for the year it began (defaults to this season)
+        #[clap(long)]
+        season: Option<u32>,
     },
     /// Get all NBA games being played today
     TodaysGames,
@@ -116,224 +450,660 @@ enum NBACommand {
     YesterdayGames,
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    // Initialize the logger
-    let subscriber = FmtSubscriber::builder()
-        .with_max_level(Level::INFO)
-        .finish();
-    tracing::subscriber::set_global_default(subscriber)?;
+#[derive(Subcommand, Debug)]
+enum NHLCommand {
+    /// Get NHL player information
+    Player {
+        /// NHL player ID
+        #[clap(short, long)]
+        id: u32,
+    },
+    /// Get NHL team information
+    Team {
+        /// NHL team ID or abbreviation (e.g. 18 or NSH)
+        #[clap(short, long, visible_alias = "abbr", value_name = "ID|ABBR")]
+        id: String,
+        
+        /// Get schedule for the specified team
+        #[clap(long)]
+        schedule: bool,
+    },
+    /// List all NHL teams with their IDs
+    Teams,
+    /// Get NHL game results with the period-by-period breakdown
+    Game {
+        /// NHL game ID
+        #[clap(short, long)]
+        id: u32,
+    },
+    /// Get all NHL games being played today
+    TodaysGames,
+    /// Get all NHL games played yesterday
+    YesterdayGames,
+}
 
-    // Parse command line arguments
-    let args = Args::parse();
+/// Game statuses that can be selected with the --only-* flags
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StatusFilter {
+    Final,
+    Live,
+    Scheduled,
+}
 
-    // Greet the user
-    if let Some(name) = args.name.as_ref() {
-        info!("Hello, {}! Welcome to Plaintext Sports!", name);
+impl StatusFilter {
+    /// The filter selected on the command line, if any
+    fn from_args(args: &Args) -> Option<Self> {
+        if args.only_final {
+            Some(StatusFilter::Final)
+        } else if args.only_live {
+            Some(StatusFilter::Live)
+        } else if args.only_scheduled {
+            Some(StatusFilter::Scheduled)
+        } else {
+            None
+        }
+    }
+
+    /// Whether a game in the given state passes the filter
+    fn matches(self, state: &GameState) -> bool {
+        match self {
+            StatusFilter::Final => *state == GameState::Final,
+            StatusFilter::Live => state.is_live(),
+            StatusFilter::Scheduled => *state == GameState::Scheduled,
+        }
+    }
+}
+
+/// Keep only the games whose state passes the filter; with no filter every game is kept
+fn filter_by_status<G>(games: Vec<G>, filter: Option<StatusFilter>, state: impl Fn(&G) -> GameState) -> Vec<G> {
+    match filter {
+        Some(filter) => games.into_iter().filter(|game| filter.matches(&state(game))).collect(),
+        None => games,
+    }
+}
+
+/// Keep only the games either of whose teams is a favorite
+fn filter_by_team<G>(games: Vec<G>, favorites: &[u32], teams: impl Fn(&G) -> (u32, u32)) -> Vec<G> {
+    games
+        .into_iter()
+        .filter(|game| {
+            let (away, home) = teams(game);
+            favorites.contains(&away) || favorites.contains(&home)
+        })
+        .collect()
+}
+
+/// Print a day's games for one league, with details for the finished ones, and return the games
+/// that were listed. A failed fetch is reported and recorded, and gives None.
+async fn show_games<L: League>(
+    league: &L,
+    fetched: Result<Vec<L::Game>>,
+    day: &GameDay,
+    args: &Args,
+    output: &Output,
+    errors: &mut ErrorSummary,
+) -> Option<Vec<L::Game>> {
+    let games = match fetched {
+        Ok(games) => {
+            let games = filter_by_status(games, StatusFilter::from_args(args), L::game_state);
+            if args.favorites {
+                filter_by_team(games, L::favorite_teams(config::get_config()), L::team_ids)
+            } else {
+                games
+            }
+        }
+        Err(e) => {
+            output.error(&format!("Error fetching {} {} games: {}", day.describe(), L::NAME, e));
+            errors.record(L::NAME, None, &format!("{} games", day.describe()), &e);
+            return None;
+        }
+    };
+    
+    // The games themselves make up the JSON output
+    if !output.is_text() {
+        return Some(games);
+    }
+    
+    println!("\n{}", day.heading(L::NAME));
+    if games.is_empty() {
+        println!("{}", day.empty_message(L::NAME));
+    } else if args.summary {
+        for game in &games {
+            println!("{}", L::summary_line(game));
+        }
     } else {
-        info!("Welcome to Plaintext Sports!");
+        show_game_list(league, &games, args, errors).await;
     }
+    
+    Some(games)
+}
 
-    // Determine which leagues to fetch based on the leagues argument
-    let fetch_mlb = args.leagues.as_ref().map_or(true, |leagues| leagues.iter().any(|l| l.to_uppercase() == "MLB"));
-    let fetch_nba = args.leagues.as_ref().map_or(true, |leagues| leagues.iter().any(|l| l.to_uppercase() == "NBA"));
+/// Fetch the selected leagues' games at the same time; a failure in one doesn't stop the others
+async fn fetch_games(
+    date: NaiveDate,
+    fetch_mlb: bool,
+    nba_api: Option<&nba::NbaApi>,
+    fetch_nhl: bool,
+) -> (Option<Result<Vec<mlb::Game>>>, Option<Result<Vec<nba::Game>>>, Option<Result<Vec<nhl::Game>>>) {
+    tokio::join!(
+        async { if fetch_mlb { Some(mlb::get_mlb_api().games_by_date(date).await) } else { None } },
+        async { if let Some(nba_api) = nba_api { Some(nba_api.games_by_date(date).await) } else { None } },
+        async { if fetch_nhl { Some(nhl::get_nhl_api().games_by_date(date).await) } else { None } },
+    )
+}
 
-    // Handle combined commands
-    if args.todays_games {
-        info!("Fetching today's games for selected leagues");
-        
-        // Fetch MLB games if selected
-        if fetch_mlb {
-            match mlb::get_todays_games().await {
-                Ok(games) => {
-                    println!("\nToday's MLB Games:");
-                    if games.is_empty() {
-                        println!("No MLB games scheduled for today.");
-                    } else {
-                        for (i, game) in games.iter().enumerate() {
-                            println!("\n==================================================");
-                            println!("Game {}: ID {}", i + 1, game.game_pk);
-                            println!("==================================================");
-                            println!("{}", game);
-                            
-                            // Debug the game status
-                            println!("DEBUG: Game status: {:?}, abstract_game_state: '{}'", 
-                                    game.status, game.status.abstract_game_state());
-                            
-                            // Only fetch data for completed games
-                            if game.status.abstract_game_state() == "Final" {
-                                // Always fetch inning-by-inning breakdown by default
-                                info!("Fetching inning-by-inning breakdown for game ID: {}", game.game_pk);
-                                match mlb::get_game_innings(game.game_pk).await {
-                                    Ok(innings_data) => {
-                                        println!("\nInning-by-Inning Breakdown:");
-                                        println!("{}", innings_data);
-                                    }
-                                    Err(e) => {
-                                        println!("Error fetching innings data: {}", e);
-                                    }
-                                }
-                                
-                                // Always fetch detailed stats if the flag is provided
-                                if args.detailed_stats {
-                                    info!("Fetching detailed stats for game ID: {}", game.game_pk);
-                                    match mlb::get_game_stats(game.game_pk).await {
-                                        Ok(stats) => {
-                                            println!("\nDetailed Statistics:");
-                                            println!("{}", stats);
-                                        }
-                                        Err(e) => {
-                                            println!("Error fetching detailed game stats: {}", e);
-                                        }
-                                    }
-                                }
-                            } else {
-                                println!("\nDetailed information not available for games that haven't been completed.");
-                            }
-                        }
-                    }
-                }
-                Err(e) => {
-                    println!("Error fetching today's MLB games: {}", e);
-                }
+/// Redraw a day's games every `every` seconds, but only when something has changed, until every
+/// game is final or the user presses Ctrl-C
+async fn watch_games(day: &GameDay, args: &Args, output: &Output, fetch_mlb: bool, nba_api: Option<&nba::NbaApi>, fetch_nhl: bool, every: u64) {
+    let mut interval = tokio::time::interval(Duration::from_secs(every.max(1)));
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    let mut last_snapshot = None;
+    
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = &mut ctrl_c => break,
+        }
+        
+        let (mlb_games, nba_games, nhl_games) = fetch_games(day.date, fetch_mlb, nba_api, fetch_nhl).await;
+        let all_final = all_final::<mlb::MlbApi>(&mlb_games)
+            && all_final::<nba::NbaApi>(&nba_games)
+            && all_final::<nhl::NhlApi>(&nhl_games);
+        let current_snapshot = (snapshot(&mlb_games), snapshot(&nba_games), snapshot(&nhl_games));
+        if last_snapshot.as_ref() != Some(&current_snapshot) {
+            last_snapshot = Some(current_snapshot);
+            
+            // Clear the screen and move the cursor to the top before redrawing
+            print!("\x1b[2J\x1b[H");
+            let mut errors = ErrorSummary::default();
+            if let Some(fetched) = mlb_games {
+                show_games(mlb::get_mlb_api(), fetched, day, args, output, &mut errors).await;
+            }
+            if let (Some(fetched), Some(nba_api)) = (nba_games, nba_api) {
+                show_games(nba_api, fetched, day, args, output, &mut errors).await;
+            }
+            if let Some(fetched) = nhl_games {
+                show_games(nhl::get_nhl_api(), fetched, day, args, output, &mut errors).await;
+            }
+            if let Some(summary) = errors.summary() {
+                output.error(&format!("\n{}", summary));
+            }
+            if !all_final {
+                println!("\nUpdated {}; refreshing every {}s (Ctrl-C to stop)", chrono::Local::now().format("%H:%M:%S"), every.max(1));
             }
         }
         
-        // Fetch NBA games if selected
-        if fetch_nba {
-            match nba::get_todays_games().await {
-                Ok(games) => {
-                    println!("\nToday's NBA Games:");
-                    if games.is_empty() {
-                        println!("No NBA games scheduled for today.");
-                    } else {
-                        for (i, game) in games.iter().enumerate() {
-                            println!("\n==================================================");
-                            println!("Game {}: ID {}", i + 1, game.id);
-                            println!("==================================================");
-                            println!("{}", game);
-                            
-                            // Fetch player stats for completed games
-                            if game.status == "Final" {
-                                info!("Fetching player stats for NBA game ID: {}", game.id);
-                                match nba::get_game_player_stats(game.id).await {
-                                    Ok(stats) => {
-                                        println!("\nPlayer Statistics:");
-                                        println!("{}", nba::display_game_player_stats(game.id, &stats));
-                                    }
-                                    Err(e) => {
-                                        println!("Error fetching player stats: {}", e);
-                                    }
-                                }
-                            } else {
-                                println!("\nDetailed player statistics not available for games that haven't been completed.");
-                            }
-                        }
+        if all_final {
+            println!("\nAll games are final.");
+            break;
+        }
+    }
+}
+
+// Whether a league's fetched games have all finished. A league that wasn't selected has nothing
+// left to watch, while one that failed to load is retried on the next refresh.
+fn all_final<L: League>(fetched: &Option<Result<Vec<L::Game>>>) -> bool {
+    match fetched {
+        None => true,
+        Some(Ok(games)) => games.iter().all(|game| L::game_state(game).is_final()),
+        Some(Err(_)) => false,
+    }
+}
+
+// What a league's listing currently says, for telling whether anything changed since the last refresh
+fn snapshot<T: Serialize>(fetched: &Option<Result<Vec<T>>>) -> Option<String> {
+    fetched.as_ref().map(|fetched| match fetched {
+        Ok(games) => serde_json::to_string(games).unwrap_or_default(),
+        Err(e) => e.to_string(),
+    })
+}
+
+/// Print each game under a numbered banner, followed by its details if it has finished
+async fn show_game_list<L: League>(league: &L, games: &[L::Game], args: &Args, errors: &mut ErrorSummary) {
+    let options = DetailOptions { compact: args.compact, detailed_stats: args.detailed_stats };
+    let progress = Progress::stderr(games.iter().filter(|game| L::game_state(game).is_final()).count(), "games", progress_mode(args));
+    
+    // Only completed games have details. They're fetched a few games at a time and come back in
+    // whatever order they finish, so they're keyed by position to print in listing order.
+    let mut details: HashMap<usize, Vec<DetailSection>> = stream::iter(games.iter().enumerate())
+        .filter(|(_, game)| future::ready(L::game_state(game).is_final()))
+        .map(|(i, game)| {
+            let progress = &progress;
+            async move {
+                info!("Fetching details for {} game ID: {}", L::NAME, L::game_id(game));
+                let sections = league.game_detail(game, options).await;
+                progress.tick();
+                (i, sections)
+            }
+        })
+        .buffer_unordered(config::get_config().detail_concurrency)
+        .collect()
+        .await;
+    
+    for (i, game) in games.iter().enumerate() {
+        let id = L::game_id(game);
+        println!("\n==================================================");
+        println!("Game {}: ID {}", i + 1, id);
+        println!("==================================================");
+        println!("{}", render(game, args.compact));
+        
+        if let Some(sections) = details.remove(&i) {
+            for section in sections {
+                match section.body {
+                    Ok(body) => {
+                        println!("\n{}:", section.heading);
+                        println!("{}", body);
+                    }
+                    Err(e) => {
+                        println!("Error fetching {}: {}", section.what, e);
+                        errors.record(L::NAME, Some(id), section.what, &e);
                     }
                 }
-                Err(e) => {
-                    println!("Error fetching today's NBA games: {}", e);
-                }
             }
+        } else {
+            println!("\n{}", L::PENDING_MESSAGE);
+        }
+    }
+}
+
+/// Where a command's results go. Text is printed as it's fetched, while JSON is collected and
+/// printed as a single document once the command has finished. CSV and markdown only cover box
+/// scores, which are printed as they're fetched; everything else is left out.
+struct Output {
+    format: OutputFormat,
+    document: serde_json::Map<String, serde_json::Value>,
+    wrote_table: bool,
+}
+
+impl Output {
+    fn new(format: OutputFormat) -> Self {
+        Self { format, document: serde_json::Map::new(), wrote_table: false }
+    }
+
+    fn is_text(&self) -> bool {
+        self.format == OutputFormat::Text
+    }
+
+    fn is_json(&self) -> bool {
+        self.format == OutputFormat::Json
+    }
+
+    fn is_csv(&self) -> bool {
+        self.format == OutputFormat::Csv
+    }
+
+    fn is_markdown(&self) -> bool {
+        self.format == OutputFormat::Markdown
+    }
+
+    /// Print a CSV or markdown table, separated from the one before it by a blank line
+    fn table(&mut self, table: &str) {
+        if self.wrote_table {
+            println!();
+        }
+        print!("{}", table);
+        self.wrote_table = true;
+    }
+
+    /// Add a value to the JSON document under a key; does nothing for text output
+    fn insert<T: Serialize>(&mut self, key: &str, value: &T) -> Result<()> {
+        if self.is_json() {
+            self.document.insert(key.to_string(), serde_json::to_value(value)?);
+        }
+        Ok(())
+    }
+
+    /// Print an error message, on stderr for JSON and table output so stdout stays a valid document
+    fn error(&self, message: &str) {
+        if !self.is_text() {
+            eprintln!("{}", message);
+        } else {
+            println!("{}", message);
+        }
+    }
+
+    /// Print a fetched value under a heading (or add it to the JSON document), or why it
+    /// couldn't be fetched
+    fn show_fetched<T: fmt::Display + Serialize>(&mut self, fetched: Result<T>, key: &str, heading: &str, what: &str) -> Result<()> {
+        self.show_rendered(fetched, false, key, heading, what)
+    }
+
+    /// Like `show_fetched`, using the value's single-line form for text output when compact
+    fn show_rendered<T: fmt::Display + Serialize>(
+        &mut self,
+        fetched: Result<T>,
+        compact: bool,
+        key: &str,
+        heading: &str,
+        what: &str,
+    ) -> Result<()> {
+        match fetched {
+            Ok(value) if !self.is_text() => self.insert(key, &value)?,
+            Ok(value) => {
+                println!("\n{}:", heading);
+                println!("{}", render(&value, compact));
+            }
+            Err(e) => self.error(&format!("Error fetching {}: {}", what, e)),
+        }
+        Ok(())
+    }
+
+    /// Print the JSON document; text and tables have already been printed as they were fetched
+    fn finish(self) -> Result<()> {
+        if self.is_json() {
+            println!("{}", serde_json::to_string_pretty(&self.document)?);
+        }
+        if self.format.is_table() && !self.wrote_table {
+            let name = self.format.name();
+            eprintln!("Nothing to write as {}: --format {} applies to box scores (mlb game --id ID)", name, name);
         }
+        Ok(())
     }
+}
+
+/// Format (division, id, abbreviation, name) rows as a listing grouped under division headings
+fn format_team_list<'a>(teams: impl IntoIterator<Item = (&'a str, u32, &'a str, &'a str)>) -> String {
+    let mut output = String::new();
+    let mut current_division = None;
+    
+    for (division, id, abbreviation, name) in teams {
+        if current_division != Some(division) {
+            let heading = if division.is_empty() { "Other" } else { division };
+            output.push_str(&format!("\n{}:\n", heading));
+            current_division = Some(division);
+        }
+        output.push_str(&format!("  {:>4}  {:<4} {}\n", id, abbreviation, name));
+    }
+    
+    output
+}
 
+/// A day of games, identified by how many days before today it falls (negative for future days)
+struct GameDay {
+    days_ago: i64,
+    date: NaiveDate,
+}
+
+impl GameDay {
+    /// Create a game day by subtracting `days_ago` days from `today`
+    fn new(today: NaiveDate, days_ago: u32) -> Result<Self> {
+        let date = today
+            .checked_sub_days(chrono::Days::new(days_ago as u64))
+            .ok_or_else(|| anyhow!("Failed to calculate the date {} days ago", days_ago))?;
+        Ok(Self { days_ago: days_ago.into(), date })
+    }
+
+    /// Create a game day for a specific date
+    fn on(today: NaiveDate, date: NaiveDate) -> Self {
+        Self { days_ago: (today - date).num_days(), date }
+    }
+
+    /// The date formatted as YYYY-MM-DD
+    fn date_string(&self) -> String {
+        self.date.format("%Y-%m-%d").to_string()
+    }
+
+    /// Describes the day for messages, e.g. "today's" or "2024-03-25"
+    fn describe(&self) -> String {
+        match self.days_ago {
+            0 => "today's".to_string(),
+            1 => "yesterday's".to_string(),
+            _ => self.date_string(),
+        }
+    }
+
+    /// Heading printed above a league's games
+    fn heading(&self, league: &str) -> String {
+        match self.days_ago {
+            0 => format!("Today's {} Games:", league),
+            1 => format!("Yesterday's {} Games:", league),
+            _ => format!("{} Games for {}:", league, self.date_string()),
+        }
+    }
+
+    /// Message printed when a league has no games on this day
+    fn empty_message(&self, league: &str) -> String {
+        match self.days_ago {
+            0 => format!("No {} games scheduled for today.", league),
+            1 => format!("No {} games played yesterday.", league),
+            n if n < 0 => format!("No {} games scheduled for {}.", league, self.date_string()),
+            _ => format!("No {} games played on {}.", league, self.date_string()),
+        }
+    }
+}
+
+/// A single error encountered while listing games
+struct RunError {
+    league: &'static str,
+    game_id: Option<u32>,
+    context: String,
+    message: String,
+}
+
+/// Accumulates errors across a multi-game run so they can be reported together
+#[derive(Default)]
+struct ErrorSummary {
+    errors: Vec<RunError>,
+}
+
+impl ErrorSummary {
+    /// Record an error, optionally tied to a specific game
+    fn record(&mut self, league: &'static str, game_id: Option<u32>, context: &str, err: &impl std::fmt::Display) {
+        self.errors.push(RunError {
+            league,
+            game_id,
+            context: context.to_string(),
+            message: err.to_string(),
+        });
+    }
+
+    /// Game IDs affected by errors, in the order they were encountered
+    fn game_ids(&self) -> Vec<u32> {
+        let mut ids = Vec::new();
+        for id in self.errors.iter().filter_map(|e| e.game_id) {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        ids
+    }
+
+    /// Renders the "Completed with N errors" summary, or None if the run had no errors
+    fn summary(&self) -> Option<String> {
+        if self.errors.is_empty() {
+            return None;
+        }
+        
+        let count = self.errors.len();
+        let mut output = format!("Completed with {} error{}", count, if count == 1 { "" } else { "s" });
+        let game_ids = self.game_ids();
+        if !game_ids.is_empty() {
+            let ids: Vec<String> = game_ids.iter().map(|id| id.to_string()).collect();
+            output.push_str(&format!(" (affected game IDs: {})", ids.join(", ")));
+        }
+        output.push(':');
+        
+        for error in &self.errors {
+            match error.game_id {
+                Some(id) => output.push_str(&format!("\n  {} game {} ({}): {}", error.league, id, error.context, error.message)),
+                None => output.push_str(&format!("\n  {} ({}): {}", error.league, error.context, error.message)),
+            }
+        }
+        Some(output)
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Parse command line arguments
+    let args = Args::parse();
+
+    // A completion script has to be the only thing printed, so it's written before logging starts
+    if let Some(Command::Completions { shell }) = args.command {
+        clap_complete::generate(shell, &mut Args::command(), "plaintext-sports", &mut std::io::stdout());
+        return Ok(());
+    }
+
+    // Initialize the logger. Logs share stdout with the output, except for a calendar export or
+    // JSON, which have to be the only thing on stdout so they can be redirected or parsed.
+    let ics_export = matches!(
+        args.command,
+        Some(Command::MLB { command: MLBCommand::Team { format: ScheduleFormat::Ics, .. } })
+            | Some(Command::NBA { command: NBACommand::Team { format: ScheduleFormat::Ics, .. } })
+    );
+    let writer = if ics_export || args.format != OutputFormat::Text {
+        BoxMakeWriter::new(std::io::stderr)
+    } else {
+        BoxMakeWriter::new(std::io::stdout)
+    };
+    let subscriber = FmtSubscriber::builder()
+        .with_max_level(log_level(&args))
+        .with_writer(writer)
+        .finish();
+    tracing::subscriber::set_global_default(subscriber)?;
+
+    // Command line flags take precedence over the environment and the config file
+    let mut app_config = Config::load()?;
+    let leagues = match args.leagues.as_ref().or(app_config.leagues.as_ref()).map(|leagues| parse_leagues(leagues)).transpose() {
+        Ok(leagues) => leagues,
+        Err(e) => Args::command().error(ErrorKind::InvalidValue, e).exit(),
+    };
+    app_config.home_first |= args.home_first;
+    if let Some(ref cache_dir) = args.cache_dir {
+        app_config.cache_dir = Some(cache_dir.clone());
+    }
+    app_config.cache_enabled &= !args.no_cache;
+    // A JSON document, CSV table or calendar file is never colored
+    app_config.color = args.color.enabled(app_config.color) && !ics_export && args.format == OutputFormat::Text;
+    if let Some(ref replay_dir) = args.replay_dir {
+        app_config.replay_dir = Some(replay_dir.clone());
+    }
+    app_config.dump_raw_dir = args.dump_raw.clone();
+    if let Some(timezone) = args.timezone {
+        app_config.timezone = Some(timezone);
+    }
+    config::init_config(app_config);
+
+    // A calendar export is printed as-is, whatever the output format
+    let mut output = Output::new(if ics_export { OutputFormat::Text } else { args.format });
+
+    // Greet the user
+    if output.is_text() {
+        if let Some(name) = args.name.as_ref() {
+            info!("Hello, {}! Welcome to Plaintext Sports!", name);
+        } else {
+            info!("Welcome to Plaintext Sports!");
+        }
+    }
+
+    if args.clear_cache {
+        match DiskCache::from_config() {
+            Some(cache) => match cache.clear() {
+                Ok(removed) => info!("Removed {} cached responses from {}", removed, cache.dir().display()),
+                Err(e) => output.error(&format!("Error clearing the cache: {}", e)),
+            },
+            None => output.error("No cache to clear; set a cache directory with --cache-dir or PLAINTEXT_SPORTS_CACHE_DIR"),
+        }
+    }
+
+    // Determine which leagues to fetch based on the leagues argument. The NHL is opt-in: the
+    // NHL Stats API it's read from was retired, so it's only asked when the NHL is named.
+    let mut fetch_mlb = leagues.as_ref().map_or(true, |leagues| leagues.iter().any(|l| l == "MLB"));
+    let mut fetch_nba = leagues.as_ref().map_or(true, |leagues| leagues.iter().any(|l| l == "NBA"));
+    let mut fetch_nhl = leagues.as_ref().is_some_and(|leagues| leagues.iter().any(|l| l == "NHL"));
+    let status_filter = StatusFilter::from_args(&args);
+
+    // --favorites skips leagues without any favorite teams
+    if args.favorites {
+        let app_config = config::get_config();
+        fetch_mlb &= !app_config.favorite_mlb_teams.is_empty();
+        fetch_nba &= !app_config.favorite_nba_teams.is_empty();
+        fetch_nhl &= !app_config.favorite_nhl_teams.is_empty();
+        if !fetch_mlb && !fetch_nba && !fetch_nhl {
+            output.error(&format!(
+                "No favorite teams set. Add their IDs to {}, e.g.\n  favorite_mlb_teams = [145]\n  favorite_nba_teams = [14]\n  favorite_nhl_teams = [18]",
+                config::config_file_path().display()
+            ));
+        }
+    }
+
+    // Handle combined commands; --todays-games and --yesterday-games are aliases for 0 and 1 days ago
+    let today = SystemClock.today();
+    let mut days = Vec::new();
+    if args.todays_games {
+        days.push(GameDay::new(today, 0)?);
+    }
     if args.yesterday_games {
-        info!("Fetching yesterday's games for selected leagues");
-        
-        // Fetch MLB games from yesterday if selected
-        if fetch_mlb {
-            match mlb::get_yesterdays_games().await {
-                Ok(games) => {
-                    println!("\nYesterday's MLB Games:");
-                    if games.is_empty() {
-                        println!("No MLB games played yesterday.");
-                    } else {
-                        for (i, game) in games.iter().enumerate() {
-                            println!("\n==================================================");
-                            println!("Game {}: ID {}", i + 1, game.game_pk);
-                            println!("==================================================");
-                            println!("{}", game);
-                            
-                            // Debug the game status
-                            println!("DEBUG: Game status: {:?}, abstract_game_state: '{}'", 
-                                    game.status, game.status.abstract_game_state());
-                            
-                            // Only fetch data for completed games
-                            if game.status.abstract_game_state() == "Final" {
-                                // Always fetch inning-by-inning breakdown by default
-                                info!("Fetching inning-by-inning breakdown for game ID: {}", game.game_pk);
-                                match mlb::get_game_innings(game.game_pk).await {
-                                    Ok(innings_data) => {
-                                        println!("\nInning-by-Inning Breakdown:");
-                                        println!("{}", innings_data);
-                                    }
-                                    Err(e) => {
-                                        println!("Error fetching innings data: {}", e);
-                                    }
-                                }
-                                
-                                // Always fetch detailed stats if the flag is provided
-                                if args.detailed_stats {
-                                    info!("Fetching detailed stats for game ID: {}", game.game_pk);
-                                    match mlb::get_game_stats(game.game_pk).await {
-                                        Ok(stats) => {
-                                            println!("\nDetailed Statistics:");
-                                            println!("{}", stats);
-                                        }
-                                        Err(e) => {
-                                            println!("Error fetching detailed game stats: {}", e);
-                                        }
-                                    }
-                                }
-                            } else {
-                                println!("\nDetailed information not available for games that haven't been completed.");
-                            }
-                        }
-                    }
-                }
-                Err(e) => {
-                    println!("Error fetching yesterday's MLB games: {}", e);
-                }
+        days.push(GameDay::new(today, 1)?);
+    }
+    if let Some(n) = args.days_ago {
+        days.push(GameDay::new(today, n)?);
+    }
+    if let Some(date) = args.date {
+        days.push(GameDay::on(today, date));
+    }
+    // Each day is listed once, however many of the flags select it
+    let mut selected = std::collections::HashSet::new();
+    days.retain(|day| selected.insert(day.date));
+
+    // Errors from multi-game listings are collected and summarized at the end of the run
+    let mut errors = ErrorSummary::default();
+
+    // Without an API key the NBA is left out of game listings rather than failing the other leagues
+    let nba_api = if fetch_nba && !days.is_empty() {
+        match nba::get_nba_api() {
+            Ok(nba_api) => Some(nba_api),
+            Err(e) => {
+                output.error(&e.to_string());
+                errors.record("NBA", None, "games", &e);
+                None
             }
         }
+    } else {
+        None
+    };
+
+    // Listed games are kept for writing to any --also destination
+    let mut report = GamesReport::default();
+
+    // Watching takes over today's listing; the JSON document is a single snapshot instead
+    if let Some(every) = args.watch.filter(|_| output.is_text()) {
+        watch_games(&GameDay::new(today, 0)?, &args, &output, fetch_mlb, nba_api, fetch_nhl, every).await;
+        days.clear();
+    }
+
+    for day in days {
+        let mut day_report = DayReport::new(day.date_string());
+        info!("Fetching {} games for selected leagues", day.describe());
         
-        // Fetch NBA games from yesterday if selected
-        if fetch_nba {
-            match nba::get_yesterdays_games().await {
-                Ok(games) => {
-                    println!("\nYesterday's NBA Games:");
-                    if games.is_empty() {
-                        println!("No NBA games played yesterday.");
-                    } else {
-                        for (i, game) in games.iter().enumerate() {
-                            println!("\n==================================================");
-                            println!("Game {}: ID {}", i + 1, game.id);
-                            println!("==================================================");
-                            println!("{}", game);
-                            
-                            // Fetch player stats for completed games
-                            if game.status == "Final" {
-                                info!("Fetching player stats for NBA game ID: {}", game.id);
-                                match nba::get_game_player_stats(game.id).await {
-                                    Ok(stats) => {
-                                        println!("\nPlayer Statistics:");
-                                        println!("{}", nba::display_game_player_stats(game.id, &stats));
-                                    }
-                                    Err(e) => {
-                                        println!("Error fetching player stats: {}", e);
-                                    }
-                                }
-                            } else {
-                                println!("\nDetailed player statistics not available for games that haven't been completed.");
-                            }
-                        }
-                    }
-                }
-                Err(e) => {
-                    println!("Error fetching yesterday's NBA games: {}", e);
-                }
-            }
+        let (mlb_games, nba_games, nhl_games) = fetch_games(day.date, fetch_mlb, nba_api, fetch_nhl).await;
+        
+        // Then show them in a fixed order: MLB, NBA, NHL
+        if let Some(fetched) = mlb_games {
+            day_report.mlb = show_games(mlb::get_mlb_api(), fetched, &day, &args, &output, &mut errors).await;
+        }
+        if let (Some(fetched), Some(nba_api)) = (nba_games, nba_api) {
+            day_report.nba = show_games(nba_api, fetched, &day, &args, &output, &mut errors).await;
+        }
+        if let Some(fetched) = nhl_games {
+            day_report.nhl = show_games(nhl::get_nhl_api(), fetched, &day, &args, &output, &mut errors).await;
+        }
+        
+        report.days.push(day_report);
+    }
+    if !report.days.is_empty() {
+        output.insert("days", &report.days)?;
+    }
+    
+    if let Some(ref spec) = args.also {
+        if report.days.is_empty() {
+            output.error(&format!("Nothing to write to {}: --also applies to game listings (--todays-games, --yesterday-games, --days-ago, --date)", spec.path.display()));
+        } else {
+            let rendered = spec.format.render(&report)?;
+            std::fs::write(&spec.path, rendered)
+                .map_err(|e| anyhow!("Failed to write {}: {}", spec.path.display(), e))?;
+            info!("Wrote games to {}", spec.path.display());
         }
     }
 
@@ -342,375 +1112,555 @@ async fn main() -> Result<()> {
         match command {
             Command::MLB { command } => {
                 match command {
-                    MLBCommand::Player { id } => {
+                    MLBCommand::Player { id, season, splits } => {
                         info!("Fetching stats for MLB player ID: {}", id);
-                        match mlb::get_player(*id).await {
-                            Ok(player_data) => {
-                                println!("\nMLB Player Information:");
-                                println!("{}", player_data);
-                            }
-                            Err(e) => {
-                                println!("Error fetching MLB player data: {}", e);
+                        output.show_fetched(mlb::get_player(*id).await, "player", "MLB Player Information", "MLB player data")?;
+                        
+                        if let Some(season) = season {
+                            info!("Fetching {} season stats for MLB player ID: {}", season, id);
+                            let mut stats = mlb::get_player_season_stats(*id, *season).await;
+                            // League averages for OPS+ and ERA+ are an extra request, so only made for --detailed-stats
+                            if args.detailed_stats && stats.is_ok() {
+                                info!("Fetching {} league averages", season);
+                                match mlb::get_league_averages(*season).await {
+                                    Ok(league) => stats = stats.map(|stats| stats.with_league_averages(&league)),
+                                    Err(e) => warn!("Showing season stats without OPS+ and ERA+: {}", e),
+                                }
                             }
+                            output.show_fetched(stats, "season_stats", "Season Statistics", "season stats")?;
+                        }
+                        
+                        if *splits {
+                            let season = season.unwrap_or(today.year() as u32);
+                            info!("Fetching {} splits for MLB player ID: {}", season, id);
+                            output.show_fetched(mlb::get_player_splits(*id, season).await, "splits", "Platoon Splits", "player splits")?;
                         }
                     },
-                    MLBCommand::Team { id, schedule } => {
-                        info!("Fetching stats for MLB team ID: {}", id);
-                        match mlb::get_team(*id).await {
-                            Ok(team_data) => {
-                                println!("\nMLB Team Information:");
-                                println!("{}", team_data);
-                            }
-                            Err(e) => {
-                                println!("Error fetching MLB team data: {}", e);
-                            }
+                    MLBCommand::Team { id, schedule, roster, format } => {
+                        let id = &resolve_team_id(mlb::get_mlb_api(), id).await?;
+                        // A calendar export is the only thing written to stdout so it can be saved directly
+                        let ics = *format == ScheduleFormat::Ics;
+                        
+                        if !ics {
+                            info!("Fetching stats for MLB team ID: {}", id);
+                            output.show_fetched(mlb::get_team(*id).await, "team", "MLB Team Information", "MLB team data")?;
+                        }
+                        
+                        if *roster && !ics {
+                            info!("Fetching roster for MLB team ID: {}", id);
+                            output.show_fetched(mlb::get_team_roster(*id, today.year() as u32).await, "roster", "MLB Roster", "roster")?;
                         }
 
                         // Handle schedule request if --schedule flag is provided
                         if *schedule {
                             info!("Fetching schedule for MLB team ID: {}", id);
-                            match mlb::get_team_schedule(*id, args.start_date.clone(), args.end_date.clone()).await {
+                            let (start_date, end_date) = schedule_window(&args, today)?;
+                            match mlb::get_team_schedule(*id, start_date.clone(), end_date.clone()).await {
                                 Ok(games) => {
-                                    println!("\nMLB Schedule:");
-                                    if games.is_empty() {
-                                        println!("No games scheduled for the specified period.");
+                                    let games = filter_by_status(games, status_filter, |game: &mlb::Game| game.status.clone());
+                                    if ics {
+                                        print!("{}", calendar::to_ics(&games));
+                                    } else if !output.is_text() {
+                                        output.insert("schedule", &games)?;
                                     } else {
-                                        for (i, game) in games.iter().enumerate() {
-                                            println!("\nGame {}:", i + 1);
-                                            println!("{}", game);
+                                        println!("\nMLB Schedule:");
+                                        if games.is_empty() {
+                                            println!("No games scheduled for the specified period.");
+                                        } else {
+                                            for (i, game) in games.iter().enumerate() {
+                                                println!("\nGame {}:", i + 1);
+                                                println!("{}", render(game, args.compact));
+                                            }
                                         }
                                     }
                                 }
                                 Err(e) => {
-                                    println!("Error fetching MLB schedule: {}", e);
+                                    output.error(&format!("Error fetching MLB schedule: {}", e));
+                                    errors.record("MLB", None, "schedule", &e);
                                 }
                             }
                         }
                     },
-                    MLBCommand::Game { id, detailed_stats } => {
-                        info!("Fetching results for MLB game ID: {}", id);
-                        match mlb::get_game(*id).await {
-                            Ok(game_data) => {
-                                println!("\nGame Information:");
-                                println!("{}", game_data);
+                    MLBCommand::Teams => {
+                        info!("Fetching all MLB teams");
+                        match mlb::get_all_teams().await {
+                            Ok(teams) if !output.is_text() => output.insert("teams", &teams)?,
+                            Ok(teams) => {
+                                println!("\nMLB Teams:");
+                                print!("{}", format_team_list(teams.iter().map(|team| (
+                                    team.division.as_ref().map_or("", |division| division.name.as_str()),
+                                    team.id,
+                                    team.abbreviation.as_deref().unwrap_or(""),
+                                    team.name.as_str(),
+                                ))));
                             }
                             Err(e) => {
-                                println!("Error fetching game data: {}", e);
+                                output.error(&format!("Error fetching MLB teams: {}", e));
                             }
                         }
-                        
-                        // Always fetch inning-by-inning breakdown by default
-                        info!("Fetching inning-by-inning breakdown for game ID: {}", id);
-                        match mlb::get_game_innings(*id as u32).await {
-                            Ok(innings_data) => {
-                                println!("\nInning-by-Inning Breakdown:");
-                                println!("{}", innings_data);
-                            }
-                            Err(e) => {
-                                println!("Error fetching innings data: {}", e);
+                    },
+                    MLBCommand::Game { id, team, date, game_number, detailed_stats, plays, live, leaders, from_file } => {
+                        if let Some(path) = from_file {
+                            info!("Reading MLB game from {}", path.display());
+                            match mlb::read_game_file(path) {
+                                Ok(game) if args.summary && output.is_text() => println!("{}", game.summary_line()),
+                                fetched => output.show_fetched(fetched, "game", "Game Information", "game data")?,
                             }
                         }
                         
-                        // Always fetch detailed stats if the flag is provided
-                        if *detailed_stats {
-                            info!("Fetching detailed statistics for game ID: {}", id);
-                            match mlb::get_game_stats(*id as u32).await {
-                                Ok(stats) => {
-                                    println!("\nDetailed Statistics:");
-                                    println!("{}", stats);
-                                }
-                                Err(e) => {
-                                    println!("Error fetching detailed game stats: {}", e);
+                        // Resolve a team and date to the game's ID
+                        let game_id = match (id, team, date) {
+                            (Some(id), _, _) => Some(*id),
+                            (None, Some(team), Some(date)) => {
+                                info!("Looking up MLB team {}'s game on {}", team, date);
+                                match mlb::find_team_game(*team, *date, *game_number).await {
+                                    Ok(game) => Some(u64::from(game.game_pk)),
+                                    Err(e) => {
+                                        output.error(&format!("Error finding game: {}", e));
+                                        None
+                                    }
                                 }
                             }
-                        }
-                    },
-                    MLBCommand::TodaysGames => {
-                        info!("Fetching all MLB games scheduled for today");
-                        match mlb::get_todays_games().await {
-                            Ok(games) => {
-                                println!("\nToday's MLB Games:");
-                                if games.is_empty() {
-                                    println!("No games scheduled for today.");
-                                } else {
-                                    for (i, game) in games.iter().enumerate() {
-                                        println!("\n==================================================");
-                                        println!("Game {}: ID {}", i + 1, game.game_pk);
-                                        println!("==================================================");
-                                        println!("{}", game);
-                                        
-                                        // Debug the game status
-                                        println!("DEBUG: Game status: {:?}, abstract_game_state: '{}'", 
-                                                game.status, game.status.abstract_game_state());
-                                        
-                                        // Only fetch data for completed games
-                                        if game.status.abstract_game_state() == "Final" {
-                                            // Always fetch inning-by-inning breakdown by default
-                                            info!("Fetching inning-by-inning breakdown for game ID: {}", game.game_pk);
-                                            match mlb::get_game_innings(game.game_pk).await {
-                                                Ok(innings_data) => {
-                                                    println!("\nInning-by-Inning Breakdown:");
-                                                    println!("{}", innings_data);
-                                                }
-                                                Err(e) => {
-                                                    println!("Error fetching innings data: {}", e);
-                                                }
-                                            }
-                                            
-                                            // Always fetch detailed stats if the flag is provided
-                                            if args.detailed_stats {
-                                                info!("Fetching detailed stats for game ID: {}", game.game_pk);
-                                                match mlb::get_game_stats(game.game_pk).await {
-                                                    Ok(stats) => {
-                                                        println!("\nDetailed Statistics:");
-                                                        println!("{}", stats);
-                                                    }
-                                                    Err(e) => {
-                                                        println!("Error fetching detailed game stats: {}", e);
-                                                    }
-                                                }
-                                            }
-                                        } else {
-                                            println!("\nDetailed information not available for games that haven't been completed.");
+                            (None, None, _) if from_file.is_some() => None,
+                            _ => unreachable!("clap requires --id, --team with --date, or --from-file"),
+                        };
+                        
+                        if let Some(id) = game_id.filter(|_| args.summary && output.is_text()) {
+                            info!("Fetching result for MLB game ID: {}", id);
+                            match mlb::get_game(id as u32).await {
+                                Ok(game) => println!("{}", game.summary_line()),
+                                Err(e) => output.error(&format!("Error fetching game data: {}", e)),
+                            }
+                        } else if let Some(id) = game_id.filter(|_| output.is_csv()) {
+                            // The box score is the only part of a game with a CSV form
+                            info!("Fetching detailed statistics for game ID: {}", id);
+                            match mlb::get_game_stats(id as u32).await {
+                                Ok(stats) => output.table(&stats.to_csv()),
+                                Err(e) => output.error(&format!("Error fetching detailed game stats: {}", e)),
+                            }
+                        } else if let Some(id) = game_id.filter(|_| output.is_markdown()) {
+                            // The line score and box score as markdown tables
+                            info!("Fetching inning-by-inning breakdown for game ID: {}", id);
+                            match mlb::get_game_innings(id as u32).await {
+                                Ok(innings) => output.table(&innings.to_markdown()),
+                                Err(e) => output.error(&format!("Error fetching innings data: {}", e)),
+                            }
+                            info!("Fetching detailed statistics for game ID: {}", id);
+                            match mlb::get_game_stats(id as u32).await {
+                                Ok(stats) => output.table(&stats.to_markdown()),
+                                Err(e) => output.error(&format!("Error fetching detailed game stats: {}", e)),
+                            }
+                        } else if let Some(id) = game_id {
+                            info!("Fetching results for MLB game ID: {}", id);
+                            output.show_fetched(mlb::get_game(id as u32).await, "game", "Game Information", "game data")?;
+                            
+                            if *live {
+                                info!("Fetching live situation for game ID: {}", id);
+                                output.show_fetched(mlb::get_live_game_state(id as u32).await, "live", "Live Situation", "live game data")?;
+                            }
+                            
+                            // Always fetch inning-by-inning breakdown by default
+                            info!("Fetching inning-by-inning breakdown for game ID: {}", id);
+                            let innings = mlb::get_game_innings(id as u32).await;
+                            output.show_rendered(innings, args.compact, "innings", "Inning-by-Inning Breakdown", "innings data")?;
+                            
+                            // Always fetch detailed stats if the flag is provided
+                            if *detailed_stats {
+                                info!("Fetching detailed statistics for game ID: {}", id);
+                                output.show_fetched(mlb::get_game_stats(id as u32).await, "stats", "Detailed Statistics", "detailed game stats")?;
+                            }
+                            
+                            if let Some(metric) = leaders {
+                                info!("Fetching {} leaders for game ID: {}", metric, id);
+                                let leaders = mlb::get_game_stats(id as u32).await.map(|stats| stats.leaders(*metric, 3));
+                                output.show_fetched(leaders, "leaders", "Team Leaders", "detailed game stats")?;
+                            }
+                            
+                            if *plays {
+                                info!("Fetching scoring plays for game ID: {}", id);
+                                match mlb::get_scoring_plays(id as u32).await {
+                                    Ok(plays) if !output.is_text() => output.insert("scoring_plays", &plays)?,
+                                    Ok(plays) => {
+                                        println!("\nScoring Plays:");
+                                        if plays.is_empty() {
+                                            println!("No runs scored.");
                                         }
+                                        for play in &plays {
+                                            println!("{}", play);
+                                        }
+                                    }
+                                    Err(e) => {
+                                        output.error(&format!("Error fetching scoring plays: {}", e));
                                     }
                                 }
                             }
-                            Err(e) => {
-                                println!("Error fetching today's games: {}", e);
-                            }
                         }
                     },
+                    MLBCommand::TodaysGames => {
+                        info!("Fetching all MLB games scheduled for today");
+                        let mlb_api = mlb::get_mlb_api();
+                        let games = show_games(mlb_api, mlb_api.todays_games().await, &GameDay::new(today, 0)?, &args, &output, &mut errors).await;
+                        output.insert("games", &games)?;
+                    },
                     MLBCommand::YesterdayGames => {
                         info!("Fetching all MLB games from yesterday");
-                        match mlb::get_yesterdays_games().await {
-                            Ok(games) => {
-                                println!("\nYesterday's MLB Games:");
-                                if games.is_empty() {
-                                    println!("No games played yesterday.");
-                                } else {
-                                    for (i, game) in games.iter().enumerate() {
-                                        println!("\n==================================================");
-                                        println!("Game {}: ID {}", i + 1, game.game_pk);
-                                        println!("==================================================");
-                                        println!("{}", game);
-                                        
-                                        // Debug the game status
-                                        println!("DEBUG: Game status: {:?}, abstract_game_state: '{}'", 
-                                                game.status, game.status.abstract_game_state());
-                                        
-                                        // Only fetch data for completed games
-                                        if game.status.abstract_game_state() == "Final" {
-                                            // Always fetch inning-by-inning breakdown by default
-                                            info!("Fetching inning-by-inning breakdown for game ID: {}", game.game_pk);
-                                            match mlb::get_game_innings(game.game_pk).await {
-                                                Ok(innings_data) => {
-                                                    println!("\nInning-by-Inning Breakdown:");
-                                                    println!("{}", innings_data);
-                                                }
-                                                Err(e) => {
-                                                    println!("Error fetching innings data: {}", e);
-                                                }
-                                            }
-                                            
-                                            // Always fetch detailed stats if the flag is provided
-                                            if args.detailed_stats {
-                                                info!("Fetching detailed stats for game ID: {}", game.game_pk);
-                                                match mlb::get_game_stats(game.game_pk).await {
-                                                    Ok(stats) => {
-                                                        println!("\nDetailed Statistics:");
-                                                        println!("{}", stats);
-                                                    }
-                                                    Err(e) => {
-                                                        println!("Error fetching detailed game stats: {}", e);
-                                                    }
-                                                }
-                                            }
-                                        } else {
-                                            println!("\nDetailed information not available for games that haven't been completed.");
-                                        }
-                                    }
+                        let mlb_api = mlb::get_mlb_api();
+                        let games = show_games(mlb_api, mlb_api.yesterdays_games().await, &GameDay::new(today, 1)?, &args, &output, &mut errors).await;
+                        output.insert("games", &games)?;
+                    },
+                    MLBCommand::Matchup { batter, pitcher } => {
+                        info!("Fetching matchup for MLB batter ID {} vs pitcher ID {}", batter, pitcher);
+                        output.show_fetched(mlb::get_matchup(*batter, *pitcher).await, "matchup", "Batter vs. Pitcher", "matchup data")?;
+                    },
+                    MLBCommand::HeadToHead { team_a, team_b, season } => {
+                        let season = season.unwrap_or(today.year() as u32);
+                        info!("Fetching {} series between MLB teams {} and {}", season, team_a, team_b);
+                        output.show_fetched(mlb::head_to_head(*team_a, *team_b, season).await, "head_to_head", &format!("{} Head-to-Head", season), "head-to-head data")?;
+                    },
+                    MLBCommand::Standings { league, season } => {
+                        info!("Fetching MLB standings");
+                        match mlb::get_standings(*league, *season).await {
+                            Ok(standings) if !output.is_text() => output.insert("standings", &standings)?,
+                            Ok(standings) => {
+                                println!("\nMLB Standings:");
+                                for division in &standings {
+                                    println!("\n{}", division);
                                 }
                             }
                             Err(e) => {
-                                println!("Error fetching yesterday's games: {}", e);
+                                output.error(&format!("Error fetching standings: {}", e));
                             }
                         }
                     },
+                    MLBCommand::PitchingStaff { id, season } => {
+                        info!("Fetching pitching staff for MLB team ID: {}", id);
+                        output.show_fetched(mlb::get_team_pitching_staff(*id, *season).await, "pitching_staff", "MLB Pitching Staff", "pitching staff")?;
+                    },
                 }
             },
             Command::NBA { command } => {
+                // Every NBA request needs the API key, so a missing one is reported once and ends the run
+                let nba_api = match nba::get_nba_api() {
+                    Ok(nba_api) => nba_api,
+                    Err(e) => {
+                        output.error(&e.to_string());
+                        std::process::exit(1);
+                    }
+                };
                 match command {
-                    NBACommand::Player { id } => {
+                    NBACommand::Player { id: None, search: Some(query), .. } => {
+                        info!("Searching NBA players for '{}'", query);
+                        match nba::search_players(query).await {
+                            Ok(players) if !output.is_text() => output.insert("players", &players)?,
+                            Ok(players) => {
+                                println!("\nNBA Players Matching '{}':", query.trim());
+                                if players.is_empty() {
+                                    println!("No players found.");
+                                }
+                                for player in &players {
+                                    println!("{}", player.search_line());
+                                }
+                            }
+                            Err(e) => output.error(&format!("Error searching NBA players: {}", e)),
+                        }
+                    },
+                    NBACommand::Player { id: Some(id), season, .. } => {
                         info!("Fetching stats for NBA player ID: {}", id);
                         match nba::get_player(*id).await {
                             Ok(player_data) => {
-                                println!("\nNBA Player Information:");
-                                println!("{}", player_data);
+                                if output.is_text() {
+                                    println!("\nNBA Player Information:");
+                                    println!("{}", player_data);
+                                }
+                                output.insert("player", &player_data)?;
+                                
+                                // Show the player's current injury status
+                                match nba::get_player_injuries(Some(player_data.team.id)).await {
+                                    Ok(injuries) => {
+                                        let status = nba::injury_status(&injuries, player_data.id);
+                                        if output.is_text() {
+                                            println!("Status: {}", status);
+                                        }
+                                        output.insert("status", &status)?;
+                                    }
+                                    Err(e) => {
+                                        output.error(&format!("Error fetching injury status: {}", e));
+                                    }
+                                }
+                                
+                                if let Some(season) = season {
+                                    info!("Fetching {} season averages for NBA player ID: {}", season, id);
+                                    match nba::get_player_season_averages(*id, *season).await {
+                                        Ok(Some(averages)) => output.show_fetched(Ok(averages), "season_averages", &format!("{} Season Averages", season), "season averages")?,
+                                        Ok(None) => {
+                                            if output.is_text() {
+                                                println!("\nNo {} season averages: {} {} didn't play that season.", season, player_data.first_name, player_data.last_name);
+                                            }
+                                            output.insert("season_averages", &None::<nba::SeasonAverages>)?;
+                                        }
+                                        Err(e) => output.error(&format!("Error fetching season averages: {}", e)),
+                                    }
+                                }
                             }
                             Err(e) => {
-                                println!("Error fetching NBA player data: {}", e);
+                                output.error(&format!("Error fetching NBA player data: {}", e));
                             }
                         }
                     },
-                    NBACommand::Team { id, schedule } => {
-                        info!("Fetching stats for NBA team ID: {}", id);
-                        match nba::get_team(*id).await {
-                            Ok(team_data) => {
-                                println!("\nNBA Team Information:");
-                                println!("{}", team_data);
-                            }
-                            Err(e) => {
-                                println!("Error fetching NBA team data: {}", e);
-                            }
+                    NBACommand::Player { .. } => unreachable!("clap requires --id or --search"),
+                    NBACommand::Team { id, schedule, format, limit } => {
+                        let id = &resolve_team_id(nba_api, id).await?;
+                        // A calendar export is the only thing written to stdout so it can be saved directly
+                        let ics = *format == ScheduleFormat::Ics;
+                        
+                        if !ics {
+                            info!("Fetching stats for NBA team ID: {}", id);
+                            output.show_fetched(nba::get_team(*id).await, "team", "NBA Team Information", "NBA team data")?;
                         }
 
                         // Handle schedule request if --schedule flag is provided
                         if *schedule {
                             info!("Fetching schedule for NBA team ID: {}", id);
-                            match nba::get_team_games(*id, args.start_date.clone(), args.end_date.clone()).await {
-                                Ok(games) => {
-                                    println!("\nNBA Schedule:");
-                                    if games.is_empty() {
-                                        println!("No games scheduled for the specified period.");
+                            let (start_date, end_date) = schedule_window(&args, today)?;
+                            match nba::get_team_games(*id, start_date.clone(), end_date.clone(), *limit).await {
+                                Ok(team_games) => {
+                                    if team_games.truncated {
+                                        warn!("Stopped after the first {} games; there are more in this window", team_games.games.len());
+                                    }
+                                    let games = filter_by_status(team_games.games, status_filter, nba::Game::game_state);
+                                    if ics {
+                                        print!("{}", calendar::to_ics(&games));
+                                    } else if !output.is_text() {
+                                        output.insert("schedule", &games)?;
                                     } else {
-                                        for (i, game) in games.iter().enumerate() {
-                                            println!("\n==================================================");
-                                            println!("Game {}: ID {}", i + 1, game.id);
-                                            println!("==================================================");
-                                            println!("{}", game);
-                                            
-                                            // Fetch player stats for completed games
-                                            if game.status == "Final" {
-                                                info!("Fetching player stats for NBA game ID: {}", game.id);
-                                                match nba::get_game_player_stats(game.id).await {
-                                                    Ok(stats) => {
-                                                        println!("\nPlayer Statistics:");
-                                                        println!("{}", nba::display_game_player_stats(game.id, &stats));
-                                                    }
-                                                    Err(e) => {
-                                                        println!("Error fetching player stats: {}", e);
-                                                    }
-                                                }
-                                            } else {
-                                                println!("\nDetailed player statistics not available for games that haven't been completed.");
-                                            }
+                                        println!("\nNBA Schedule:");
+                                        if games.is_empty() {
+                                            println!("No games scheduled for the specified period.");
+                                        } else {
+                                            show_game_list(nba_api, &games, &args, &mut errors).await;
+                                            println!("\n{}", nba::summarize_team_games(*id, &games));
                                         }
                                     }
                                 }
                                 Err(e) => {
-                                    println!("Error fetching NBA schedule: {}", e);
+                                    output.error(&format!("Error fetching NBA schedule: {}", e));
+                                    errors.record("NBA", None, "schedule", &e);
                                 }
                             }
                         }
                     },
-                    NBACommand::TodaysGames => {
-                        info!("Fetching all NBA games for today");
-                        match nba::get_todays_games().await {
-                            Ok(games) => {
-                                println!("\nToday's NBA Games:");
-                                if games.is_empty() {
-                                    println!("No NBA games scheduled for today.");
-                                } else {
-                                    for (i, game) in games.iter().enumerate() {
-                                        println!("\n==================================================");
-                                        println!("Game {}: ID {}", i + 1, game.id);
-                                        println!("==================================================");
-                                        println!("{}", game);
-                                        
-                                        // Fetch player stats for completed games
-                                        if game.status == "Final" {
-                                            info!("Fetching player stats for NBA game ID: {}", game.id);
-                                            match nba::get_game_player_stats(game.id).await {
-                                                Ok(stats) => {
-                                                    println!("\nPlayer Statistics:");
-                                                    println!("{}", nba::display_game_player_stats(game.id, &stats));
-                                                }
-                                                Err(e) => {
-                                                    println!("Error fetching player stats: {}", e);
-                                                }
-                                            }
-                                        } else {
-                                            println!("\nDetailed player statistics not available for games that haven't been completed.");
-                                        }
-                                    }
-                                }
+                    NBACommand::Teams => {
+                        info!("Fetching all NBA teams");
+                        match nba::get_all_teams().await {
+                            Ok(teams) if !output.is_text() => output.insert("teams", &teams)?,
+                            Ok(teams) => {
+                                println!("\nNBA Teams:");
+                                print!("{}", format_team_list(teams.iter().map(|team| (
+                                    team.division.as_str(),
+                                    team.id,
+                                    team.abbreviation.as_str(),
+                                    team.full_name.as_str(),
+                                ))));
                             }
                             Err(e) => {
-                                println!("Error fetching today's NBA games: {}", e);
+                                output.error(&format!("Error fetching NBA teams: {}", e));
                             }
                         }
                     },
+                    NBACommand::Standings { season } => {
+                        let season = season.unwrap_or_else(|| nba::current_season(today));
+                        info!("Fetching NBA standings for the {} season", season);
+                        output.show_fetched(nba::get_standings(season).await, "standings", "NBA Standings", "standings")?;
+                    },
+                    NBACommand::TodaysGames => {
+                        info!("Fetching all NBA games for today");
+                        let games = show_games(nba_api, nba_api.todays_games().await, &GameDay::new(today, 0)?, &args, &output, &mut errors).await;
+                        output.insert("games", &games)?;
+                    },
                     NBACommand::YesterdayGames => {
                         info!("Fetching all NBA games from yesterday");
-                        match nba::get_yesterdays_games().await {
-                            Ok(games) => {
-                                println!("\nYesterday's NBA Games:");
-                                if games.is_empty() {
-                                    println!("No NBA games played yesterday.");
-                                } else {
-                                    for (i, game) in games.iter().enumerate() {
-                                        println!("\n==================================================");
-                                        println!("Game {}: ID {}", i + 1, game.id);
-                                        println!("==================================================");
-                                        println!("{}", game);
-                                        
-                                        // Fetch player stats for completed games
-                                        if game.status == "Final" {
-                                            info!("Fetching player stats for NBA game ID: {}", game.id);
-                                            match nba::get_game_player_stats(game.id).await {
-                                                Ok(stats) => {
-                                                    println!("\nPlayer Statistics:");
-                                                    println!("{}", nba::display_game_player_stats(game.id, &stats));
-                                                }
-                                                Err(e) => {
-                                                    println!("Error fetching player stats: {}", e);
-                                                }
-                                            }
+                        let games = show_games(nba_api, nba_api.yesterdays_games().await, &GameDay::new(today, 1)?, &args, &output, &mut errors).await;
+                        output.insert("games", &games)?;
+                    },
+                }
+            },
+            Command::NHL { command } => {
+                match command {
+                    NHLCommand::Player { id } => {
+                        info!("Fetching information for NHL player ID: {}", id);
+                        output.show_fetched(nhl::get_player(*id).await, "player", "NHL Player Information", "NHL player data")?;
+                    },
+                    NHLCommand::Team { id, schedule } => {
+                        let id = &resolve_team_id(nhl::get_nhl_api(), id).await?;
+                        info!("Fetching information for NHL team ID: {}", id);
+                        output.show_fetched(nhl::get_team(*id).await, "team", "NHL Team Information", "NHL team data")?;
+                        
+                        if *schedule {
+                            info!("Fetching schedule for NHL team ID: {}", id);
+                            let (start_date, end_date) = schedule_window(&args, today)?;
+                            match nhl::get_team_schedule(*id, start_date, end_date).await {
+                                Ok(games) => {
+                                    let games = filter_by_status(games, status_filter, |game: &nhl::Game| game.status.clone());
+                                    if !output.is_text() {
+                                        output.insert("schedule", &games)?;
+                                    } else {
+                                        println!("\nNHL Schedule:");
+                                        if games.is_empty() {
+                                            println!("No games scheduled for the specified period.");
                                         } else {
-                                            println!("\nDetailed player statistics not available for games that haven't been completed.");
+                                            for (i, game) in games.iter().enumerate() {
+                                                println!("\nGame {}:", i + 1);
+                                                println!("{}", render(game, args.compact));
+                                            }
                                         }
                                     }
                                 }
+                                Err(e) => {
+                                    output.error(&format!("Error fetching NHL schedule: {}", e));
+                                    errors.record("NHL", None, "schedule", &e);
+                                }
+                            }
+                        }
+                    },
+                    NHLCommand::Teams => {
+                        info!("Fetching all NHL teams");
+                        match nhl::get_all_teams().await {
+                            Ok(teams) if !output.is_text() => output.insert("teams", &teams)?,
+                            Ok(teams) => {
+                                println!("\nNHL Teams:");
+                                print!("{}", format_team_list(teams.iter().map(|team| (
+                                    team.division.as_ref().map_or("", |division| division.name.as_str()),
+                                    team.id,
+                                    team.abbreviation.as_deref().unwrap_or(""),
+                                    team.name.as_str(),
+                                ))));
                             }
                             Err(e) => {
-                                println!("Error fetching yesterday's NBA games: {}", e);
+                                output.error(&format!("Error fetching NHL teams: {}", e));
                             }
                         }
                     },
+                    NHLCommand::Game { id } => {
+                        info!("Fetching results for NHL game ID: {}", id);
+                        match nhl::get_game(*id).await {
+                            Ok(game) if args.summary && output.is_text() => println!("{}", game.summary_line()),
+                            fetched => {
+                                output.show_fetched(fetched, "game", "Game Information", "game data")?;
+                                
+                                info!("Fetching period-by-period breakdown for game ID: {}", id);
+                                let linescore = nhl::get_game_linescore(*id).await;
+                                output.show_rendered(linescore, args.compact, "linescore", "Period-by-Period Breakdown", "period data")?;
+                            }
+                        }
+                    },
+                    NHLCommand::TodaysGames => {
+                        info!("Fetching all NHL games for today");
+                        let nhl_api = nhl::get_nhl_api();
+                        let games = show_games(nhl_api, nhl_api.todays_games().await, &GameDay::new(today, 0)?, &args, &output, &mut errors).await;
+                        output.insert("games", &games)?;
+                    },
+                    NHLCommand::YesterdayGames => {
+                        info!("Fetching all NHL games from yesterday");
+                        let nhl_api = nhl::get_nhl_api();
+                        let games = show_games(nhl_api, nhl_api.yesterdays_games().await, &GameDay::new(today, 1)?, &args, &output, &mut errors).await;
+                        output.insert("games", &games)?;
+                    },
+                }
+            },
+            Command::Scorebug { league, id } => {
+                info!("Fetching score bug for {} game ID: {}", league.to_uppercase(), id);
+                let scorebug = match league.to_uppercase().as_str() {
+                    "MLB" => match u32::try_from(*id) {
+                        Ok(id) => mlb::get_scorebug(id).await,
+                        Err(_) => Err(anyhow!("Invalid MLB game ID: {}", id)),
+                    },
+                    "NBA" => match u32::try_from(*id) {
+                        Ok(id) => nba::get_game(id).await.map(|game| game.scorebug()),
+                        Err(_) => Err(anyhow!("Invalid NBA game ID: {}", id)),
+                    },
+                    other => Err(anyhow!("Unknown league '{}', expected mlb or nba", other)),
+                };
+                match scorebug {
+                    Ok(scorebug) if !output.is_text() => output.insert("scorebug", &scorebug)?,
+                    Ok(scorebug) => println!("{}", scorebug),
+                    Err(e) => output.error(&format!("Error fetching score bug: {}", e)),
                 }
             },
+            Command::Completions { .. } => unreachable!("completion scripts are printed before anything else"),
         }
     }
 
+    if let Some(summary) = errors.summary() {
+        output.error(&format!("\n{}", summary));
+    }
+
     // If no specific request was made, show usage information
-    if args.command.is_none() && !args.todays_games && !args.yesterday_games {
+    if output.is_text() && args.command.is_none() && !args.todays_games && !args.yesterday_games && args.days_ago.is_none() && args.date.is_none() && !args.clear_cache {
         println!("\nUsage Examples:");
-        println!("  Get all of today's games (MLB and NBA): plaintext-sports --todays-games");
+        println!("  Get all of today's MLB and NBA games: plaintext-sports --todays-games");
         println!("  Get only MLB games for today: plaintext-sports --todays-games --leagues MLB");
         println!("  Get only NBA games for today: plaintext-sports --todays-games --leagues NBA");
-        println!("  Get all of yesterday's games (MLB and NBA): plaintext-sports --yesterday-games");
+        println!("  Include NHL games, which are left out unless asked for: plaintext-sports --todays-games --leagues MLB,NBA,NHL");
+        println!("  Watch today's scores update live: plaintext-sports --todays-games --watch");
+        println!("  Get all of yesterday's MLB and NBA games: plaintext-sports --yesterday-games");
         println!("  Get only MLB games from yesterday: plaintext-sports --yesterday-games --leagues MLB");
         println!("  Get only NBA games from yesterday: plaintext-sports --yesterday-games --leagues NBA");
+        println!("  Get all games from three days ago: plaintext-sports --days-ago 3");
+        println!("  Get MLB games on a specific date: plaintext-sports --date 2024-07-04 --leagues MLB");
         println!("  Get all of today's games with detailed stats: plaintext-sports --todays-games --detailed-stats");
+        println!("  Get today's games one per line: plaintext-sports --todays-games --compact");
+        println!("  Get just today's results: plaintext-sports --todays-games --summary");
+        println!("  Also save today's games as JSON: plaintext-sports --todays-games --also json:games.json");
+        println!("  Get only games in progress: plaintext-sports --todays-games --only-live");
+        println!("  List the home team first: plaintext-sports --todays-games --home-first");
+        println!("  Print today's games as JSON: plaintext-sports --format json --todays-games");
+        println!("  Hide the log messages: plaintext-sports --todays-games --quiet");
         println!("\nMLB Commands:");
         println!("  Get player stats: plaintext-sports mlb player --id 547989");
+        println!("  Get a player's season stats: plaintext-sports mlb player --id 547989 --season 2024");
         println!("  Get team stats: plaintext-sports mlb team --id 145");
         println!("  Get team schedule: plaintext-sports mlb team --id 145 --schedule");
+        println!("  Get the next week's games: plaintext-sports mlb team --id 145 --schedule --next 7");
+        println!("  Get team roster: plaintext-sports mlb team --id 145 --roster");
+        println!("  Look a team up by abbreviation: plaintext-sports mlb team --abbr NYY");
+        println!("  List all teams and their IDs: plaintext-sports mlb teams");
         println!("  Get game results: plaintext-sports mlb game --id 12345");
         println!("  Get game results with detailed stats: plaintext-sports mlb game --id 12345 --detailed-stats");
+        println!("  Get a game's scoring plays: plaintext-sports mlb game --id 12345 --plays");
+        println!("  Show a saved game: plaintext-sports mlb game --from-file game.json");
         println!("  Get all of today's MLB games: plaintext-sports mlb todays-games");
         println!("  Get all of yesterday's MLB games: plaintext-sports mlb yesterday-games");
+        println!("  Get a batter vs. pitcher matchup: plaintext-sports mlb matchup --batter 545361 --pitcher 543037");
+        println!("  Get a season series record: plaintext-sports mlb h2h --team-a 147 --team-b 111");
+        println!("  Get division standings: plaintext-sports mlb standings");
+        println!("  Get one league's standings for a season: plaintext-sports mlb standings --league 103 --season 2024");
+        println!("  Get a team's pitching staff: plaintext-sports mlb pitching-staff --id 145");
+        println!("  Export a team schedule to a calendar: plaintext-sports mlb team --id 145 --schedule --format ics > sox.ics");
+        println!("  Export an NBA team schedule to a calendar: plaintext-sports nba team --id 14 --schedule --format ics > lakers.ics");
         println!("\nNBA Commands:");
         println!("  Get player stats: plaintext-sports nba player --id 237");
+        println!("  Get a player's season averages: plaintext-sports nba player --id 237 --season 2024");
+        println!("  Find a player's ID: plaintext-sports nba player --search lebron");
         println!("  Get team stats: plaintext-sports nba team --id 14");
         println!("  Get team schedule: plaintext-sports nba team --id 14 --schedule");
+        println!("  List all teams and their IDs: plaintext-sports nba teams");
+        println!("  Get the conference standings: plaintext-sports nba standings --season 2024");
         println!("  Get all of today's NBA games: plaintext-sports nba todays-games");
         println!("  Get all of yesterday's NBA games: plaintext-sports nba yesterday-games");
+        println!("\nNHL Commands:");
+        println!("  Get player information: plaintext-sports nhl player --id 8478402");
+        println!("  Get team information: plaintext-sports nhl team --id 18");
+        println!("  Get team schedule: plaintext-sports nhl team --abbr NSH --schedule");
+        println!("  List all teams and their IDs: plaintext-sports nhl teams");
+        println!("  Get game results: plaintext-sports nhl game --id 2023020001");
+        println!("  Get all of today's NHL games: plaintext-sports nhl todays-games");
+        println!("  Get all of yesterday's NHL games: plaintext-sports nhl yesterday-games");
+        println!("\nOther Commands:");
+        println!("  Get a compact score bug for a game: plaintext-sports scorebug --league mlb --id 717465");
         println!("\nFor more options, use --help");
     }
 
-    Ok(())
+    output.finish()
 }
 
 #[cfg(test)]
@@ -747,7 +1697,11 @@ mod tests {
         match args.command {
             Some(Command::MLB { command }) => {
                 match command {
-                    MLBCommand::Player { id } => assert_eq!(id, 547989),
+                    MLBCommand::Player { id, season, splits } => {
+                        assert_eq!(id, 547989);
+                        assert_eq!(season, None);
+                        assert!(!splits);
+                    },
                     _ => panic!("Expected MLBCommand::Player"),
                 }
             },
@@ -759,8 +1713,8 @@ mod tests {
         match args.command {
             Some(Command::MLB { command }) => {
                 match command {
-                    MLBCommand::Team { id, schedule } => {
-                        assert_eq!(id, 145);
+                    MLBCommand::Team { id, schedule, .. } => {
+                        assert_eq!(id, "145");
                         assert_eq!(schedule, false);
                     },
                     _ => panic!("Expected MLBCommand::Team"),
@@ -769,13 +1723,20 @@ mod tests {
             _ => panic!("Expected Command::MLB"),
         }
         
+        // Test MLB team lookup by abbreviation
+        let args = Args::parse_from(["plaintext-sports", "mlb", "team", "--abbr", "NYY"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::MLB { command: MLBCommand::Team { ref id, .. } }) if id == "NYY"
+        ));
+        
         // Test MLB team schedule command
         let args = Args::parse_from(["plaintext-sports", "mlb", "team", "--id", "145", "--schedule"]);
         match args.command {
             Some(Command::MLB { command }) => {
                 match command {
-                    MLBCommand::Team { id, schedule } => {
-                        assert_eq!(id, 145);
+                    MLBCommand::Team { id, schedule, .. } => {
+                        assert_eq!(id, "145");
                         assert_eq!(schedule, true);
                     },
                     _ => panic!("Expected MLBCommand::Team"),
@@ -784,6 +1745,67 @@ mod tests {
             _ => panic!("Expected Command::MLB"),
         }
         
+        // Test MLB team roster command
+        let args = Args::parse_from(["plaintext-sports", "mlb", "team", "--id", "145", "--roster"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::MLB { command: MLBCommand::Team { ref id, schedule: false, roster: true, .. } }) if id == "145"
+        ));
+        
+        // Test MLB game scoring plays
+        let args = Args::parse_from(["plaintext-sports", "mlb", "game", "--id", "12345", "--plays"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::MLB { command: MLBCommand::Game { id: Some(12345), plays: true, .. } })
+        ));
+        
+        // Test MLB live game situation
+        let args = Args::parse_from(["plaintext-sports", "mlb", "game", "--id", "12345", "--live"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::MLB { command: MLBCommand::Game { id: Some(12345), live: true, plays: false, .. } })
+        ));
+        
+        // Test MLB game leaders by a stat
+        let args = Args::parse_from(["plaintext-sports", "mlb", "game", "--id", "12345", "--leaders", "HR"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::MLB { command: MLBCommand::Game { leaders: Some(mlb::BattingMetric::HomeRuns), .. } })
+        ));
+        assert!(Args::try_parse_from(["plaintext-sports", "mlb", "game", "--id", "12345", "--leaders", "era"]).is_err());
+        
+        // Test MLB game lookup by team and date
+        let args = Args::parse_from([
+            "plaintext-sports", "mlb", "game", "--team", "145", "--date", "2024-07-04", "--game-number", "2",
+        ]);
+        match args.command {
+            Some(Command::MLB { command: MLBCommand::Game { id, team, date, game_number, .. } }) => {
+                assert_eq!(id, None);
+                assert_eq!(team, Some(145));
+                assert_eq!(date, NaiveDate::from_ymd_opt(2024, 7, 4));
+                assert_eq!(game_number, Some(2));
+            },
+            _ => panic!("Expected MLBCommand::Game"),
+        }
+        
+        // Test MLB game read from a saved file
+        let args = Args::parse_from(["plaintext-sports", "mlb", "game", "--from-file", "game.json"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::MLB { command: MLBCommand::Game { id: None, from_file: Some(ref path), .. } })
+                if path == std::path::Path::new("game.json")
+        ));
+        assert!(Args::try_parse_from([
+            "plaintext-sports", "mlb", "game", "--id", "1", "--from-file", "game.json",
+        ]).is_err());
+        
+        // A game needs either an ID or a team and date, not both
+        assert!(Args::try_parse_from(["plaintext-sports", "mlb", "game"]).is_err());
+        assert!(Args::try_parse_from(["plaintext-sports", "mlb", "game", "--team", "145"]).is_err());
+        assert!(Args::try_parse_from([
+            "plaintext-sports", "mlb", "game", "--id", "1", "--team", "145", "--date", "2024-07-04",
+        ]).is_err());
+        
         // Test MLB todays games command
         let args = Args::parse_from(["plaintext-sports", "mlb", "todays-games"]);
         match args.command {
@@ -813,20 +1835,40 @@ mod tests {
         match args.command {
             Some(Command::NBA { command }) => {
                 match command {
-                    NBACommand::Player { id } => assert_eq!(id, 237),
+                    NBACommand::Player { id, season, search } => {
+                        assert_eq!(id, Some(237));
+                        assert_eq!(season, None);
+                        assert_eq!(search, None);
+                    }
                     _ => panic!("Expected NBACommand::Player"),
                 }
             },
             _ => panic!("Expected Command::NBA"),
         }
         
+        // Searching by name takes the place of an ID
+        let args = Args::parse_from(["plaintext-sports", "nba", "player", "--search", "lebron"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::NBA { command: NBACommand::Player { id: None, search: Some(ref query), .. } }) if query == "lebron"
+        ));
+        assert!(Args::try_parse_from(["plaintext-sports", "nba", "player", "--id", "237", "--search", "lebron"]).is_err());
+        assert!(Args::try_parse_from(["plaintext-sports", "nba", "player"]).is_err());
+        
+        // Test NBA standings command
+        let args = Args::parse_from(["plaintext-sports", "nba", "standings", "--season", "2024"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::NBA { command: NBACommand::Standings { season: Some(2024) } })
+        ));
+        
         // Test NBA team command
         let args = Args::parse_from(["plaintext-sports", "nba", "team", "--id", "14"]);
         match args.command {
             Some(Command::NBA { command }) => {
                 match command {
-                    NBACommand::Team { id, schedule } => {
-                        assert_eq!(id, 14);
+                    NBACommand::Team { id, schedule, .. } => {
+                        assert_eq!(id, "14");
                         assert_eq!(schedule, false);
                     },
                     _ => panic!("Expected NBACommand::Team"),
@@ -840,8 +1882,8 @@ mod tests {
         match args.command {
             Some(Command::NBA { command }) => {
                 match command {
-                    NBACommand::Team { id, schedule } => {
-                        assert_eq!(id, 14);
+                    NBACommand::Team { id, schedule, .. } => {
+                        assert_eq!(id, "14");
                         assert_eq!(schedule, true);
                     },
                     _ => panic!("Expected NBACommand::Team"),
@@ -874,6 +1916,90 @@ mod tests {
             _ => panic!("Expected Command::NBA"),
         }
         
+        // Test NHL commands
+        let args = Args::parse_from(["plaintext-sports", "nhl", "team", "--abbr", "NSH", "--schedule"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::NHL { command: NHLCommand::Team { ref id, schedule: true } }) if id == "NSH"
+        ));
+        let args = Args::parse_from(["plaintext-sports", "nhl", "game", "--id", "2023020001"]);
+        assert!(matches!(args.command, Some(Command::NHL { command: NHLCommand::Game { id: 2023020001 } })));
+        let args = Args::parse_from(["plaintext-sports", "nhl", "todays-games"]);
+        assert!(matches!(args.command, Some(Command::NHL { command: NHLCommand::TodaysGames })));
+        
+        // Test MLB matchup command
+        let args = Args::parse_from(["plaintext-sports", "mlb", "matchup", "--batter", "545361", "--pitcher", "543037"]);
+        match args.command {
+            Some(Command::MLB { command }) => {
+                match command {
+                    MLBCommand::Matchup { batter, pitcher } => {
+                        assert_eq!(batter, 545361);
+                        assert_eq!(pitcher, 543037);
+                    },
+                    _ => panic!("Expected MLBCommand::Matchup"),
+                }
+            },
+            _ => panic!("Expected Command::MLB"),
+        }
+        
+        // Test head-to-head command
+        let args = Args::parse_from(["plaintext-sports", "mlb", "h2h", "--team-a", "147", "--team-b", "111"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::MLB { command: MLBCommand::HeadToHead { team_a: 147, team_b: 111, season: None } })
+        ));
+        assert!(Args::try_parse_from(["plaintext-sports", "mlb", "h2h", "--team-a", "147"]).is_err());
+        
+        // Test MLB schedule calendar export
+        let args = Args::parse_from(["plaintext-sports", "mlb", "team", "--id", "145", "--schedule", "--format", "ics"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::MLB { command: MLBCommand::Team { ref id, schedule: true, roster: false, format: ScheduleFormat::Ics } }) if id == "145"
+        ));
+        assert!(Args::try_parse_from(["plaintext-sports", "mlb", "team", "--id", "145", "--format", "ics"]).is_err());
+        let args = Args::parse_from(["plaintext-sports", "nba", "team", "--id", "14", "--schedule", "--format", "ics"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::NBA { command: NBACommand::Team { ref id, schedule: true, format: ScheduleFormat::Ics, limit: None } }) if id == "14"
+        ));
+        
+        // Test NBA schedule game limit
+        let args = Args::parse_from(["plaintext-sports", "nba", "team", "--id", "14", "--schedule", "--limit", "5"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::NBA { command: NBACommand::Team { schedule: true, limit: Some(5), .. } })
+        ));
+        assert!(Args::try_parse_from(["plaintext-sports", "nba", "team", "--id", "14", "--limit", "5"]).is_err());
+        
+        // Test MLB standings command
+        let args = Args::parse_from(["plaintext-sports", "mlb", "standings"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::MLB { command: MLBCommand::Standings { league: None, season: None } })
+        ));
+        let args = Args::parse_from(["plaintext-sports", "mlb", "standings", "--league", "103", "--season", "2023"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::MLB { command: MLBCommand::Standings { league: Some(103), season: Some(2023) } })
+        ));
+        
+        // Test MLB pitching staff command
+        let args = Args::parse_from(["plaintext-sports", "mlb", "pitching-staff", "--id", "145", "--season", "2023"]);
+        assert!(matches!(
+            args.command,
+            Some(Command::MLB { command: MLBCommand::PitchingStaff { id: 145, season: Some(2023) } })
+        ));
+        
+        // Test scorebug command
+        let args = Args::parse_from(["plaintext-sports", "scorebug", "--league", "nba", "--id", "12345"]);
+        match args.command {
+            Some(Command::Scorebug { league, id }) => {
+                assert_eq!(league, "nba");
+                assert_eq!(id, 12345);
+            },
+            _ => panic!("Expected Command::Scorebug"),
+        }
+        
         // Test combined flags
         let args = Args::parse_from(["plaintext-sports", "--todays-games", "--detailed-stats"]);
         assert_eq!(args.todays_games, true);
@@ -882,5 +2008,309 @@ mod tests {
         let args = Args::parse_from(["plaintext-sports", "--yesterday-games", "--detailed-stats"]);
         assert_eq!(args.yesterday_games, true);
         assert_eq!(args.detailed_stats, true);
+        
+        // Test days_ago option
+        let args = Args::parse_from(["plaintext-sports", "--days-ago", "3"]);
+        assert_eq!(args.days_ago, Some(3));
+        
+        // Test date option, which has to be a real YYYY-MM-DD date
+        let args = Args::parse_from(["plaintext-sports", "--date", "2024-07-04", "--leagues", "MLB"]);
+        assert_eq!(args.date, NaiveDate::from_ymd_opt(2024, 7, 4));
+        let err = Args::try_parse_from(["plaintext-sports", "--date", "2024-02-30"]).unwrap_err();
+        assert!(err.to_string().contains("invalid date '2024-02-30'"));
+        assert!(Args::try_parse_from(["plaintext-sports", "--date", "07/04/2024"]).is_err());
+        
+        // Test leagues, given as separate values or in a comma- or space-separated list
+        for list in [&["mlb", "NBA"][..], &["Mlb,nba"], &["MLB NBA"]] {
+            let args = Args::parse_from(["plaintext-sports", "--todays-games", "--leagues"].iter().chain(list));
+            assert_eq!(parse_leagues(&args.leagues.unwrap()), Ok(vec!["MLB".to_string(), "NBA".to_string()]));
+        }
+        assert_eq!(
+            parse_leagues(&["MLB,NFL".to_string(), "mls".to_string()]),
+            Err("unknown leagues 'NFL', 'mls' for --leagues, valid leagues are MLB, NBA, NHL".to_string())
+        );
+        assert_eq!(parse_leagues(&["nhl".to_string()]), Ok(vec!["NHL".to_string()]));
+        
+        // Test home-first flag
+        let args = Args::parse_from(["plaintext-sports", "--todays-games", "--home-first"]);
+        assert!(args.home_first);
+        
+        // Test cache directory override
+        let args = Args::parse_from(["plaintext-sports", "--todays-games", "--cache-dir", "/tmp/sports-cache"]);
+        assert_eq!(args.cache_dir, Some(std::path::PathBuf::from("/tmp/sports-cache")));
+        
+        // Test watch mode, which only applies to today's games
+        let args = Args::parse_from(["plaintext-sports", "--todays-games", "--watch"]);
+        assert_eq!(args.watch, Some(30));
+        let args = Args::parse_from(["plaintext-sports", "--todays-games", "--watch", "10"]);
+        assert_eq!(args.watch, Some(10));
+        assert!(Args::try_parse_from(["plaintext-sports", "--watch"]).is_err());
+        assert!(Args::try_parse_from(["plaintext-sports", "--todays-games", "--yesterday-games", "--watch"]).is_err());
+        
+        // Test color choice
+        assert_eq!(Args::parse_from(["plaintext-sports"]).color, ColorChoice::Auto);
+        assert_eq!(Args::parse_from(["plaintext-sports", "--color", "never"]).color, ColorChoice::Never);
+        assert!(Args::try_parse_from(["plaintext-sports", "--color", "sometimes"]).is_err());
+        
+        // Test cache flags
+        let args = Args::parse_from(["plaintext-sports", "--todays-games", "--no-cache"]);
+        assert!(args.no_cache);
+        assert!(!args.clear_cache);
+        let args = Args::parse_from(["plaintext-sports", "--clear-cache"]);
+        assert!(args.clear_cache);
+        
+        // Test replay directory
+        let args = Args::parse_from(["plaintext-sports", "mlb", "teams", "--replay-dir", "fixtures"]);
+        assert_eq!(args.replay_dir, Some(std::path::PathBuf::from("fixtures")));
+        
+        // Test raw response dumps
+        let args = Args::parse_from(["plaintext-sports", "mlb", "teams", "--dump-raw", "dumps"]);
+        assert_eq!(args.dump_raw, Some(std::path::PathBuf::from("dumps")));
+        assert_eq!(Args::parse_from(["plaintext-sports"]).dump_raw, None);
+        
+        // Test --also output spec
+        let args = Args::parse_from(["plaintext-sports", "--todays-games", "--also", "json:games.json"]);
+        let spec = args.also.unwrap();
+        assert_eq!(spec.path, std::path::PathBuf::from("games.json"));
+        assert!(Args::try_parse_from(["plaintext-sports", "--todays-games", "--also", "xml:games.xml"]).is_err());
+        
+        // Test timezone, which must be a known IANA name
+        let args = Args::parse_from(["plaintext-sports", "--todays-games", "--timezone", "America/Los_Angeles"]);
+        assert_eq!(args.timezone, Some(chrono_tz::America::Los_Angeles));
+        assert!(Args::try_parse_from(["plaintext-sports", "--todays-games", "--timezone", "PST8"]).is_err());
+        
+        // Test compact flag
+        let args = Args::parse_from(["plaintext-sports", "--todays-games", "--compact"]);
+        assert!(args.compact);
+        
+        // --summary applies to game listings and to a single MLB game
+        let args = Args::parse_from(["plaintext-sports", "--todays-games", "--summary"]);
+        assert!(args.summary);
+        let args = Args::parse_from(["plaintext-sports", "mlb", "game", "--id", "12345", "--summary"]);
+        assert!(args.summary);
+        
+        // Test output format, which defaults to text and is separate from a schedule's --format
+        let args = Args::parse_from(["plaintext-sports", "--todays-games"]);
+        assert_eq!(args.format, OutputFormat::Text);
+        let args = Args::parse_from(["plaintext-sports", "--format", "json", "mlb", "team", "--id", "145", "--schedule", "--format", "ics"]);
+        assert_eq!(args.format, OutputFormat::Json);
+        assert_eq!(Args::parse_from(["plaintext-sports", "--format", "csv", "mlb", "game", "--id", "1"]).format, OutputFormat::Csv);
+        assert_eq!(Args::parse_from(["plaintext-sports", "--format", "markdown", "mlb", "game", "--id", "1"]).format, OutputFormat::Markdown);
+        assert!(matches!(
+            args.command,
+            Some(Command::MLB { command: MLBCommand::Team { format: ScheduleFormat::Ics, .. } })
+        ));
+        assert!(Args::try_parse_from(["plaintext-sports", "--format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn test_format_team_list() {
+        let teams = [
+            ("AL East", 111, "BOS", "Boston Red Sox"),
+            ("AL East", 147, "NYY", "New York Yankees"),
+            ("NL Central", 112, "CHC", "Chicago Cubs"),
+        ];
+        
+        let listing = format_team_list(teams);
+        assert_eq!(
+            listing,
+            "\nAL East:\n   111  BOS  Boston Red Sox\n   147  NYY  New York Yankees\n\nNL Central:\n   112  CHC  Chicago Cubs\n"
+        );
+    }
+
+    #[test]
+    fn test_status_filters() {
+        let team = |id, name: &str| mlb::GameTeam { score: None, team: mlb::Team::new(id, name.to_string()), is_winner: None, probable_pitcher: None };
+        let mlb_game = |game_pk, status| mlb::Game::new(
+            game_pk,
+            "2024-03-28".to_string(),
+            status,
+            mlb::GameTeams { away: team(145, "Chicago White Sox"), home: team(112, "Chicago Cubs") },
+            None,
+        );
+        let mlb_slate = || vec![
+            mlb_game(1, GameState::Final),
+            mlb_game(2, GameState::Live),
+            mlb_game(3, GameState::Scheduled),
+            mlb_game(4, GameState::Postponed),
+            mlb_game(5, GameState::Final),
+        ];
+        let mlb_ids = |filter| -> Vec<u32> {
+            filter_by_status(mlb_slate(), filter, |game: &mlb::Game| game.status.clone())
+                .iter().map(|game| game.game_pk).collect()
+        };
+        
+        assert_eq!(mlb_ids(None), vec![1, 2, 3, 4, 5]);
+        assert_eq!(mlb_ids(Some(StatusFilter::Final)), vec![1, 5]);
+        assert_eq!(mlb_ids(Some(StatusFilter::Live)), vec![2]);
+        assert_eq!(mlb_ids(Some(StatusFilter::Scheduled)), vec![3]);
+        
+        // NBA games are classified from their status string and period
+        let nba_game = |id: u32, status: &str, period: u32| -> nba::Game {
+            serde_json::from_value(serde_json::json!({
+                "id": id, "date": "2024-03-28", "status": status, "period": period,
+                "home_team": {"id": 14}, "visitor_team": {"id": 2}
+            })).unwrap()
+        };
+        let nba_slate = || vec![
+            nba_game(1, "Final", 4),
+            nba_game(2, "3rd Qtr", 3),
+            nba_game(3, "2024-03-28T23:30:00Z", 0),
+            nba_game(4, "Halftime", 2),
+        ];
+        let nba_ids = |filter| -> Vec<u32> {
+            filter_by_status(nba_slate(), filter, nba::Game::game_state).iter().map(|game| game.id).collect()
+        };
+        
+        assert_eq!(nba_ids(Some(StatusFilter::Final)), vec![1]);
+        assert_eq!(nba_ids(Some(StatusFilter::Live)), vec![2, 4]);
+        assert_eq!(nba_ids(Some(StatusFilter::Scheduled)), vec![3]);
+        
+        // Filters are selected from the command line and only one may be given
+        let args = Args::parse_from(["plaintext-sports", "--todays-games", "--only-live"]);
+        assert_eq!(StatusFilter::from_args(&args), Some(StatusFilter::Live));
+        let args = Args::parse_from(["plaintext-sports", "--todays-games", "--leagues", "NBA"]);
+        assert_eq!(StatusFilter::from_args(&args), None);
+        assert!(Args::try_parse_from(["plaintext-sports", "--only-final", "--only-scheduled"]).is_err());
+    }
+
+    #[test]
+    fn test_log_level() {
+        let level = |args: &[&str]| log_level(&Args::parse_from(["plaintext-sports"].iter().chain(args)));
+        assert_eq!(level(&[]), Level::INFO);
+        assert_eq!(level(&["-q"]), Level::WARN);
+        assert_eq!(level(&["--todays-games", "--quiet"]), Level::WARN);
+        assert_eq!(level(&["-v"]), Level::DEBUG);
+        assert_eq!(level(&["mlb", "teams", "-vv"]), Level::TRACE);
+        assert_eq!(level(&["-v", "--verbose", "-v"]), Level::TRACE);
+        assert!(Args::try_parse_from(["plaintext-sports", "--quiet", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_progress_mode() {
+        // Progress is shown on a terminal, or always when asked, but never quietly or in JSON
+        let mode = |args: &[&str]| progress_mode(&Args::parse_from(["plaintext-sports"].iter().chain(args)));
+        assert_eq!(mode(&["--todays-games"]), ProgressMode::Auto);
+        assert_eq!(mode(&["--todays-games", "--progress"]), ProgressMode::Always);
+        assert_eq!(mode(&["--todays-games", "--progress", "--quiet"]), ProgressMode::Never);
+        assert_eq!(mode(&["--todays-games", "--progress", "--format", "json"]), ProgressMode::Never);
+    }
+
+    #[test]
+    fn test_schedule_window() {
+        let today = NaiveDate::from_ymd_opt(2024, 7, 4).unwrap();
+        let window = |argv: &[&str]| {
+            let args = Args::parse_from([&["plaintext-sports"], argv].concat());
+            schedule_window(&args, today)
+        };
+        let dates = |start: &str, end: &str| (Some(start.to_string()), Some(end.to_string()));
+        
+        // --next starts today and --last ends yesterday, each covering N days
+        assert_eq!(window(&["mlb", "team", "--id", "145", "--schedule", "--next", "7"]).unwrap(), dates("2024-07-04", "2024-07-10"));
+        assert_eq!(window(&["mlb", "team", "--id", "145", "--schedule", "--last", "7"]).unwrap(), dates("2024-06-27", "2024-07-03"));
+        assert_eq!(window(&["--next", "1", "nba", "team", "--id", "14", "--schedule"]).unwrap(), dates("2024-07-04", "2024-07-04"));
+        
+        // Explicit dates pass through, and are left to the API to default when absent
+        assert_eq!(window(&["mlb", "team", "--id", "145", "--schedule", "--start-date", "2024-04-01"]).unwrap(), (Some("2024-04-01".to_string()), None));
+        assert_eq!(window(&[]).unwrap(), (None, None));
+        
+        // Relative and explicit windows can't be mixed
+        assert!(Args::try_parse_from(["plaintext-sports", "mlb", "team", "--id", "145", "--schedule", "--next", "7", "--end-date", "2024-07-31"]).is_err());
+        assert!(Args::try_parse_from(["plaintext-sports", "mlb", "team", "--id", "145", "--schedule", "--next", "7", "--last", "7"]).is_err());
+        let err = window(&["--last", "3", "mlb", "team", "--id", "145", "--schedule", "--start-date", "2024-04-01"]).unwrap_err();
+        assert_eq!(err.to_string(), "--next and --last can't be combined with --start-date or --end-date");
+        assert!(window(&["mlb", "team", "--id", "145", "--schedule", "--next", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_filter_by_team() {
+        let team = |id| mlb::GameTeam { score: None, team: mlb::Team::new(id, String::new()), is_winner: None, probable_pitcher: None };
+        let mlb_game = |game_pk, away, home| mlb::Game::new(
+            game_pk,
+            "2024-03-28".to_string(),
+            GameState::Scheduled,
+            mlb::GameTeams { away: team(away), home: team(home) },
+            None,
+        );
+        let slate = vec![mlb_game(1, 145, 112), mlb_game(2, 147, 145), mlb_game(3, 111, 147)];
+        let ids = |favorites: &[u32]| -> Vec<u32> {
+            filter_by_team(slate.clone(), favorites, <mlb::MlbApi as League>::team_ids)
+                .iter().map(|game| game.game_pk).collect()
+        };
+        
+        // Either the home or the away team may be the favorite
+        assert_eq!(ids(&[145]), vec![1, 2]);
+        assert_eq!(ids(&[112, 111]), vec![1, 3]);
+        assert!(ids(&[]).is_empty());
+        
+        // --favorites narrows down today's games
+        let args = Args::parse_from(["plaintext-sports", "--todays-games", "--favorites"]);
+        assert!(args.favorites);
+        assert!(Args::try_parse_from(["plaintext-sports", "--favorites"]).is_err());
+    }
+
+    #[test]
+    fn test_game_day_days_ago() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 28).unwrap();
+        
+        let day = GameDay::new(today, 3).unwrap();
+        assert_eq!(day.date_string(), "2024-03-25");
+        assert_eq!(day.heading("MLB"), "MLB Games for 2024-03-25:");
+        assert_eq!(day.empty_message("NBA"), "No NBA games played on 2024-03-25.");
+        
+        // Crossing a month boundary
+        let day = GameDay::new(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(), 3).unwrap();
+        assert_eq!(day.date_string(), "2024-02-27");
+    }
+
+    #[test]
+    fn test_game_day_on_date() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 28).unwrap();
+        
+        let day = GameDay::on(today, NaiveDate::from_ymd_opt(2023, 7, 4).unwrap());
+        assert_eq!(day.heading("MLB"), "MLB Games for 2023-07-04:");
+        assert_eq!(day.empty_message("MLB"), "No MLB games played on 2023-07-04.");
+        
+        // Dates that are also today or yesterday read the same as those flags
+        assert_eq!(GameDay::on(today, today).heading("NBA"), "Today's NBA Games:");
+        
+        let day = GameDay::on(today, NaiveDate::from_ymd_opt(2024, 4, 2).unwrap());
+        assert_eq!(day.heading("MLB"), "MLB Games for 2024-04-02:");
+        assert_eq!(day.empty_message("MLB"), "No MLB games scheduled for 2024-04-02.");
+    }
+
+    #[test]
+    fn test_error_summary() {
+        let mut errors = ErrorSummary::default();
+        assert!(errors.summary().is_none());
+        
+        // A game with several failures is only listed once (a two-game run is in main_tests)
+        errors.record("MLB", Some(717466), "innings data", &"HTTP 503 Service Unavailable");
+        errors.record("MLB", Some(717466), "detailed game stats", &"HTTP 503 Service Unavailable");
+        assert_eq!(errors.game_ids(), vec![717466]);
+        let summary = errors.summary().unwrap();
+        assert!(summary.starts_with("Completed with 2 errors (affected game IDs: 717466):"));
+        assert!(summary.contains("MLB game 717466 (innings data): HTTP 503 Service Unavailable"));
+        
+        // Listing failures have no game ID
+        errors.record("NBA", None, "today's games", &"HTTP 401 Unauthorized");
+        let summary = errors.summary().unwrap();
+        assert!(summary.starts_with("Completed with 3 errors (affected game IDs: 717466):"));
+        assert!(summary.contains("NBA (today's games): HTTP 401 Unauthorized"));
+    }
+
+    #[test]
+    fn test_game_day_aliases() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 28).unwrap();
+        
+        let day = GameDay::new(today, 0).unwrap();
+        assert_eq!(day.date_string(), "2024-03-28");
+        assert_eq!(day.heading("MLB"), "Today's MLB Games:");
+        assert_eq!(day.empty_message("MLB"), "No MLB games scheduled for today.");
+        
+        let day = GameDay::new(today, 1).unwrap();
+        assert_eq!(day.date_string(), "2024-03-27");
+        assert_eq!(day.heading("NBA"), "Yesterday's NBA Games:");
+        assert_eq!(day.empty_message("NBA"), "No NBA games played yesterday.");
     }
 }
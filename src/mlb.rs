@@ -1,17 +1,28 @@
 use anyhow::{anyhow, Result};
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fmt;
-use chrono::{Datelike, Local};
-use std::sync::OnceLock;
-
-static MLB_API_BASE_URL: OnceLock<String> = OnceLock::new();
+use chrono::{Datelike, NaiveDate};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tracing::debug;
+use crate::cache::DiskCache;
+use crate::calendar::CalendarEvent;
+use crate::league::{self, DetailOptions, DetailSection};
+use crate::output::{csv_field, markdown_cell, markdown_table, render};
+use crate::clock::{Clock, SystemClock};
+use crate::color::{paint, Style};
+use crate::config::get_config;
+use crate::error::SportError;
+use crate::order::{in_order, separator, TeamOrdered};
+use crate::scorebug::{self, Scorebug, ScorebugState};
+use crate::types::{Average, GameId, InningsPitched, RateStat, Record, Streak, TeamId};
+use crate::utils::{calculate_pythagorean_pct, calculate_whip, determine_winner, format_decimal_stat, format_record, parse_decimal_stat, schedule_range, truncate_string, Table};
 
 fn get_mlb_api_base_url() -> Result<&'static str> {
-    Ok(MLB_API_BASE_URL
-        .get_or_init(|| "https://statsapi.mlb.com/api/v1".to_string())
-        .as_str())
+    Ok(crate::config::get_config().mlb_api_base_url.as_str())
 }
 
 /// Represents the possible states of a game
@@ -20,6 +31,9 @@ fn get_mlb_api_base_url() -> Result<&'static str> {
 pub enum GameState {
     Scheduled,
     Live,
+    /// A game being played, as reported by APIs that say "In Progress" rather than "Live"
+    #[serde(alias = "In Progress")]
+    InProgress,
     Final,
     Postponed,
     Cancelled,
@@ -34,11 +48,21 @@ impl GameState {
         matches!(self, GameState::Final)
     }
 
+    /// Returns true if the game is being played
+    pub fn is_live(&self) -> bool {
+        matches!(self, GameState::Live | GameState::InProgress)
+    }
+
+    /// Returns true if play has begun, whether or not the game has finished
+    pub fn has_started(&self) -> bool {
+        self.is_live() || self.is_final()
+    }
+
     /// For compatibility with existing code that checks abstract_game_state
     pub fn abstract_game_state(&self) -> &str {
         match self {
             GameState::Final => "Final",
-            GameState::Live => "Live",
+            GameState::Live | GameState::InProgress => "Live",
             GameState::Scheduled => "Scheduled",
             GameState::Postponed => "Postponed",
             GameState::Cancelled => "Cancelled",
@@ -53,6 +77,7 @@ impl fmt::Display for GameState {
         match self {
             GameState::Scheduled => write!(f, "Scheduled"),
             GameState::Live => write!(f, "Live"),
+            GameState::InProgress => write!(f, "In Progress"),
             GameState::Final => write!(f, "Final"),
             GameState::Postponed => write!(f, "Postponed"),
             GameState::Cancelled => write!(f, "Cancelled"),
@@ -66,6 +91,11 @@ impl fmt::Display for GameState {
 #[derive(Clone)]
 pub struct MlbApi {
     client: Client,
+    clock: Arc<dyn Clock>,
+    // The team list only changes between seasons, so it's fetched once per client
+    teams_cache: OnceLock<Vec<Team>>,
+    // Boxscores of finished games never change, so they're kept on disk between runs
+    cache: Option<DiskCache>,
 }
 
 /// Player information
@@ -146,6 +176,9 @@ pub struct Team {
     pub league: Option<League>,
     /// Division the team belongs to
     pub division: Option<Division>,
+    /// This season's win-loss record; only filled in when the team is looked up on its own
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record: Option<Record>,
 }
 
 impl Team {
@@ -167,6 +200,7 @@ impl Team {
             venue: None,
             league: None,
             division: None,
+            record: None,
         }
     }
 
@@ -186,6 +220,37 @@ impl Team {
 pub struct Venue {
     pub id: u32,
     pub name: String,
+    /// City the venue is in; only present when the venue's location is hydrated
+    pub city: Option<String>,
+    /// State or province abbreviation (e.g., "MA")
+    pub state: Option<String>,
+    /// IANA timezone name (e.g., "America/New_York")
+    pub timezone: Option<String>,
+}
+
+impl Venue {
+    /// Read a venue from API JSON, picking up its location and timezone when they were hydrated
+    pub fn from_json(venue: &Value) -> Venue {
+        let location = &venue["location"];
+        let text = |value: &Value| value.as_str().filter(|s| !s.is_empty()).map(str::to_string);
+        Venue {
+            id: venue["id"].as_u64().unwrap_or(0) as u32,
+            name: venue["name"].as_str().unwrap_or("").to_string(),
+            city: text(&location["city"]),
+            state: text(&location["stateAbbrev"]).or_else(|| text(&location["state"])),
+            timezone: text(&venue["timeZone"]["id"]),
+        }
+    }
+}
+
+impl fmt::Display for Venue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        for part in [&self.city, &self.state].into_iter().flatten() {
+            write!(f, ", {}", part)?;
+        }
+        Ok(())
+    }
 }
 
 /// League information
@@ -240,6 +305,24 @@ pub struct Game {
     pub teams: GameTeams,
     /// Venue where the game is being played
     pub venue: Option<Venue>,
+    /// Position of this game within its series (e.g. 2 in "Game 2 of 3")
+    #[serde(rename = "seriesGameNumber", default)]
+    pub series_game_number: Option<u32>,
+    /// Number of games in the series
+    #[serde(rename = "gamesInSeries", default)]
+    pub games_in_series: Option<u32>,
+    /// Which game of the day this is for the teams (2 for the second game of a doubleheader)
+    #[serde(rename = "gameNumber", default)]
+    pub game_number: Option<u32>,
+    /// Whether the game is part of a doubleheader
+    #[serde(default)]
+    pub doubleheader: bool,
+    /// Half and inning being played (e.g. "Top 5th"), while the game is live
+    #[serde(default)]
+    pub current_inning: Option<String>,
+    /// Kind of game: "R" for regular season, or "F", "D", "L" or "W" for a postseason round
+    #[serde(rename = "gameType", default)]
+    pub game_type: Option<String>,
 }
 
 impl Game {
@@ -257,12 +340,18 @@ impl Game {
             status,
             teams,
             venue,
+            series_game_number: None,
+            games_in_series: None,
+            game_number: None,
+            doubleheader: false,
+            current_inning: None,
+            game_type: None,
         }
     }
 
     /// Returns true if the game has started
     pub fn has_started(&self) -> bool {
-        matches!(self.status, GameState::Live | GameState::Final)
+        self.status.has_started()
     }
 
     /// Returns true if the game is finished
@@ -270,20 +359,67 @@ impl Game {
         matches!(self.status, GameState::Final)
     }
 
-    /// Returns the winning team, if the game is finished
+    /// Returns the winning team, if the game is finished. Schedule data sometimes leaves out the
+    /// `isWinner` flags, in which case the higher score wins.
     pub fn winner(&self) -> Option<&Team> {
-        if self.is_finished() {
-            if self.teams.home.is_winner.unwrap_or(false) {
-                Some(&self.teams.home.team)
-            } else if self.teams.away.is_winner.unwrap_or(false) {
-                Some(&self.teams.away.team)
-            } else {
-                None
-            }
+        if !self.is_finished() {
+            return None;
+        }
+        
+        let (away, home) = (&self.teams.away, &self.teams.home);
+        if away.is_winner.is_none() && home.is_winner.is_none() {
+            return match determine_winner(away.score, home.score) {
+                Some(0) => Some(&away.team),
+                Some(_) => Some(&home.team),
+                None => None,
+            };
+        }
+        
+        if home.is_winner.unwrap_or(false) {
+            Some(&home.team)
+        } else if away.is_winner.unwrap_or(false) {
+            Some(&away.team)
         } else {
             None
         }
     }
+
+    /// Name of the postseason round the game belongs to, e.g. "World Series"
+    pub fn series_label(&self) -> Option<&str> {
+        match self.game_type.as_deref()? {
+            "F" => Some("Wild Card Series"),
+            "D" => Some("Division Series"),
+            "L" => Some("League Championship Series"),
+            "W" => Some("World Series"),
+            _ => None,
+        }
+    }
+
+    /// Returns true for playoff games
+    pub fn is_postseason(&self) -> bool {
+        self.series_label().is_some()
+    }
+
+    /// Returns true if the game finished with level scores and no winner flagged
+    pub fn is_tie(&self) -> bool {
+        self.is_finished()
+            && self.winner().is_none()
+            && self.teams.away.score.is_some()
+            && determine_winner(self.teams.away.score, self.teams.home.score).is_none()
+    }
+
+    /// One-line result such as "NYY 5 @ BOS 3 (Final)", with the inning for live games
+    pub fn summary_line(&self) -> String {
+        let team = |team: &GameTeam| match team.score.filter(|_| self.has_started()) {
+            Some(score) => format!("{} {}", team.team.short_label(), score),
+            None => team.team.short_label().to_string(),
+        };
+        let status = match (&self.status, &self.current_inning) {
+            (status, Some(inning)) if status.is_live() => format!("Live, {}", inning),
+            (status, _) => status.to_string(),
+        };
+        format!("{} @ {} ({})", team(&self.teams.away), team(&self.teams.home), status)
+    }
 }
 
 /// Game teams
@@ -300,6 +436,8 @@ pub struct GameTeam {
     pub team: Team,
     #[serde(rename = "isWinner")]
     pub is_winner: Option<bool>,
+    #[serde(default)]
+    pub probable_pitcher: Option<String>,
 }
 
 /// Schedule information
@@ -322,12 +460,90 @@ pub struct GameStats {
     pub home_team_stats: TeamStats,
 }
 
+impl GameStats {
+    /// Each team's top `n` batters by `metric`, away team first
+    pub fn leaders(&self, metric: BattingMetric, n: usize) -> GameLeaders {
+        let team = |stats: &TeamStats| TeamLeaders {
+            team_name: stats.team_name.clone(),
+            leaders: stats.leaders(metric, n)
+                .into_iter()
+                .map(|batter| (batter.name.clone(), batter.metric_value(metric)))
+                .collect(),
+        };
+        GameLeaders {
+            metric,
+            teams: vec![team(&self.away_team_stats), team(&self.home_team_stats)],
+        }
+    }
+    
+    /// The box score as CSV, in two sections separated by a blank line, away team's players first
+    /// in each:
+    ///
+    /// - batters: `name,team,pos,ab,h,r,hr,rbi,avg`
+    /// - pitchers: `name,team,ip,h,r,er,bb,k,hr,era`
+    ///
+    /// A missing position or average is an empty field. Fields containing commas or quotes (e.g.
+    /// "Griffey, Jr.") are quoted.
+    pub fn to_csv(&self) -> String {
+        let teams = [&self.away_team_stats, &self.home_team_stats];
+        let mut csv = String::from("name,team,pos,ab,h,r,hr,rbi,avg\n");
+        for team in teams {
+            for batter in &team.batters {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{},{},{},{}\n",
+                    csv_field(&batter.name),
+                    csv_field(&team.team_name),
+                    csv_field(batter.position.as_deref().unwrap_or("")),
+                    batter.at_bats,
+                    batter.hits,
+                    batter.runs,
+                    batter.home_runs,
+                    batter.rbi,
+                    batter.avg.as_deref().unwrap_or("")
+                ));
+            }
+        }
+        
+        csv.push_str("\nname,team,ip,h,r,er,bb,k,hr,era\n");
+        for team in teams {
+            for pitcher in &team.pitchers {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{},{},{},{},{}\n",
+                    csv_field(&pitcher.name),
+                    csv_field(&team.team_name),
+                    pitcher.innings_pitched,
+                    pitcher.hits_allowed,
+                    pitcher.runs_allowed,
+                    pitcher.earned_runs,
+                    pitcher.walks,
+                    pitcher.strikeouts,
+                    pitcher.home_runs_allowed,
+                    pitcher.era.as_deref().unwrap_or("")
+                ));
+            }
+        }
+        csv
+    }
+
+    /// The box score as GitHub-flavored markdown: a batters table and a pitchers table for each
+    /// team, away team first, each ending in a TEAM totals row
+    pub fn to_markdown(&self) -> String {
+        [&self.away_team_stats, &self.home_team_stats]
+            .iter()
+            .map(|team| team.to_markdown())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
 /// Team statistics
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TeamStats {
     pub team_name: String,
-    pub batting: BattingStats,
-    pub pitching: PitchingStats,
+    /// Team batting totals, if the boxscore included them
+    pub batting: Option<BattingStats>,
+    /// Team pitching totals, if the boxscore included them
+    pub pitching: Option<PitchingStats>,
     pub batters: Vec<PlayerBattingStats>,
     pub pitchers: Vec<PlayerPitchingStats>,
 }
@@ -357,6 +573,7 @@ pub struct PitchingStats {
     pub strikeouts: u32,
     pub home_runs_allowed: u32,
     pub era: String,
+    pub whip: String,
 }
 
 /// Player batting statistics
@@ -376,6 +593,73 @@ pub struct PlayerBattingStats {
     pub avg: Option<String>,
     pub obp: Option<String>,
     pub slg: Option<String>,
+    /// Position abbreviation (e.g. "SS"), when the boxscore lists it
+    #[serde(default)]
+    pub position: Option<String>,
+    /// Spot in the batting order from the boxscore: 100 for the leadoff hitter, 101 for the first
+    /// substitute in that spot, 200 for the second hitter and so on
+    #[serde(default)]
+    pub batting_order: Option<u32>,
+}
+
+/// A batting stat players can be ranked by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BattingMetric {
+    Avg,
+    HomeRuns,
+    Rbi,
+    Ops,
+    StolenBases,
+}
+
+impl BattingMetric {
+    /// Short label, as in box score headers (e.g. "HR")
+    pub fn label(&self) -> &'static str {
+        match self {
+            BattingMetric::Avg => "AVG",
+            BattingMetric::HomeRuns => "HR",
+            BattingMetric::Rbi => "RBI",
+            BattingMetric::Ops => "OPS",
+            BattingMetric::StolenBases => "SB",
+        }
+    }
+}
+
+impl fmt::Display for BattingMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl std::str::FromStr for BattingMetric {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "avg" => Ok(BattingMetric::Avg),
+            "hr" => Ok(BattingMetric::HomeRuns),
+            "rbi" => Ok(BattingMetric::Rbi),
+            "ops" => Ok(BattingMetric::Ops),
+            "sb" => Ok(BattingMetric::StolenBases),
+            other => Err(format!("unknown stat '{}' (expected avg, hr, rbi, ops or sb)", other)),
+        }
+    }
+}
+
+/// A game's top batters on each team by one stat
+#[derive(Debug, Clone, Serialize)]
+pub struct GameLeaders {
+    pub metric: BattingMetric,
+    /// The away team's leaders, then the home team's
+    pub teams: Vec<TeamLeaders>,
+}
+
+/// A team's top batters by one stat, best first
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamLeaders {
+    pub team_name: String,
+    /// Each leader's name and their value of the stat as shown in the box score
+    pub leaders: Vec<(String, String)>,
 }
 
 /// Player pitching statistics
@@ -390,6 +674,9 @@ pub struct PlayerPitchingStats {
     pub walks: u32,
     pub home_runs_allowed: u32,
     pub era: Option<String>,
+    pub whip: Option<String>,
+    /// Pitching decision for the game: W, L, SV, HLD, or BS
+    pub decision: Option<String>,
 }
 
 /// Inning data with runs scored per inning
@@ -411,6 +698,370 @@ pub struct GameInnings {
     pub innings: Vec<InningData>,
     pub home_runs: Option<u32>,
     pub away_runs: Option<u32>,
+    pub home_hits: Option<u32>,
+    pub away_hits: Option<u32>,
+    pub home_errors: Option<u32>,
+    pub away_errors: Option<u32>,
+}
+
+/// The situation in a game being played
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveGameState {
+    pub inning: u32,
+    /// "Top", "Middle", "Bottom" or "End"; outs and the count only apply to "Top" and "Bottom"
+    pub half: String,
+    pub balls: u32,
+    pub strikes: u32,
+    pub outs: u32,
+    /// Names of the runners on first, second and third
+    pub on_first: Option<String>,
+    pub on_second: Option<String>,
+    pub on_third: Option<String>,
+    pub batter: Option<String>,
+    pub pitcher: Option<String>,
+}
+
+/// A game's live situation, or the state of a game that isn't being played and so has none
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LiveData {
+    InProgress(LiveGameState),
+    NotLive(GameState),
+}
+
+/// A play on which runs scored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoringPlay {
+    pub inning: u32,
+    /// "top" or "bottom"
+    pub half: String,
+    pub description: String,
+    pub away_team: String,
+    pub home_team: String,
+    /// Score after the play
+    pub away_score: u32,
+    pub home_score: u32,
+}
+
+/// A batter's career line against a specific pitcher
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Matchup {
+    pub batter_id: u32,
+    pub batter_name: Option<String>,
+    pub pitcher_id: u32,
+    pub pitcher_name: Option<String>,
+    /// Career totals, or None if the batter has never faced the pitcher
+    pub career: Option<PlayerBattingStats>,
+}
+
+/// Two teams' season series, with each team's wins in the games that have finished
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HeadToHead {
+    pub team_a: u32,
+    pub team_b: u32,
+    pub a_wins: u32,
+    pub b_wins: u32,
+    /// Every game between the teams, including ones not yet played
+    pub games: Vec<Game>,
+}
+
+impl HeadToHead {
+    /// Count each team's wins among the finished games; ties and unfinished games count for neither
+    pub fn tally(team_a: u32, team_b: u32, games: Vec<Game>) -> Self {
+        let wins = |team_id: u32| games.iter().filter(|game| game.winner().is_some_and(|team| team.id == team_id)).count() as u32;
+        let (a_wins, b_wins) = (wins(team_a), wins(team_b));
+        HeadToHead { team_a, team_b, a_wins, b_wins, games }
+    }
+
+    // The team's club name (e.g. "Yankees") as it appears in the games, or its ID
+    fn team_label(&self, team_id: u32) -> String {
+        self.games
+            .iter()
+            .flat_map(|game| [&game.teams.away.team, &game.teams.home.team])
+            .find(|team| team.id == team_id)
+            .map(|team| team.team_name.clone().unwrap_or_else(|| team.name.clone()))
+            .unwrap_or_else(|| format!("Team {}", team_id))
+    }
+}
+
+/// A team's line in the division standings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandingsRecord {
+    pub team: Team,
+    pub wins: u32,
+    pub losses: u32,
+    /// Games behind the division leader ("-" for the leader)
+    pub games_back: String,
+    /// Current streak, e.g. "W3" (None before the first game)
+    pub streak: Option<Streak>,
+    pub runs_scored: u32,
+    pub runs_allowed: u32,
+}
+
+impl StandingsRecord {
+    /// The team's win-loss record
+    pub fn record(&self) -> Record {
+        Record::new(self.wins, self.losses)
+    }
+
+    /// Winning percentage, or None before the team has played
+    pub fn winning_pct(&self) -> Option<f32> {
+        let record = self.record();
+        (record.wins + record.losses > 0).then(|| record.winning_percentage())
+    }
+
+    /// Runs scored minus runs allowed
+    pub fn run_differential(&self) -> i64 {
+        self.runs_scored as i64 - self.runs_allowed as i64
+    }
+
+    /// Pythagorean expected winning percentage, or None before any runs have been scored
+    pub fn pythagorean_pct(&self) -> Option<f32> {
+        calculate_pythagorean_pct(self.runs_scored, self.runs_allowed)
+    }
+
+    /// Wins and losses the team would be expected to have from its runs scored and allowed
+    pub fn expected_record(&self) -> Option<(u32, u32)> {
+        let games = self.wins + self.losses;
+        self.pythagorean_pct().map(|pct| {
+            let wins = (pct * games as f32).round() as u32;
+            (wins, games - wins)
+        })
+    }
+}
+
+/// A player's hitting line for a season
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonHittingStats {
+    pub games: u32,
+    pub at_bats: u32,
+    pub avg: String,
+    pub obp: String,
+    pub slg: String,
+    pub ops: String,
+    pub home_runs: u32,
+    pub rbi: u32,
+    /// OPS relative to the league, where 100 is average; only set when league averages were fetched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ops_plus: Option<u32>,
+}
+
+/// A player's pitching line for a season
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonPitchingStats {
+    pub games: u32,
+    pub innings_pitched: String,
+    pub era: String,
+    pub whip: String,
+    pub strikeouts: u32,
+    /// ERA relative to the league, where 100 is average; only set when league averages were fetched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub era_plus: Option<u32>,
+}
+
+impl SeasonHittingStats {
+    // Read a hitting line from a stats API "stat" object; a missing object reads as a zero line
+    fn from_stat(stat: &Value) -> Self {
+        SeasonHittingStats {
+            games: stat_count(&stat["gamesPlayed"]),
+            at_bats: stat_count(&stat["atBats"]),
+            avg: stat_text(&stat["avg"], ".---"),
+            obp: stat_text(&stat["obp"], ".---"),
+            slg: stat_text(&stat["slg"], ".---"),
+            ops: stat_text(&stat["ops"], ".---"),
+            home_runs: stat_count(&stat["homeRuns"]),
+            rbi: stat_count(&stat["rbi"]),
+            ops_plus: None,
+        }
+    }
+}
+
+impl SeasonPitchingStats {
+    // Read a pitching line from a stats API "stat" object; a missing object reads as a zero line
+    fn from_stat(stat: &Value) -> Self {
+        SeasonPitchingStats {
+            games: stat_count(&stat["gamesPlayed"]),
+            innings_pitched: stat_text(&stat["inningsPitched"], "0.0"),
+            era: stat_text(&stat["era"], "-.--"),
+            whip: stat_text(&stat["whip"], "-.--"),
+            strikeouts: stat_count(&stat["strikeOuts"]),
+            era_plus: None,
+        }
+    }
+}
+
+fn stat_count(value: &Value) -> u32 {
+    value.as_u64().unwrap_or(0) as u32
+}
+
+fn stat_text(value: &Value, missing: &str) -> String {
+    value.as_str().unwrap_or(missing).to_string()
+}
+
+/// A player's hitting and pitching for one season. Either side is None if the player
+/// didn't bat or pitch that season.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerSeasonStats {
+    pub player_id: u32,
+    pub season: u32,
+    pub hitting: Option<SeasonHittingStats>,
+    pub pitching: Option<SeasonPitchingStats>,
+}
+
+impl PlayerSeasonStats {
+    /// Fill in OPS+ and ERA+ against the league's averages for the season
+    pub fn with_league_averages(mut self, league: &LeagueAverages) -> Self {
+        if let Some(ref mut hitting) = self.hitting {
+            hitting.ops_plus = match (parse_decimal_stat(&hitting.obp), parse_decimal_stat(&hitting.slg)) {
+                (Some(obp), Some(slg)) => ops_plus(obp as f64, slg as f64, league.obp, league.slg),
+                _ => None,
+            };
+        }
+        if let Some(ref mut pitching) = self.pitching {
+            pitching.era_plus = parse_decimal_stat(&pitching.era).and_then(|era| era_plus(era as f64, league.era));
+        }
+        self
+    }
+}
+
+/// League-wide rates for a season, the baseline for OPS+ and ERA+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LeagueAverages {
+    pub season: u32,
+    pub obp: f64,
+    pub slg: f64,
+    pub era: f64,
+}
+
+// Highest ERA+/OPS+ shown; a scoreless pitcher's ERA+ would otherwise be infinite
+const MAX_PLUS: u32 = 999;
+
+// Below these, a season is too short for OPS+ or ERA+ to mean much and it's marked as such
+const MIN_PLUS_AT_BATS: u32 = 100;
+const MIN_PLUS_INNINGS: f32 = 20.0;
+
+/// ERA+ = 100 * league ERA / player ERA, so higher is better. A 0.00 ERA is capped at 999; None
+/// if the league ERA isn't positive.
+pub fn era_plus(era: f64, league_era: f64) -> Option<u32> {
+    if league_era.is_nan() || league_era <= 0.0 || era.is_nan() {
+        return None;
+    }
+    if era <= 0.0 {
+        return Some(MAX_PLUS);
+    }
+    Some((100.0 * league_era / era).round().min(MAX_PLUS as f64) as u32)
+}
+
+/// OPS+ = 100 * (OBP / league OBP + SLG / league SLG - 1), the usual approximation without
+/// park adjustments. Floored at 0; None if either league rate isn't positive.
+pub fn ops_plus(obp: f64, slg: f64, league_obp: f64, league_slg: f64) -> Option<u32> {
+    if league_obp.is_nan() || league_obp <= 0.0 || league_slg.is_nan() || league_slg <= 0.0 {
+        return None;
+    }
+    let plus = 100.0 * (obp / league_obp + slg / league_slg - 1.0);
+    if plus.is_nan() {
+        return None;
+    }
+    Some(plus.round().clamp(0.0, MAX_PLUS as f64) as u32)
+}
+
+/// A stat line split by the opponent's handedness
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Splits<T> {
+    pub vs_left: T,
+    pub vs_right: T,
+}
+
+/// A player's platoon splits for one season: hitting against left- and right-handed
+/// pitchers, and pitching against left- and right-handed batters. Either side is None
+/// if the player didn't bat or pitch that season.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerSplits {
+    pub player_id: u32,
+    pub season: u32,
+    pub hitting: Option<Splits<SeasonHittingStats>>,
+    pub pitching: Option<Splits<SeasonPitchingStats>>,
+}
+
+/// Whether a pitcher has mostly started or relieved this season
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PitcherRole {
+    Starter,
+    Reliever,
+}
+
+impl fmt::Display for PitcherRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PitcherRole::Starter => write!(f, "SP"),
+            PitcherRole::Reliever => write!(f, "RP"),
+        }
+    }
+}
+
+/// A pitcher's season line on a team's staff
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaffPitcher {
+    pub name: String,
+    pub role: PitcherRole,
+    pub games: u32,
+    pub games_started: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub saves: u32,
+    pub innings_pitched: String,
+    pub era: Option<String>,
+}
+
+/// A team's pitchers for a season, starters first and then by innings pitched
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PitchingStaff {
+    pub team_id: u32,
+    pub season: u32,
+    pub pitchers: Vec<StaffPitcher>,
+}
+
+impl PitchingStaff {
+    // "W: Name (15)" for the pitcher with the most of a stat, ignoring pitchers with none of it
+    fn leader(&self, label: &str, stat: impl Fn(&StaffPitcher) -> u32) -> Option<String> {
+        self.pitchers
+            .iter()
+            .filter(|pitcher| stat(pitcher) > 0)
+            .max_by_key(|pitcher| stat(pitcher))
+            .map(|pitcher| format!("{}: {} ({})", label, pitcher.name, stat(pitcher)))
+    }
+}
+
+/// A player on a team's roster
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RosterEntry {
+    pub player_id: u32,
+    pub name: String,
+    pub jersey_number: Option<String>,
+    pub position: String,
+    pub status: String,
+}
+
+impl RosterEntry {
+    /// The player's position, for grouping the roster
+    pub fn position(&self) -> crate::types::Position {
+        self.position.parse().unwrap_or(crate::types::Position::Unknown)
+    }
+}
+
+/// A team's roster for a season
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Roster {
+    pub team_id: u32,
+    pub season: u32,
+    pub players: Vec<RosterEntry>,
+}
+
+/// Standings for a single division
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DivisionStandings {
+    pub division: String,
+    pub teams: Vec<StandingsRecord>,
 }
 
 impl fmt::Display for Player {
@@ -471,8 +1122,12 @@ impl fmt::Display for Team {
             writeln!(f, "Abbreviation: {}", abbr)?;
         }
         
+        if let Some(record) = self.record {
+            writeln!(f, "Record: {} ({})", record, Average(record.winning_percentage()))?;
+        }
+        
         if let Some(ref venue) = self.venue {
-            writeln!(f, "Venue: {}", venue.name)?;
+            writeln!(f, "Venue: {}", venue)?;
         }
         
         if let Some(ref league) = self.league {
@@ -493,22 +1148,103 @@ impl fmt::Display for Team {
     }
 }
 
+impl Team {
+    /// Abbreviation if known, otherwise the team name
+    fn short_label(&self) -> &str {
+        self.abbreviation.as_deref().unwrap_or(&self.name)
+    }
+}
+
 impl fmt::Display for Game {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_ordered(f, get_config().home_first)
+    }
+}
+
+impl TeamOrdered for Game {
+    fn fmt_ordered(&self, f: &mut fmt::Formatter<'_>, home_first: bool) -> fmt::Result {
+        // With color on, the winner's score is green, the loser is dimmed and a live status is yellow
+        let winner_id = self.winner().map(|team| team.id);
+        let score = |team: &GameTeam| {
+            let score = team.score.unwrap_or(0);
+            if winner_id == Some(team.team.id) {
+                paint(score, Style::Winner)
+            } else {
+                score.to_string()
+            }
+        };
+        let status = if self.status.is_live() {
+            paint(&self.status, Style::Live)
+        } else {
+            self.status.to_string()
+        };
+        
+        // Compact single-line form: {:#}
+        if f.alternate() {
+            let (first, second) = in_order(&self.teams.away, &self.teams.home, home_first);
+            return write!(
+                f,
+                "{} {} {} {} {} {} ({}{}{})",
+                self.game_date.split('T').next().unwrap_or(&self.game_date),
+                first.team.short_label(),
+                score(first),
+                separator(home_first),
+                second.team.short_label(),
+                score(second),
+                status,
+                if self.is_tie() { ", Tie" } else { "" },
+                self.series_label().map(|label| format!(", {}", label)).unwrap_or_default()
+            );
+        }
+
         writeln!(f, "Game ID: {}", self.game_pk)?;
         writeln!(f, "Date: {}", self.game_date.split('T').next().unwrap_or(&self.game_date))?;
-        writeln!(f, "Status: {}", self.status)?;
+        writeln!(f, "Status: {}", status)?;
+        if self.status == GameState::Scheduled {
+            writeln!(f, "Start Time: {}", scorebug::start_time(&self.game_date))?;
+        }
         writeln!(f, "Teams:")?;
         
-        let away_location = self.teams.away.team.location_name.as_deref().unwrap_or("");
-        let home_location = self.teams.home.team.location_name.as_deref().unwrap_or("");
+        let line = |label: &str, team: &GameTeam| {
+            let location = team.team.location_name.as_deref().unwrap_or("");
+            let line = format!("  {}: {} {} ({})", label, location, team.team.name, score(team));
+            if winner_id.is_some_and(|id| id != team.team.id) {
+                paint(line, Style::Dimmed)
+            } else {
+                line
+            }
+        };
+        let (first, second) = in_order(line("Away", &self.teams.away), line("Home", &self.teams.home), home_first);
+        writeln!(f, "{}", first)?;
+        writeln!(f, "{}", second)?;
+        
+        if let Some(winner) = self.winner() {
+            writeln!(f, "Winner: {}", winner.name)?;
+        } else if self.is_tie() {
+            writeln!(f, "Result: Tie")?;
+        }
         
-        writeln!(f, "  Away: {} {} ({})", away_location, self.teams.away.team.name, self.teams.away.score.unwrap_or(0))?;
-        writeln!(f, "  Home: {} {} ({})", home_location, self.teams.home.team.name, self.teams.home.score.unwrap_or(0))?;
+        if !self.status.is_final() {
+            let away = self.teams.away.probable_pitcher.as_deref();
+            let home = self.teams.home.probable_pitcher.as_deref();
+            if away.is_some() || home.is_some() {
+                let (first, second) = in_order(away, home, home_first);
+                writeln!(f, "Probable: {} vs {}", first.unwrap_or("TBD"), second.unwrap_or("TBD"))?;
+            }
+        }
         
         if let Some(ref venue) = self.venue {
             writeln!(f, "Venue: {}", venue.name)?;
         }
+        match (self.series_label(), self.series_game_number, self.games_in_series) {
+            (Some(label), Some(number), Some(total)) => writeln!(f, "Series: {}, Game {} of {}", label, number, total)?,
+            (Some(label), _, _) => writeln!(f, "Series: {}", label)?,
+            (None, Some(number), Some(total)) => writeln!(f, "Series: Game {} of {}", number, total)?,
+            _ => {}
+        }
+        if let Some(number) = self.game_number.filter(|&number| self.doubleheader || number > 1) {
+            writeln!(f, "Game {} of doubleheader", number)?;
+        }
         Ok(())
     }
 }
@@ -516,16 +1252,47 @@ impl fmt::Display for Game {
 impl MlbApi {
     /// Create a new MLB API client
     pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+
+    /// Create a new MLB API client that uses the given clock for "today"
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
         Self {
-            client: Client::new(),
+            client: Client::builder()
+                .timeout(Duration::from_secs(get_config().request_timeout_secs))
+                .build()
+                .expect("Failed to create HTTP client"),
+            clock,
+            teams_cache: OnceLock::new(),
+            cache: DiskCache::from_config().filter(|_| get_config().cache_enabled),
+        }
+    }
+
+    // Send a GET request, or answer it from a recorded fixture when replaying
+    async fn fetch(&self, url: &str) -> Result<reqwest::Response> {
+        match get_config().replay_dir {
+            Some(ref dir) => crate::replay::response(dir, "mlb", get_mlb_api_base_url()?, url),
+            None => {
+                let response = crate::retry::send_with_retry(get_config().http_retries, || self.client.get(url).send()).await?;
+                crate::replay::dump(get_config().dump_raw_dir.as_deref(), "mlb", get_mlb_api_base_url()?, url, response).await
+            }
         }
     }
 
+    // Fetch a finished game's live feed through the response cache, storing it once the feed
+    // itself says the game is final
+    async fn fetch_final(&self, url: &str) -> Result<reqwest::Response> {
+        crate::cache::fetch_final(self.cache.as_ref(), url, feed_is_final, self.fetch(url)).await
+    }
+
     /// Get player information by ID
     pub async fn get_player(&self, player_id: u32) -> Result<Player> {
         let url = format!("{}/people/{}", get_mlb_api_base_url()?, player_id);
-        let response = self.client.get(&url).send().await?;
+        let response = self.fetch(&url).await?;
         
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(SportError::NotFound(format!("Player {}", player_id)).into());
+        }
         if !response.status().is_success() {
             return Err(anyhow!("Failed to fetch player data: HTTP {}", response.status()));
         }
@@ -544,77 +1311,256 @@ impl MlbApi {
         Ok(player)
     }
 
-    /// Get team information by ID
-    pub async fn get_team(&self, team_id: u32) -> Result<Team> {
-        let url = format!("{}/teams/{}", get_mlb_api_base_url()?, team_id);
-        let response = self.client.get(&url).send().await?;
-
+    /// Get a player's hitting and pitching stats for a season
+    pub async fn get_player_season_stats(&self, player_id: u32, season: u32) -> Result<PlayerSeasonStats> {
+        let url = format!(
+            "{}/people/{}/stats?stats=season&group=hitting,pitching&season={}",
+            get_mlb_api_base_url()?, player_id, season
+        );
+        let response = self.fetch(&url).await?;
+        
         if !response.status().is_success() {
-            return Err(anyhow!("Failed to fetch team data: HTTP {}", response.status()));
+            return Err(anyhow!("Failed to fetch player season stats: HTTP {}", response.status()));
         }
-
+        
         let data = response.json::<serde_json::Value>().await?;
-        let teams = data.get("teams").ok_or_else(|| -> anyhow::Error {
-            anyhow!("Team with ID {} not found", team_id)
-        })?;
-        let team = teams.get(0).ok_or_else(|| -> anyhow::Error {
-            anyhow!("Team with ID {} not found", team_id)
-        })?;
-
-        let team: Team = serde_json::from_value(team.to_owned())?;
-        Ok(team)
+        Ok(self.parse_player_season_stats(&data, player_id, season))
     }
 
-    /// Get schedule for a team
-    pub async fn get_team_schedule(&self, team_id: u32, start_date: Option<String>, end_date: Option<String>) -> Result<Vec<Game>> {
-        // Default to current month if no dates provided
-        let now = Local::now();
-        let start = start_date.unwrap_or_else(|| format!("{}-{:02}-01", now.year(), now.month()));
-        let end = end_date.unwrap_or_else(|| format!("{}-{:02}-30", now.year(), now.month()));
+    /// Helper method to extract the hitting and pitching lines from a season stats response
+    fn parse_player_season_stats(&self, data: &Value, player_id: u32, season: u32) -> PlayerSeasonStats {
+        // Each group's season line is the first split of its entry; groups the player has no
+        // stats in are left out of the response or have no splits
+        let line = |group: &str| {
+            data["stats"].as_array()?
+                .iter()
+                .find(|entry| entry["group"]["displayName"].as_str() == Some(group))
+                .and_then(|entry| entry["splits"].get(0))
+                .map(|split| split["stat"].clone())
+        };
         
+        PlayerSeasonStats {
+            player_id,
+            season,
+            hitting: line("hitting").map(|stat| SeasonHittingStats::from_stat(&stat)),
+            pitching: line("pitching").map(|stat| SeasonPitchingStats::from_stat(&stat)),
+        }
+    }
+
+    /// Get the league-wide OBP, SLG and ERA for a season, from every team's season totals
+    pub async fn get_league_averages(&self, season: u32) -> Result<LeagueAverages> {
         let url = format!(
-            "{}/schedule?teamId={}&startDate={}&endDate={}&sportId=1",
-            get_mlb_api_base_url()?, team_id, start, end
+            "{}/teams/stats?stats=season&group=hitting,pitching&sportIds=1&season={}",
+            get_mlb_api_base_url()?, season
         );
-        
-        let response = self.client.get(&url).send().await?;
+        let response = self.fetch(&url).await?;
         
         if !response.status().is_success() {
-            return Err(anyhow!("Failed to fetch schedule data: HTTP {}", response.status()));
+            return Err(anyhow!("Failed to fetch league averages: HTTP {}", response.status()));
         }
         
-        let schedule: Schedule = response.json().await?;
-        
-        let mut games = Vec::new();
-        for date in schedule.dates {
-            for game in date.games {
-                games.push(game);
-            }
+        let data = response.json::<serde_json::Value>().await?;
+        self.parse_league_averages(&data, season)
+    }
+
+    /// Helper method to total every team's season line into league rates
+    fn parse_league_averages(&self, data: &Value, season: u32) -> Result<LeagueAverages> {
+        let lines = |group: &str| -> Vec<Value> {
+            data["stats"].as_array()
+                .and_then(|stats| stats.iter().find(|entry| entry["group"]["displayName"].as_str() == Some(group)))
+                .and_then(|entry| entry["splits"].as_array())
+                .map(|splits| splits.iter().map(|split| split["stat"].clone()).collect())
+                .unwrap_or_default()
+        };
+        let total = |lines: &[Value], field: &str| -> f64 {
+            lines.iter().map(|stat| stat[field].as_u64().unwrap_or(0) as f64).sum()
+        };
+        
+        // Rates are recomputed from the summed counts rather than averaging each team's rate
+        let hitting = lines("hitting");
+        let on_base = total(&hitting, "hits") + total(&hitting, "baseOnBalls") + total(&hitting, "hitByPitch");
+        let obp_chances = total(&hitting, "atBats") + total(&hitting, "baseOnBalls")
+            + total(&hitting, "hitByPitch") + total(&hitting, "sacFlies");
+        let at_bats = total(&hitting, "atBats");
+        
+        let pitching = lines("pitching");
+        let innings: Vec<&str> = pitching.iter().filter_map(|stat| stat["inningsPitched"].as_str()).collect();
+        let innings = InningsPitched::sum(&innings).as_float() as f64;
+        
+        if obp_chances == 0.0 || at_bats == 0.0 || innings == 0.0 {
+            return Err(anyhow!("No league totals for the {} season", season));
+        }
+        Ok(LeagueAverages {
+            season,
+            obp: on_base / obp_chances,
+            slg: total(&hitting, "totalBases") / at_bats,
+            era: 9.0 * total(&pitching, "earnedRuns") / innings,
+        })
+    }
+
+    /// Get a player's hitting and pitching splits against left- and right-handed opponents for a season
+    pub async fn get_player_splits(&self, player_id: u32, season: u32) -> Result<PlayerSplits> {
+        let url = format!(
+            "{}/people/{}/stats?stats=statSplits&sitCodes=vl,vr&group=hitting,pitching&season={}",
+            get_mlb_api_base_url()?, player_id, season
+        );
+        let response = self.fetch(&url).await?;
+        
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch player splits: HTTP {}", response.status()));
+        }
+        
+        let data = response.json::<serde_json::Value>().await?;
+        Ok(self.parse_player_splits(&data, player_id, season))
+    }
+
+    /// Helper method to extract the vs-left and vs-right lines from a statSplits response
+    fn parse_player_splits(&self, data: &Value, player_id: u32, season: u32) -> PlayerSplits {
+        // Each group's entry has one split per situation code; a side the player never faced
+        // is left out, and a group the player has no stats in has no splits at all
+        let splits = |group: &str| -> Option<Vec<Value>> {
+            data["stats"].as_array()?
+                .iter()
+                .find(|entry| entry["group"]["displayName"].as_str() == Some(group))
+                .and_then(|entry| entry["splits"].as_array())
+                .filter(|splits| !splits.is_empty())
+                .cloned()
+        };
+        let side = |splits: &[Value], code: &str| {
+            splits
+                .iter()
+                .find(|split| split["split"]["code"].as_str() == Some(code))
+                .map(|split| split["stat"].clone())
+                .unwrap_or(Value::Null)
+        };
+        
+        PlayerSplits {
+            player_id,
+            season,
+            hitting: splits("hitting").map(|splits| Splits {
+                vs_left: SeasonHittingStats::from_stat(&side(&splits, "vl")),
+                vs_right: SeasonHittingStats::from_stat(&side(&splits, "vr")),
+            }),
+            pitching: splits("pitching").map(|splits| Splits {
+                vs_left: SeasonPitchingStats::from_stat(&side(&splits, "vl")),
+                vs_right: SeasonPitchingStats::from_stat(&side(&splits, "vr")),
+            }),
+        }
+    }
+
+    /// Get team information by ID
+    pub async fn get_team(&self, team_id: u32) -> Result<Team> {
+        let url = format!("{}/teams/{}?hydrate=venue(location,timezone)", get_mlb_api_base_url()?, team_id);
+        let response = self.fetch(&url).await?;
+        let not_found = || SportError::NotFound(format!("Team {}", team_id));
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(not_found().into());
+        }
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch team data: HTTP {}", response.status()));
+        }
+
+        let data = response.json::<serde_json::Value>().await?;
+        let mut team = parse_team(data["teams"].get(0).ok_or_else(not_found)?)?;
+        
+        // The team endpoint has no record, so it's looked up in this season's standings; a team
+        // that isn't in them (e.g. before opening day) or a failed lookup just leaves it out
+        if team.record.is_none() {
+            let standings = self.get_standings(team.league.as_ref().map(|league| league.id), None).await;
+            team.record = standings.ok().and_then(|divisions| {
+                divisions
+                    .iter()
+                    .flat_map(|division| &division.teams)
+                    .find(|standing| standing.team.id == team.id)
+                    .map(StandingsRecord::record)
+            });
+        }
+        Ok(team)
+    }
+
+    /// Get all MLB teams, sorted by division then name
+    pub async fn get_all_teams(&self) -> Result<Vec<Team>> {
+        if let Some(teams) = self.teams_cache.get() {
+            return Ok(teams.clone());
+        }
+
+        let url = format!("{}/teams?sportId=1", get_mlb_api_base_url()?);
+        let response = self.fetch(&url).await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch teams: HTTP {}", response.status()));
+        }
+
+        let data = response.json::<serde_json::Value>().await?;
+        let teams = data.get("teams").cloned().unwrap_or(Value::Array(Vec::new()));
+        let mut teams: Vec<Team> = serde_json::from_value(teams)?;
+
+        teams.sort_by(|a, b| {
+            let division = |team: &Team| team.division.as_ref().map(|d| d.name.clone()).unwrap_or_default();
+            division(a).cmp(&division(b)).then_with(|| a.name.cmp(&b.name))
+        });
+
+        Ok(self.teams_cache.get_or_init(|| teams).clone())
+    }
+
+    /// Get schedule for a team
+    pub async fn get_team_schedule(&self, team_id: u32, start_date: Option<String>, end_date: Option<String>) -> Result<Vec<Game>> {
+        let url = self.team_schedule_url(team_id, start_date, end_date)?;
+        let response = self.fetch(&url).await?;
+        
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch schedule data: HTTP {}", response.status()));
         }
         
-        Ok(games)
+        let data = response.json::<serde_json::Value>().await?;
+        Ok(self.parse_schedule(&data))
+    }
+
+    /// Find a team's game on a date, using `game_number` to pick one game of a doubleheader
+    pub async fn find_team_game(&self, team_id: u32, date: NaiveDate, game_number: Option<u32>) -> Result<Game> {
+        let day = date.format("%Y-%m-%d").to_string();
+        let games = self.get_team_schedule(team_id, Some(day.clone()), Some(day)).await?;
+        pick_game(games, game_number)
+            .map_err(|e| anyhow!("Team {} on {}: {}", team_id, date.format("%Y-%m-%d"), e))
+    }
+
+    // Build the schedule URL for a team, defaulting to the current month if no dates are provided
+    // and to the end of the start date's month if only that is
+    fn team_schedule_url(&self, team_id: u32, start_date: Option<String>, end_date: Option<String>) -> Result<String> {
+        let (start, end) = schedule_range(self.clock.today(), start_date, end_date)?;
+        
+        Ok(format!(
+            "{}/schedule?teamId={}&startDate={}&endDate={}&sportId=1&hydrate=probablePitcher",
+            get_mlb_api_base_url()?, team_id, start, end
+        ))
     }
 
     /// Get game information by ID
     pub async fn get_game(&self, game_id: u64) -> Result<Game> {
         let url = format!("{}/game/{}/feed/live", get_mlb_api_base_url()?, game_id);
-        let response = self.client.get(&url).send().await?;
+        let response = self.fetch(&url).await?;
         
         if !response.status().is_success() {
             return Err(anyhow!("Failed to fetch game data: HTTP {}", response.status()));
         }
         
         let data = response.json::<serde_json::Value>().await?;
+        self.parse_game_from_value(&data)
+    }
+
+    /// Parse a game from a feed/live response, such as one saved to disk
+    pub fn parse_game_from_value(&self, data: &Value) -> Result<Game> {
         let game_data = data.get("gameData").ok_or_else(|| anyhow!("Missing game data"))?;
         
         // Extract basic game information
         let status_value = &game_data["status"];
         let status_abstract_game_state = status_value.get("abstractGameState").and_then(|s| s.as_str()).unwrap_or("Unknown");
         let status_detailed_state = status_value.get("detailedState").and_then(|s| s.as_str()).unwrap_or("Unknown");
-        println!("DEBUG: Game {} status: abstractGameState='{}', detailedState='{}'", 
-                 game_data["gamePk"].as_u64().unwrap_or(0),
-                 status_abstract_game_state,
-                 status_detailed_state);
+        debug!("Game {} status: abstractGameState='{}', detailedState='{}'", 
+               game_data["gamePk"].as_u64().unwrap_or(0),
+               status_abstract_game_state,
+               status_detailed_state);
         
         // Map the abstractGameState to our GameState enum
         let status = match status_abstract_game_state {
@@ -627,30 +1573,47 @@ impl MlbApi {
             _ => GameState::Unknown,
         };
         
+        let mut away = self.extract_game_team(data, "away")?;
+        let mut home = self.extract_game_team(data, "home")?;
+        
+        // feed/live doesn't flag the winner, so take it from the final score
+        if status == GameState::Final && away.is_winner.is_none() && home.is_winner.is_none() {
+            if let (Some(away_runs), Some(home_runs)) = (away.score, home.score) {
+                if away_runs != home_runs {
+                    away.is_winner = Some(away_runs > home_runs);
+                    home.is_winner = Some(home_runs > away_runs);
+                }
+            }
+        }
+        
+        let current_inning = current_inning(&status, &data["liveData"]["linescore"]);
         let game = Game {
             game_pk: game_data["gamePk"].as_u64().unwrap_or(0) as u32,
             game_date: game_data["gameDate"].as_str().unwrap_or("").to_string(),
             status, // Use our mapped status
-            teams: GameTeams {
-                away: self.extract_game_team(&data, "away")?,
-                home: self.extract_game_team(&data, "home")?,
-            },
-            venue: Some(Venue {
-                id: game_data["venue"]["id"].as_u64().unwrap_or(0) as u32,
-                name: game_data["venue"]["name"].as_str().unwrap_or("").to_string(),
-            }),
+            teams: GameTeams { away, home },
+            venue: Some(Venue::from_json(&game_data["venue"])),
+            series_game_number: game_data["game"]["seriesGameNumber"].as_u64().map(|n| n as u32),
+            games_in_series: game_data["game"]["gamesInSeries"].as_u64().map(|n| n as u32),
+            game_number: game_data["game"]["gameNumber"].as_u64().map(|n| n as u32),
+            doubleheader: is_doubleheader(&game_data["game"]["doubleHeader"]),
+            current_inning,
+            game_type: game_data["game"]["type"].as_str().map(String::from),
         };
         Ok(game)
     }
 
     /// Helper method to extract team information from game data
     fn extract_game_team(&self, data: &Value, team_type: &str) -> Result<GameTeam> {
-        let team_data = data.get(team_type).ok_or_else(|| 
-            anyhow!("Missing {} team data", team_type)
-        )?;
+        // feed/live keeps the teams under gameData and the runs in the linescore
+        let team_data = data["gameData"]["teams"].get(team_type)
+            .or_else(|| data.get(team_type))
+            .ok_or_else(|| anyhow!("Missing {} team data", team_type))?;
+        let runs = team_data.get("runs")
+            .or_else(|| data["liveData"]["linescore"]["teams"][team_type].get("runs"));
         
         Ok(GameTeam {
-            score: team_data["runs"].as_u64().map(|s| s as u32),
+            score: runs.and_then(|r| r.as_u64()).map(|s| s as u32),
             team: Team {
                 id: team_data["id"].as_u64().unwrap_or(0) as u32,
                 name: team_data["name"].as_str().unwrap_or("").to_string(),
@@ -667,134 +1630,50 @@ impl MlbApi {
                 venue: None,
                 league: None,
                 division: None,
+                record: None,
             },
             is_winner: team_data["isWinner"].as_bool(),
+            probable_pitcher: None,
         })
     }
 
     /// Get all games scheduled for today
     pub async fn get_todays_games(&self) -> Result<Vec<Game>> {
-        let today = Local::now().date_naive();
-        let url = format!(
-            "{}/schedule?sportId=1&date={}-{}-{}&hydrate=game(content(editorial(recap))),linescore,team",
-            get_mlb_api_base_url()?,
-            today.year(),
-            today.month(),
-            today.day(),
-        );
-        
-        let response = self.client.get(&url).send().await?;
-        
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to fetch today's games: HTTP {}", response.status()));
-        }
-        
-        let data = response.json::<serde_json::Value>().await?;
-        let empty_vec = Vec::new();
-        let dates = data.get("dates").and_then(|d| d.as_array()).unwrap_or(&empty_vec);
-        
-        let mut games = Vec::new();
-        for date in dates {
-            if let Some(games_array) = date.get("games").and_then(|g| g.as_array()) {
-                for game_data in games_array {
-                    // Add debug information about the game status
-                    let status_value = &game_data["status"];
-                    let status_abstract_game_state = status_value.get("abstractGameState").and_then(|s| s.as_str()).unwrap_or("Unknown");
-                    let status_detailed_state = status_value.get("detailedState").and_then(|s| s.as_str()).unwrap_or("Unknown");
-                    println!("DEBUG: Game {} status: abstractGameState='{}', detailedState='{}'", 
-                             game_data["gamePk"].as_u64().unwrap_or(0),
-                             status_abstract_game_state,
-                             status_detailed_state);
-                    
-                    // Map the abstractGameState to our GameState enum
-                    let status = match status_abstract_game_state {
-                        "Final" => GameState::Final,
-                        "Live" => GameState::Live,
-                        "Preview" => GameState::Scheduled,
-                        "Postponed" => GameState::Postponed,
-                        "Cancelled" => GameState::Cancelled,
-                        "Suspended" => GameState::Suspended,
-                        _ => GameState::Unknown,
-                    };
-                    
-                    let game = Game {
-                        game_pk: game_data["gamePk"].as_u64().unwrap_or(0) as u32,
-                        game_date: game_data["gameDate"].as_str().unwrap_or("").to_string(),
-                        status,
-                        teams: GameTeams {
-                            away: GameTeam {
-                                score: game_data["teams"]["away"]["score"].as_u64().map(|s| s as u32),
-                                team: Team {
-                                    id: game_data["teams"]["away"]["team"]["id"].as_u64().unwrap_or(0) as u32,
-                                    name: game_data["teams"]["away"]["team"]["name"].as_str().unwrap_or("").to_string(),
-                                    team_code: game_data["teams"]["away"]["team"]["teamCode"].as_str().map(String::from),
-                                    file_code: game_data["teams"]["away"]["team"]["fileCode"].as_str().map(String::from),
-                                    team_name: game_data["teams"]["away"]["team"]["teamName"].as_str().map(String::from),
-                                    location_name: game_data["teams"]["away"]["team"]["locationName"].as_str().map(String::from),
-                                    short_name: game_data["teams"]["away"]["team"]["shortName"].as_str().map(String::from),
-                                    abbreviation: game_data["teams"]["away"]["team"]["abbreviation"].as_str().map(String::from),
-                                    franchise_name: None,
-                                    club_name: None,
-                                    first_year_of_play: None,
-                                    active: None,
-                                    venue: None,
-                                    league: None,
-                                    division: None,
-                                },
-                                is_winner: game_data["teams"]["away"]["isWinner"].as_bool(),
-                            },
-                            home: GameTeam {
-                                score: game_data["teams"]["home"]["score"].as_u64().map(|s| s as u32),
-                                team: Team {
-                                    id: game_data["teams"]["home"]["team"]["id"].as_u64().unwrap_or(0) as u32,
-                                    name: game_data["teams"]["home"]["team"]["name"].as_str().unwrap_or("").to_string(),
-                                    team_code: game_data["teams"]["home"]["team"]["teamCode"].as_str().map(String::from),
-                                    file_code: game_data["teams"]["home"]["team"]["fileCode"].as_str().map(String::from),
-                                    team_name: game_data["teams"]["home"]["team"]["teamName"].as_str().map(String::from),
-                                    location_name: game_data["teams"]["home"]["team"]["locationName"].as_str().map(String::from),
-                                    short_name: game_data["teams"]["home"]["team"]["shortName"].as_str().map(String::from),
-                                    abbreviation: game_data["teams"]["home"]["team"]["abbreviation"].as_str().map(String::from),
-                                    franchise_name: None,
-                                    club_name: None,
-                                    first_year_of_play: None,
-                                    active: None,
-                                    venue: None,
-                                    league: None,
-                                    division: None,
-                                },
-                                is_winner: game_data["teams"]["home"]["isWinner"].as_bool(),
-                            },
-                        },
-                        venue: Some(Venue {
-                            id: game_data["venue"]["id"].as_u64().unwrap_or(0) as u32,
-                            name: game_data["venue"]["name"].as_str().unwrap_or("").to_string(),
-                        }),
-                    };
-                    games.push(game);
-                }
-            }
-        }
-        
-        Ok(games)
+        let today = self.clock.today();
+        self.get_games_by_date(today).await
     }
 
     /// Get all games scheduled for yesterday
     pub async fn get_yesterdays_games(&self) -> Result<Vec<Game>> {
-        // Get yesterday's date in YYYY-MM-DD format
-        let yesterday = Local::now().checked_sub_days(chrono::Days::new(1)).unwrap().format("%Y-%m-%d").to_string();
-        
-        let url = format!(
-            "{}/schedule?sportId=1&date={}&hydrate=game(content(editorial(recap))),linescore,team",
-            get_mlb_api_base_url()?, yesterday
-        );
+        let yesterday = self.clock.today().pred_opt().ok_or_else(|| anyhow!("Failed to calculate yesterday's date"))?;
+        self.get_games_by_date(yesterday).await
+    }
+
+    // Build the schedule URL for all games on a date
+    fn games_by_date_url(&self, date: NaiveDate) -> Result<String> {
+        Ok(format!(
+            "{}/schedule?sportId=1&date={}&hydrate=game(content(editorial(recap))),linescore,team,probablePitcher",
+            get_mlb_api_base_url()?, date.format("%Y-%m-%d")
+        ))
+    }
+
+    /// Get all games scheduled for a specific date
+    pub async fn get_games_by_date(&self, date: NaiveDate) -> Result<Vec<Game>> {
+        let date_str = date.format("%Y-%m-%d").to_string();
+        let url = self.games_by_date_url(date)?;
         
-        let response = self.client.get(&url).send().await?;
+        let response = self.fetch(&url).await?;
         
         if !response.status().is_success() {
-            return Err(anyhow!("Failed to fetch yesterday's games: HTTP {}", response.status()));
+            return Err(anyhow!("Failed to fetch games for {}: HTTP {}", date_str, response.status()));
         }
         
         let data = response.json::<serde_json::Value>().await?;
+        Ok(self.parse_schedule(&data))
+    }
+
+    /// Helper method to build the games from every date of a schedule response
+    fn parse_schedule(&self, data: &Value) -> Vec<Game> {
         let empty_vec = Vec::new();
         let dates = data.get("dates").and_then(|d| d.as_array()).unwrap_or(&empty_vec);
         
@@ -802,91 +1681,114 @@ impl MlbApi {
         for date in dates {
             if let Some(games_array) = date.get("games").and_then(|g| g.as_array()) {
                 for game_data in games_array {
-                    // Add debug information about the game status
-                    let status_value = &game_data["status"];
-                    let status_abstract_game_state = status_value.get("abstractGameState").and_then(|s| s.as_str()).unwrap_or("Unknown");
-                    let status_detailed_state = status_value.get("detailedState").and_then(|s| s.as_str()).unwrap_or("Unknown");
-                    println!("DEBUG: Game {} status: abstractGameState='{}', detailedState='{}'", 
-                             game_data["gamePk"].as_u64().unwrap_or(0),
-                             status_abstract_game_state,
-                             status_detailed_state);
-                    
-                    // Map the abstractGameState to our GameState enum
-                    let status = match status_abstract_game_state {
-                        "Final" => GameState::Final,
-                        "Live" => GameState::Live,
-                        "Preview" => GameState::Scheduled,
-                        "Postponed" => GameState::Postponed,
-                        "Cancelled" => GameState::Cancelled,
-                        "Suspended" => GameState::Suspended,
-                        _ => GameState::Unknown,
-                    };
-                    
-                    let game = Game {
-                        game_pk: game_data["gamePk"].as_u64().unwrap_or(0) as u32,
-                        game_date: game_data["gameDate"].as_str().unwrap_or("").to_string(),
-                        status,
-                        teams: GameTeams {
-                            away: GameTeam {
-                                score: game_data["teams"]["away"]["score"].as_u64().map(|s| s as u32),
-                                team: Team {
-                                    id: game_data["teams"]["away"]["team"]["id"].as_u64().unwrap_or(0) as u32,
-                                    name: game_data["teams"]["away"]["team"]["name"].as_str().unwrap_or("").to_string(),
-                                    team_code: game_data["teams"]["away"]["team"]["teamCode"].as_str().map(String::from),
-                                    file_code: game_data["teams"]["away"]["team"]["fileCode"].as_str().map(String::from),
-                                    team_name: game_data["teams"]["away"]["team"]["teamName"].as_str().map(String::from),
-                                    location_name: game_data["teams"]["away"]["team"]["locationName"].as_str().map(String::from),
-                                    short_name: game_data["teams"]["away"]["team"]["shortName"].as_str().map(String::from),
-                                    abbreviation: game_data["teams"]["away"]["team"]["abbreviation"].as_str().map(String::from),
-                                    franchise_name: None,
-                                    club_name: None,
-                                    first_year_of_play: None,
-                                    active: None,
-                                    venue: None,
-                                    league: None,
-                                    division: None,
-                                },
-                                is_winner: game_data["teams"]["away"]["isWinner"].as_bool(),
-                            },
-                            home: GameTeam {
-                                score: game_data["teams"]["home"]["score"].as_u64().map(|s| s as u32),
-                                team: Team {
-                                    id: game_data["teams"]["home"]["team"]["id"].as_u64().unwrap_or(0) as u32,
-                                    name: game_data["teams"]["home"]["team"]["name"].as_str().unwrap_or("").to_string(),
-                                    team_code: game_data["teams"]["home"]["team"]["teamCode"].as_str().map(String::from),
-                                    file_code: game_data["teams"]["home"]["team"]["fileCode"].as_str().map(String::from),
-                                    team_name: game_data["teams"]["home"]["team"]["teamName"].as_str().map(String::from),
-                                    location_name: game_data["teams"]["home"]["team"]["locationName"].as_str().map(String::from),
-                                    short_name: game_data["teams"]["home"]["team"]["shortName"].as_str().map(String::from),
-                                    abbreviation: game_data["teams"]["home"]["team"]["abbreviation"].as_str().map(String::from),
-                                    franchise_name: None,
-                                    club_name: None,
-                                    first_year_of_play: None,
-                                    active: None,
-                                    venue: None,
-                                    league: None,
-                                    division: None,
-                                },
-                                is_winner: game_data["teams"]["home"]["isWinner"].as_bool(),
-                            },
-                        },
-                        venue: Some(Venue {
-                            id: game_data["venue"]["id"].as_u64().unwrap_or(0) as u32,
-                            name: game_data["venue"]["name"].as_str().unwrap_or("").to_string(),
-                        }),
-                    };
-                    games.push(game);
+                    games.push(self.parse_schedule_game(game_data));
                 }
             }
         }
+        games
+    }
+
+    /// Helper method to build a game from an entry in a schedule response
+    fn parse_schedule_game(&self, game_data: &Value) -> Game {
+        // Add debug information about the game status
+        let status_value = &game_data["status"];
+        let status_abstract_game_state = status_value.get("abstractGameState").and_then(|s| s.as_str()).unwrap_or("Unknown");
+        let status_detailed_state = status_value.get("detailedState").and_then(|s| s.as_str()).unwrap_or("Unknown");
+        debug!("Game {} status: abstractGameState='{}', detailedState='{}'", 
+               game_data["gamePk"].as_u64().unwrap_or(0),
+               status_abstract_game_state,
+               status_detailed_state);
+        
+        // Map the abstractGameState to our GameState enum
+        let status = match status_abstract_game_state {
+            "Final" => GameState::Final,
+            "Live" => GameState::Live,
+            "Preview" => GameState::Scheduled,
+            "Postponed" => GameState::Postponed,
+            "Cancelled" => GameState::Cancelled,
+            "Suspended" => GameState::Suspended,
+            _ => GameState::Unknown,
+        };
         
-        Ok(games)
+        let current_inning = current_inning(&status, &game_data["linescore"]);
+        Game {
+            game_pk: game_data["gamePk"].as_u64().unwrap_or(0) as u32,
+            game_date: game_data["gameDate"].as_str().unwrap_or("").to_string(),
+            status,
+            teams: GameTeams {
+                away: GameTeam {
+                    score: game_data["teams"]["away"]["score"].as_u64().map(|s| s as u32),
+                    team: Team {
+                        id: game_data["teams"]["away"]["team"]["id"].as_u64().unwrap_or(0) as u32,
+                        name: game_data["teams"]["away"]["team"]["name"].as_str().unwrap_or("").to_string(),
+                        team_code: game_data["teams"]["away"]["team"]["teamCode"].as_str().map(String::from),
+                        file_code: game_data["teams"]["away"]["team"]["fileCode"].as_str().map(String::from),
+                        team_name: game_data["teams"]["away"]["team"]["teamName"].as_str().map(String::from),
+                        location_name: game_data["teams"]["away"]["team"]["locationName"].as_str().map(String::from),
+                        short_name: game_data["teams"]["away"]["team"]["shortName"].as_str().map(String::from),
+                        abbreviation: game_data["teams"]["away"]["team"]["abbreviation"].as_str().map(String::from),
+                        franchise_name: None,
+                        club_name: None,
+                        first_year_of_play: None,
+                        active: None,
+                        venue: None,
+                        league: None,
+                        division: None,
+                        record: None,
+                    },
+                    is_winner: game_data["teams"]["away"]["isWinner"].as_bool(),
+                    probable_pitcher: probable_pitcher(&game_data["teams"]["away"]),
+                },
+                home: GameTeam {
+                    score: game_data["teams"]["home"]["score"].as_u64().map(|s| s as u32),
+                    team: Team {
+                        id: game_data["teams"]["home"]["team"]["id"].as_u64().unwrap_or(0) as u32,
+                        name: game_data["teams"]["home"]["team"]["name"].as_str().unwrap_or("").to_string(),
+                        team_code: game_data["teams"]["home"]["team"]["teamCode"].as_str().map(String::from),
+                        file_code: game_data["teams"]["home"]["team"]["fileCode"].as_str().map(String::from),
+                        team_name: game_data["teams"]["home"]["team"]["teamName"].as_str().map(String::from),
+                        location_name: game_data["teams"]["home"]["team"]["locationName"].as_str().map(String::from),
+                        short_name: game_data["teams"]["home"]["team"]["shortName"].as_str().map(String::from),
+                        abbreviation: game_data["teams"]["home"]["team"]["abbreviation"].as_str().map(String::from),
+                        franchise_name: None,
+                        club_name: None,
+                        first_year_of_play: None,
+                        active: None,
+                        venue: None,
+                        league: None,
+                        division: None,
+                        record: None,
+                    },
+                    is_winner: game_data["teams"]["home"]["isWinner"].as_bool(),
+                    probable_pitcher: probable_pitcher(&game_data["teams"]["home"]),
+                },
+            },
+            series_game_number: game_data["seriesGameNumber"].as_u64().map(|n| n as u32),
+            games_in_series: game_data["gamesInSeries"].as_u64().map(|n| n as u32),
+            game_number: game_data["gameNumber"].as_u64().map(|n| n as u32),
+            doubleheader: is_doubleheader(&game_data["doubleHeader"]),
+            current_inning,
+            game_type: game_data["gameType"].as_str().map(String::from),
+            venue: Some(Venue::from_json(&game_data["venue"])),
+        }
     }
 
     /// Get inning-by-inning data for a game
     pub async fn get_game_innings(&self, game_id: u32) -> Result<GameInnings> {
+        self.innings(game_id, false).await
+    }
+
+    /// Get the inning-by-inning breakdown of a finished game, reading its live feed through the
+    /// response cache
+    pub async fn get_final_game_innings(&self, game_id: u32) -> Result<GameInnings> {
+        self.innings(game_id, true).await
+    }
+
+    // The inning-by-inning breakdown, from the live feed (cached once the game is final, if
+    // `finished`) or else from the linescore or play-by-play
+    async fn innings(&self, game_id: u32, finished: bool) -> Result<GameInnings> {
         // Try each endpoint in sequence
-        match self.try_feed_live_endpoint(game_id).await {
+        match self.try_feed_live_endpoint(game_id, finished).await {
             Ok(innings) => return Ok(innings),
             Err(feed_live_error) => {
                 // Feed/live endpoint failed, try linescore endpoint
@@ -911,9 +1813,13 @@ impl MlbApi {
     }
 
     /// Try to get inning data from the feed/live endpoint
-    async fn try_feed_live_endpoint(&self, game_id: u32) -> Result<GameInnings> {
+    async fn try_feed_live_endpoint(&self, game_id: u32, finished: bool) -> Result<GameInnings> {
         let feed_live_url = format!("{}/game/{}/feed/live", get_mlb_api_base_url()?, game_id);
-        let response = self.client.get(&feed_live_url).send().await?;
+        let response = if finished {
+            self.fetch_final(&feed_live_url).await?
+        } else {
+            self.fetch(&feed_live_url).await?
+        };
         
         if !response.status().is_success() {
             return Err(anyhow!("HTTP error: {}", response.status()));
@@ -940,10 +1846,10 @@ impl MlbApi {
         let status_value = game_data.get("status").ok_or_else(|| anyhow!("Missing status data"))?;
         let status_abstract_game_state = status_value.get("abstractGameState").and_then(|s| s.as_str()).unwrap_or("Unknown");
         let status_detailed_state = status_value.get("detailedState").and_then(|s| s.as_str()).unwrap_or("Unknown");
-        println!("DEBUG: Game {} innings - status: abstractGameState='{}', detailedState='{}'", 
-                game_id,
-                status_abstract_game_state,
-                status_detailed_state);
+        debug!("Game {} innings - status: abstractGameState='{}', detailedState='{}'", 
+               game_id,
+               status_abstract_game_state,
+               status_detailed_state);
         
         // Map the abstractGameState to our GameState enum
         let status = match status_abstract_game_state {
@@ -981,6 +1887,7 @@ impl MlbApi {
             venue: None,
             league: None,
             division: None,
+            record: None,
         };
         
         let away_team = Team {
@@ -999,6 +1906,7 @@ impl MlbApi {
             venue: None,
             league: None,
             division: None,
+            record: None,
         };
         
         // Extract innings data
@@ -1019,10 +1927,6 @@ impl MlbApi {
             }
         }
         
-        // Extract total runs
-        let home_runs = home["runs"].as_u64().map(|r| r as u32);
-        let away_runs = away["runs"].as_u64().map(|r| r as u32);
-        
         // Extract game info
         let game_pk = game_data["game"]["pk"].as_u64().unwrap_or(0) as u32;
         let game_date = game_data["datetime"]["dateTime"].as_str().unwrap_or("").to_string();
@@ -1034,17 +1938,21 @@ impl MlbApi {
             home_team,
             away_team,
             innings,
-            home_runs,
-            away_runs,
+            home_runs: total(home, "runs"),
+            away_runs: total(away, "runs"),
+            home_hits: total(home, "hits"),
+            away_hits: total(away, "hits"),
+            home_errors: total(home, "errors"),
+            away_errors: total(away, "errors"),
         })
     }
 
     /// Try to get inning data from the linescore endpoint
     async fn try_linescore_endpoint(&self, game_id: u32) -> Result<GameInnings> {
-        println!("DEBUG: Using /linescore endpoint for game {}", game_id);
+        debug!("Using /linescore endpoint for game {}", game_id);
         
         let linescore_url = format!("{}/game/{}/linescore", get_mlb_api_base_url()?, game_id);
-        let linescore_response = self.client.get(&linescore_url).send().await?;
+        let linescore_response = self.fetch(&linescore_url).await?;
         
         if !linescore_response.status().is_success() {
             return Err(anyhow!("HTTP error: {}", linescore_response.status()));
@@ -1052,7 +1960,7 @@ impl MlbApi {
         
         // Get basic game info from the boxscore endpoint since we know that's working
         let boxscore_url = format!("{}/game/{}/boxscore", get_mlb_api_base_url()?, game_id);
-        let boxscore_response = self.client.get(&boxscore_url).send().await?;
+        let boxscore_response = self.fetch(&boxscore_url).await?;
         
         if !boxscore_response.status().is_success() {
             return Err(anyhow!("Failed to fetch game data from boxscore: HTTP {}", boxscore_response.status()));
@@ -1085,6 +1993,7 @@ impl MlbApi {
             venue: None,
             league: None,
             division: None,
+            record: None,
         };
         
         let away_team = Team {
@@ -1103,6 +2012,7 @@ impl MlbApi {
             venue: None,
             league: None,
             division: None,
+            record: None,
         };
         
         // Assume it's a completed game if we're getting linescore data for a game we know is done
@@ -1126,10 +2036,6 @@ impl MlbApi {
             }
         }
         
-        // Extract total runs
-        let home_runs = home["runs"].as_u64().map(|r| r as u32);
-        let away_runs = away["runs"].as_u64().map(|r| r as u32);
-        
         // Get game date from info object if available
         let game_date = linescore_data.get("gameDate")
             .and_then(|d| d.as_str())
@@ -1143,94 +2049,560 @@ impl MlbApi {
             home_team,
             away_team,
             innings,
-            home_runs,
-            away_runs,
+            home_runs: total(home, "runs"),
+            away_runs: total(away, "runs"),
+            home_hits: total(home, "hits"),
+            away_hits: total(away, "hits"),
+            home_errors: total(home, "errors"),
+            away_errors: total(away, "errors"),
         })
     }
 
     /// Try to get inning data from the playByPlay endpoint
     async fn try_playbyplay_endpoint(&self, game_id: u32) -> Result<GameInnings> {
-        println!("DEBUG: Using /playByPlay endpoint for game {}", game_id);
+        debug!("Using /playByPlay endpoint for game {}", game_id);
         
         let playbyplay_url = format!("{}/game/{}/playByPlay", get_mlb_api_base_url()?, game_id);
-        let playbyplay_response = self.client.get(&playbyplay_url).send().await?;
+        let playbyplay_response = self.fetch(&playbyplay_url).await?;
         
         if !playbyplay_response.status().is_success() {
             return Err(anyhow!("HTTP error: {}", playbyplay_response.status()));
         }
         
-        // For now, we'll return an error since we haven't implemented this endpoint yet
-        Err(anyhow!("Play-by-play endpoint found but not implemented for inning extraction"))
+        let data = playbyplay_response.json::<serde_json::Value>().await?;
+        let innings = self.parse_play_by_play_innings(&data);
+        if innings.is_empty() {
+            return Err(anyhow!("No plays in play-by-play data"));
+        }
+        
+        // The play-by-play has no team information, so take it from the boxscore
+        let boxscore_url = format!("{}/game/{}/boxscore", get_mlb_api_base_url()?, game_id);
+        let boxscore_response = self.fetch(&boxscore_url).await?;
+        
+        if !boxscore_response.status().is_success() {
+            return Err(anyhow!("Failed to fetch game data from boxscore: HTTP {}", boxscore_response.status()));
+        }
+        
+        let boxscore_data = boxscore_response.json::<serde_json::Value>().await?;
+        let team = |side: &str| {
+            let team = &boxscore_data["teams"][side]["team"];
+            Team {
+                id: team["id"].as_u64().unwrap_or(0) as u32,
+                name: team["name"].as_str().unwrap_or("").to_string(),
+                team_code: None,
+                file_code: None,
+                team_name: None,
+                location_name: None,
+                short_name: None,
+                abbreviation: team["abbreviation"].as_str().map(String::from),
+                franchise_name: None,
+                club_name: None,
+                first_year_of_play: None,
+                active: None,
+                venue: None,
+                league: None,
+                division: None,
+                record: None,
+            }
+        };
+        let runs = |side: fn(&InningData) -> Option<u32>| innings.iter().filter_map(side).sum();
+        
+        Ok(GameInnings {
+            game_pk: game_id,
+            game_date: String::new(),
+            // As with the linescore, assume the game is done if it has a play-by-play
+            status: GameState::Final,
+            home_team: team("home"),
+            away_team: team("away"),
+            home_runs: Some(runs(|inning| inning.home)),
+            away_runs: Some(runs(|inning| inning.away)),
+            innings,
+            home_hits: None,
+            away_hits: None,
+            home_errors: None,
+            away_errors: None,
+        })
+    }
+    
+    /// Helper method to total the runs scored in each half-inning of a play-by-play response
+    fn parse_play_by_play_innings(&self, data: &Value) -> Vec<InningData> {
+        let mut innings: Vec<InningData> = Vec::new();
+        let (mut away_score, mut home_score) = (0, 0);
+        
+        for play in data["allPlays"].as_array().into_iter().flatten() {
+            let inning = play["about"]["inning"].as_u64().unwrap_or(0) as u32;
+            if inning == 0 {
+                continue;
+            }
+            while innings.len() < inning as usize {
+                innings.push(InningData { inning: innings.len() as u32 + 1, home: None, away: None });
+            }
+            
+            // Runs on a play are the change in the score since the play before it
+            let away = play["result"]["awayScore"].as_u64().map_or(away_score, |score| score as u32);
+            let home = play["result"]["homeScore"].as_u64().map_or(home_score, |score| score as u32);
+            let line = &mut innings[inning as usize - 1];
+            if play["about"]["isTopInning"].as_bool().unwrap_or(play["about"]["halfInning"] == "top") {
+                line.away = Some(line.away.unwrap_or(0) + away.saturating_sub(away_score));
+            } else {
+                line.home = Some(line.home.unwrap_or(0) + home.saturating_sub(home_score));
+            }
+            away_score = away;
+            home_score = home;
+        }
+        
+        innings
     }
-
-    /// Get game statistics
-    pub async fn get_game_stats(&self, game_id: u32) -> Result<GameStats> {
-        let url = format!("{}/game/{}/boxscore", get_mlb_api_base_url()?, game_id);
-        let response = self.client.get(&url).send().await?;
+    
+    /// Get the plays on which runs scored in a game, in the order they happened
+    pub async fn get_scoring_plays(&self, game_id: u32) -> Result<Vec<ScoringPlay>> {
+        let url = format!("{}/game/{}/playByPlay", get_mlb_api_base_url()?, game_id);
+        let response = self.fetch(&url).await?;
         
         if !response.status().is_success() {
-            return Err(anyhow!("Failed to fetch game stats: HTTP {}", response.status()));
+            return Err(anyhow!("Failed to fetch play-by-play data: HTTP {}", response.status()));
         }
         
         let data = response.json::<serde_json::Value>().await?;
-        let teams = data.get("teams").ok_or_else(|| anyhow!("Missing teams data"))?;
-        let away = teams.get("away").ok_or_else(|| anyhow!("Missing away team data"))?;
-        let home = teams.get("home").ok_or_else(|| anyhow!("Missing home team data"))?;
         
-        // Extract team stats
-        let away_team_stats = self.extract_team_stats(away)?;
-        let home_team_stats = self.extract_team_stats(home)?;
+        // The play-by-play has no team information, so take it from the boxscore
+        let boxscore_url = format!("{}/game/{}/boxscore", get_mlb_api_base_url()?, game_id);
+        let boxscore_response = self.fetch(&boxscore_url).await?;
         
-        Ok(GameStats {
-            away_team_stats,
-            home_team_stats,
-        })
+        if !boxscore_response.status().is_success() {
+            return Err(anyhow!("Failed to fetch game data from boxscore: HTTP {}", boxscore_response.status()));
+        }
+        
+        let boxscore_data = boxscore_response.json::<serde_json::Value>().await?;
+        let label = |side: &str| {
+            let team = &boxscore_data["teams"][side]["team"];
+            team["abbreviation"].as_str().or(team["name"].as_str()).unwrap_or(side).to_string()
+        };
+        
+        Ok(self.parse_scoring_plays(&data, &label("away"), &label("home")))
+    }
+    
+    /// Helper method to pick out the plays on which runs scored from a play-by-play response
+    fn parse_scoring_plays(&self, data: &Value, away_team: &str, home_team: &str) -> Vec<ScoringPlay> {
+        data["allPlays"].as_array()
+            .into_iter()
+            .flatten()
+            .filter(|play| play["about"]["isScoringPlay"].as_bool().unwrap_or(false))
+            .map(|play| ScoringPlay {
+                inning: play["about"]["inning"].as_u64().unwrap_or(0) as u32,
+                half: play["about"]["halfInning"].as_str().unwrap_or("top").to_string(),
+                description: play["result"]["description"].as_str().unwrap_or("").trim().to_string(),
+                away_team: away_team.to_string(),
+                home_team: home_team.to_string(),
+                away_score: play["result"]["awayScore"].as_u64().unwrap_or(0) as u32,
+                home_score: play["result"]["homeScore"].as_u64().unwrap_or(0) as u32,
+            })
+            .collect()
     }
 
-    /// Helper method to extract team statistics
-    fn extract_team_stats(&self, team_data: &Value) -> Result<TeamStats> {
-        // Get team info
-        let team_name = team_data.get("team").and_then(|t| t.get("name"))
-            .and_then(|n| n.as_str())
-            .ok_or_else(|| anyhow!("Missing team name"))?
-            .to_string();
-            
-        // Extract batting stats
-        let batting = BattingStats {
-            runs: team_data["teamStats"]["batting"]["runs"].as_u64().unwrap_or(0) as u32,
-            hits: team_data["teamStats"]["batting"]["hits"].as_u64().unwrap_or(0) as u32,
-            home_runs: team_data["teamStats"]["batting"]["homeRuns"].as_u64().unwrap_or(0) as u32,
-            rbi: team_data["teamStats"]["batting"]["rbi"].as_u64().unwrap_or(0) as u32,
-            stolen_bases: team_data["teamStats"]["batting"]["stolenBases"].as_u64().unwrap_or(0) as u32,
-            avg: team_data["teamStats"]["batting"]["avg"].as_str().unwrap_or(".000").to_string(),
-            obp: team_data["teamStats"]["batting"]["obp"].as_str().unwrap_or(".000").to_string(),
-            slg: team_data["teamStats"]["batting"]["slg"].as_str().unwrap_or(".000").to_string(),
-            ops: team_data["teamStats"]["batting"]["ops"].as_str().unwrap_or(".000").to_string(),
-        };
+    /// Get a batter's career line against a pitcher
+    pub async fn get_matchup(&self, batter_id: u32, pitcher_id: u32) -> Result<Matchup> {
+        let url = format!(
+            "{}/people/{}/stats?stats=vsPlayer&opposingPlayerId={}&group=hitting&sportId=1",
+            get_mlb_api_base_url()?, batter_id, pitcher_id
+        );
+        let response = self.fetch(&url).await?;
         
-        // Extract pitching stats
-        let pitching = PitchingStats {
-            innings_pitched: team_data["teamStats"]["pitching"]["inningsPitched"].as_str().unwrap_or("0").to_string(),
-            hits_allowed: team_data["teamStats"]["pitching"]["hits"].as_u64().unwrap_or(0) as u32,
-            runs_allowed: team_data["teamStats"]["pitching"]["runs"].as_u64().unwrap_or(0) as u32,
-            earned_runs: team_data["teamStats"]["pitching"]["earnedRuns"].as_u64().unwrap_or(0) as u32,
-            walks: team_data["teamStats"]["pitching"]["baseOnBalls"].as_u64().unwrap_or(0) as u32,
-            strikeouts: team_data["teamStats"]["pitching"]["strikeOuts"].as_u64().unwrap_or(0) as u32,
-            home_runs_allowed: team_data["teamStats"]["pitching"]["homeRuns"].as_u64().unwrap_or(0) as u32,
-            era: team_data["teamStats"]["pitching"]["era"].as_str().unwrap_or("0.00").to_string(),
-        };
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch matchup data: HTTP {}", response.status()));
+        }
         
-        // Debug print to check if batters and pitchers data exists in the response
-        println!("DEBUG: Batters data exists: {}", team_data.get("batters").is_some());
-        if let Some(batters_array) = team_data.get("batters") {
-            println!("DEBUG: Batters array is array: {}", batters_array.is_array());
-            if let Some(arr) = batters_array.as_array() {
-                println!("DEBUG: Batters array length: {}", arr.len());
-                if !arr.is_empty() {
-                    // Print the first batter object to see the structure
-                    println!("DEBUG: First batter structure: {}", serde_json::to_string_pretty(&arr[0]).unwrap_or_else(|_| "Error serializing".to_string()));
-                }
-            }
+        let data = response.json::<serde_json::Value>().await?;
+        Ok(self.parse_matchup(&data, batter_id, pitcher_id))
+    }
+
+    /// Get two teams' regular season series: every game between them, with each team's wins
+    /// from the finished ones
+    pub async fn head_to_head(&self, team_a: u32, team_b: u32, season: u32) -> Result<HeadToHead> {
+        let url = format!(
+            "{}/schedule?teamId={}&opponentId={}&season={}&sportId=1&gameType=R&hydrate=team",
+            get_mlb_api_base_url()?, team_a, team_b, season
+        );
+        let response = self.fetch(&url).await?;
+        
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch head-to-head schedule: HTTP {}", response.status()));
+        }
+        
+        let data = response.json::<serde_json::Value>().await?;
+        Ok(HeadToHead::tally(team_a, team_b, self.parse_schedule(&data)))
+    }
+
+    /// Helper method to extract the career totals from a vsPlayer stats response
+    fn parse_matchup(&self, data: &Value, batter_id: u32, pitcher_id: u32) -> Matchup {
+        // The career line is the single split of the "vsPlayerTotal" entry; per-season
+        // lines live under "vsPlayer"
+        let total_split = data["stats"].as_array()
+            .and_then(|stats| stats.iter().find(|s| s["type"]["displayName"].as_str() == Some("vsPlayerTotal")))
+            .and_then(|total| total["splits"].as_array())
+            .and_then(|splits| splits.first());
+        
+        match total_split {
+            Some(split) => {
+                let batter_name = split["batter"]["fullName"].as_str().map(String::from);
+                let name = batter_name.clone().unwrap_or_else(|| format!("Player {}", batter_id));
+                Matchup {
+                    batter_id,
+                    batter_name,
+                    pitcher_id,
+                    pitcher_name: split["pitcher"]["fullName"].as_str().map(String::from),
+                    career: Some(parse_batting_line(name, &split["stat"])),
+                }
+            }
+            None => Matchup {
+                batter_id,
+                batter_name: None,
+                pitcher_id,
+                pitcher_name: None,
+                career: None,
+            },
+        }
+    }
+
+    /// Get a compact score bug for a game from its live feed
+    pub async fn get_scorebug(&self, game_id: u64) -> Result<Scorebug> {
+        let url = format!("{}/game/{}/feed/live", get_mlb_api_base_url()?, game_id);
+        let response = self.fetch(&url).await?;
+        
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch game data: HTTP {}", response.status()));
+        }
+        
+        let data = response.json::<serde_json::Value>().await?;
+        Ok(self.parse_scorebug(&data))
+    }
+
+    /// Helper method to build a score bug from a live feed response
+    fn parse_scorebug(&self, data: &Value) -> Scorebug {
+        let game_data = &data["gameData"];
+        let linescore = &data["liveData"]["linescore"];
+        let team_label = |side: &str| {
+            let team = &game_data["teams"][side];
+            team["abbreviation"].as_str()
+                .or_else(|| team["name"].as_str())
+                .unwrap_or("???")
+                .to_string()
+        };
+        
+        let state = match game_data["status"]["abstractGameState"].as_str() {
+            Some("Final") => ScorebugState::Final,
+            Some("Live") => {
+                let inning = linescore["currentInning"].as_u64().unwrap_or(1);
+                let inning_state = linescore["inningState"].as_str().unwrap_or("Top");
+                // Outs only matter while a half-inning is being played
+                let detail = match inning_state {
+                    "Top" | "Bottom" => linescore["outs"].as_u64().map(|outs| format!("{} out", outs)),
+                    _ => None,
+                };
+                ScorebugState::Live {
+                    period: format!("{}{}", &inning_state[..1], inning),
+                    detail,
+                }
+            }
+            _ => ScorebugState::Scheduled(scorebug::start_time(game_data["datetime"]["dateTime"].as_str().unwrap_or("TBD"))),
+        };
+        
+        Scorebug {
+            away: team_label("away"),
+            away_score: linescore["teams"]["away"]["runs"].as_u64().map(|runs| runs as u32),
+            home: team_label("home"),
+            home_score: linescore["teams"]["home"]["runs"].as_u64().map(|runs| runs as u32),
+            state,
+        }
+    }
+
+    /// Get the inning, count, outs, runners and matchup of a game being played. A game that
+    /// hasn't started or has finished gives `LiveData::NotLive` with its status.
+    pub async fn get_live_game_state(&self, game_id: u32) -> Result<LiveData> {
+        let url = format!("{}/game/{}/feed/live", get_mlb_api_base_url()?, game_id);
+        let response = self.fetch(&url).await?;
+        
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch game data: HTTP {}", response.status()));
+        }
+        
+        let data = response.json::<serde_json::Value>().await?;
+        self.parse_live_data(&data)
+    }
+
+    /// Helper method to read the live situation from a live feed response, taking the count and
+    /// matchup from the current play and falling back to the linescore
+    fn parse_live_data(&self, data: &Value) -> Result<LiveData> {
+        let status = self.parse_game_from_value(data)?.status;
+        if !status.is_live() {
+            return Ok(LiveData::NotLive(status));
+        }
+        
+        let linescore = &data["liveData"]["linescore"];
+        let play = &data["liveData"]["plays"]["currentPlay"];
+        let count = |stat: &str| {
+            play["count"][stat].as_u64().or_else(|| linescore[stat].as_u64()).unwrap_or(0) as u32
+        };
+        let name = |person: &Value| person["fullName"].as_str().map(String::from);
+        let runner = |base: &str| name(&linescore["offense"][base]);
+        
+        Ok(LiveData::InProgress(LiveGameState {
+            inning: linescore["currentInning"].as_u64().unwrap_or(1) as u32,
+            half: linescore["inningState"].as_str().unwrap_or("Top").to_string(),
+            balls: count("balls"),
+            strikes: count("strikes"),
+            outs: count("outs"),
+            on_first: runner("first"),
+            on_second: runner("second"),
+            on_third: runner("third"),
+            batter: name(&play["matchup"]["batter"]).or_else(|| name(&linescore["offense"]["batter"])),
+            pitcher: name(&play["matchup"]["pitcher"]).or_else(|| name(&linescore["defense"]["pitcher"])),
+        }))
+    }
+
+    /// Get the regular season standings for every division, for both leagues and this season
+    /// unless a league (103 = AL, 104 = NL) or season is given
+    pub async fn get_standings(&self, league_id: Option<u32>, season: Option<u32>) -> Result<Vec<DivisionStandings>> {
+        let league_ids = league_id.map_or_else(|| "103,104".to_string(), |id| id.to_string());
+        let url = format!(
+            "{}/standings?leagueId={}&season={}&standingsTypes=regularSeason&hydrate=division",
+            get_mlb_api_base_url()?, league_ids, season.unwrap_or_else(|| self.clock.today().year() as u32)
+        );
+        let response = self.fetch(&url).await?;
+        
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch standings: HTTP {}", response.status()));
+        }
+        
+        let data = response.json::<serde_json::Value>().await?;
+        Ok(self.parse_standings(&data))
+    }
+
+    /// Helper method to extract each division's team records from a standings response, best
+    /// winning percentage first
+    fn parse_standings(&self, data: &Value) -> Vec<DivisionStandings> {
+        let count = |value: &Value| value.as_u64().unwrap_or(0) as u32;
+        
+        data["records"].as_array().map(|records| {
+            records.iter().map(|record| {
+                let mut teams: Vec<StandingsRecord> = record["teamRecords"].as_array().map(|team_records| {
+                    team_records.iter().map(|team_record| StandingsRecord {
+                        team: Team::new(
+                            count(&team_record["team"]["id"]),
+                            team_record["team"]["name"].as_str().unwrap_or("Unknown").to_string(),
+                        ),
+                        wins: count(&team_record["wins"]),
+                        losses: count(&team_record["losses"]),
+                        games_back: team_record["gamesBack"].as_str().unwrap_or("-").to_string(),
+                        streak: team_record["streak"]["streakCode"].as_str().and_then(|code| code.parse().ok()),
+                        runs_scored: count(&team_record["runsScored"]),
+                        runs_allowed: count(&team_record["runsAllowed"]),
+                    }).collect()
+                }).unwrap_or_default();
+                
+                // Teams that haven't played sort after any that have; ties keep the API's order
+                teams.sort_by(|a, b| {
+                    b.winning_pct().unwrap_or(-1.0).total_cmp(&a.winning_pct().unwrap_or(-1.0))
+                });
+                
+                DivisionStandings {
+                    division: record["division"]["name"].as_str().unwrap_or("Unknown Division").to_string(),
+                    teams,
+                }
+            }).collect()
+        }).unwrap_or_default()
+    }
+
+    /// Get a team's roster for a season
+    pub async fn get_team_roster(&self, team_id: u32, season: u32) -> Result<Roster> {
+        let url = format!("{}/teams/{}/roster?season={}", get_mlb_api_base_url()?, team_id, season);
+        let response = self.fetch(&url).await?;
+        
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch roster: HTTP {}", response.status()));
+        }
+        
+        let data = response.json::<serde_json::Value>().await?;
+        Ok(Roster {
+            team_id,
+            season,
+            players: self.parse_roster(&data),
+        })
+    }
+
+    /// Helper method to extract the players from a roster response
+    fn parse_roster(&self, data: &Value) -> Vec<RosterEntry> {
+        data["roster"].as_array().map(|roster| {
+            roster.iter().map(|entry| RosterEntry {
+                player_id: entry["person"]["id"].as_u64().unwrap_or(0) as u32,
+                name: entry["person"]["fullName"].as_str().unwrap_or("Unknown Player").to_string(),
+                jersey_number: entry["jerseyNumber"].as_str().filter(|number| !number.is_empty()).map(String::from),
+                position: entry["position"]["abbreviation"].as_str().unwrap_or("??").to_string(),
+                status: entry["status"]["description"].as_str().unwrap_or("Active").to_string(),
+            }).collect()
+        }).unwrap_or_default()
+    }
+
+    /// Get a team's pitching staff with each pitcher's season line
+    pub async fn get_team_pitching_staff(&self, team_id: u32, season: Option<u32>) -> Result<PitchingStaff> {
+        let season = season.unwrap_or_else(|| self.clock.today().year() as u32);
+        let url = format!(
+            "{}/teams/{}/roster?rosterType=fullSeason&season={}&hydrate=person(stats(type=season,group=pitching,season={}))",
+            get_mlb_api_base_url()?, team_id, season, season
+        );
+        let response = self.fetch(&url).await?;
+        
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch pitching staff: HTTP {}", response.status()));
+        }
+        
+        let data = response.json::<serde_json::Value>().await?;
+        Ok(PitchingStaff {
+            team_id,
+            season,
+            pitchers: self.parse_pitching_staff(&data),
+        })
+    }
+
+    /// Helper method to extract the pitchers and their season lines from a hydrated roster
+    fn parse_pitching_staff(&self, data: &Value) -> Vec<StaffPitcher> {
+        let count = |value: &Value| value.as_u64().unwrap_or(0) as u32;
+        
+        let mut pitchers: Vec<StaffPitcher> = data["roster"].as_array().map(|roster| {
+            roster.iter().filter_map(|entry| {
+                // Position players who pitched in a blowout have a line too, but aren't staff
+                if entry["position"]["type"].as_str() != Some("Pitcher") {
+                    return None;
+                }
+                let person = &entry["person"];
+                let stat = &person["stats"][0]["splits"][0]["stat"];
+                let games = count(&stat["gamesPitched"]);
+                let games_started = count(&stat["gamesStarted"]);
+                Some(StaffPitcher {
+                    name: person["fullName"].as_str().unwrap_or("Unknown Player").to_string(),
+                    role: if games > 0 && games_started * 2 >= games {
+                        PitcherRole::Starter
+                    } else {
+                        PitcherRole::Reliever
+                    },
+                    games,
+                    games_started,
+                    wins: count(&stat["wins"]),
+                    losses: count(&stat["losses"]),
+                    saves: count(&stat["saves"]),
+                    innings_pitched: stat["inningsPitched"].as_str().unwrap_or("0.0").to_string(),
+                    era: stat["era"].as_str().map(String::from),
+                })
+            }).collect()
+        }).unwrap_or_default();
+        
+        pitchers.sort_by(|a, b| {
+            a.role.cmp(&b.role).then_with(|| {
+                parse_innings_pitched(&b.innings_pitched).total_cmp(&parse_innings_pitched(&a.innings_pitched))
+            })
+        });
+        pitchers
+    }
+
+    /// Get game statistics
+    pub async fn get_game_stats(&self, game_id: u32) -> Result<GameStats> {
+        let url = format!("{}/game/{}/boxscore", get_mlb_api_base_url()?, game_id);
+        let response = self.fetch(&url).await?;
+        
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch game stats: HTTP {}", response.status()));
+        }
+        
+        let data = response.json::<serde_json::Value>().await?;
+        self.parse_game_stats(&data, game_decisions(&data))
+    }
+
+    /// Get game statistics for a finished game from its live feed, which holds the same boxscore
+    /// and is read through the response cache (and shared with `get_final_game_innings`)
+    pub async fn get_final_game_stats(&self, game_id: u32) -> Result<GameStats> {
+        let url = format!("{}/game/{}/feed/live", get_mlb_api_base_url()?, game_id);
+        let response = self.fetch_final(&url).await?;
+        
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch game stats: HTTP {}", response.status()));
+        }
+        
+        let data = response.json::<serde_json::Value>().await?;
+        let live_data = &data["liveData"];
+        self.parse_game_stats(&live_data["boxscore"], game_decisions(live_data))
+    }
+
+    /// Helper method to extract both teams' statistics from a boxscore, crediting the pitching
+    /// `decisions` (by pitcher ID)
+    fn parse_game_stats(&self, data: &Value, decisions: HashMap<u64, &'static str>) -> Result<GameStats> {
+        let teams = data.get("teams").ok_or_else(|| anyhow!("Missing teams data"))?;
+        let away = teams.get("away").ok_or_else(|| anyhow!("Missing away team data"))?;
+        let home = teams.get("home").ok_or_else(|| anyhow!("Missing home team data"))?;
+        
+        // Extract team stats
+        let away_team_stats = self.extract_team_stats(away, &decisions)?;
+        let home_team_stats = self.extract_team_stats(home, &decisions)?;
+        
+        Ok(GameStats {
+            away_team_stats,
+            home_team_stats,
+        })
+    }
+
+    /// Helper method to extract team statistics, taking pitching decisions from `decisions` (by
+    /// pitcher ID) ahead of each pitcher's own stats
+    fn extract_team_stats(&self, team_data: &Value, decisions: &HashMap<u64, &str>) -> Result<TeamStats> {
+        // Get team info
+        let team_name = team_data.get("team").and_then(|t| t.get("name"))
+            .and_then(|n| n.as_str())
+            .ok_or_else(|| anyhow!("Missing team name"))?
+            .to_string();
+            
+        // Extract batting stats. A missing node means the totals weren't published, which is
+        // different from a team that genuinely went 0-for.
+        let batting_data = &team_data["teamStats"]["batting"];
+        let batting = batting_data.is_object().then(|| BattingStats {
+            runs: batting_data["runs"].as_u64().unwrap_or(0) as u32,
+            hits: batting_data["hits"].as_u64().unwrap_or(0) as u32,
+            home_runs: batting_data["homeRuns"].as_u64().unwrap_or(0) as u32,
+            rbi: batting_data["rbi"].as_u64().unwrap_or(0) as u32,
+            stolen_bases: batting_data["stolenBases"].as_u64().unwrap_or(0) as u32,
+            avg: valid_average(&batting_data["avg"]).unwrap_or(".000").to_string(),
+            obp: valid_average(&batting_data["obp"]).unwrap_or(".000").to_string(),
+            slg: valid_rate_stat(&batting_data["slg"]).unwrap_or(".000").to_string(),
+            ops: valid_rate_stat(&batting_data["ops"]).unwrap_or(".000").to_string(),
+        });
+        
+        // Extract pitching stats
+        let pitching_data = &team_data["teamStats"]["pitching"];
+        let pitching = pitching_data.is_object().then(|| {
+            let innings_pitched = pitching_data["inningsPitched"].as_str().unwrap_or("0").to_string();
+            let hits_allowed = pitching_data["hits"].as_u64().unwrap_or(0) as u32;
+            let walks = pitching_data["baseOnBalls"].as_u64().unwrap_or(0) as u32;
+            PitchingStats {
+                whip: format_whip(walks, hits_allowed, &innings_pitched),
+                innings_pitched,
+                hits_allowed,
+                runs_allowed: pitching_data["runs"].as_u64().unwrap_or(0) as u32,
+                earned_runs: pitching_data["earnedRuns"].as_u64().unwrap_or(0) as u32,
+                walks,
+                strikeouts: pitching_data["strikeOuts"].as_u64().unwrap_or(0) as u32,
+                home_runs_allowed: pitching_data["homeRuns"].as_u64().unwrap_or(0) as u32,
+                era: pitching_data["era"].as_str().unwrap_or("0.00").to_string(),
+            }
+        });
+        
+        // Debug print to check if batters and pitchers data exists in the response
+        debug!("Batters data exists: {}", team_data.get("batters").is_some());
+        if let Some(batters_array) = team_data.get("batters") {
+            debug!("Batters array is array: {}", batters_array.is_array());
+            if let Some(arr) = batters_array.as_array() {
+                debug!("Batters array length: {}", arr.len());
+                if !arr.is_empty() {
+                    // Print the first batter object to see the structure
+                    debug!("First batter structure: {}", serde_json::to_string_pretty(&arr[0]).unwrap_or_else(|_| "Error serializing".to_string()));
+                }
+            }
         }
         
         // Extract batter stats
@@ -1249,40 +2621,32 @@ impl MlbApi {
                             
                             // Check if stats are available for this player
                             if let Some(stats_obj) = player_obj.get("stats").and_then(|s| s.get("batting")) {
-                                batter_stats.push(PlayerBattingStats {
-                                    name,
-                                    hits: stats_obj.get("hits").and_then(|h| h.as_u64()).unwrap_or(0) as u32,
-                                    at_bats: stats_obj.get("atBats").and_then(|ab| ab.as_u64()).unwrap_or(0) as u32,
-                                    home_runs: stats_obj.get("homeRuns").and_then(|hr| hr.as_u64()).unwrap_or(0) as u32,
-                                    rbi: stats_obj.get("rbi").and_then(|rbi| rbi.as_u64()).unwrap_or(0) as u32,
-                                    runs: stats_obj.get("runs").and_then(|r| r.as_u64()).unwrap_or(0) as u32,
-                                    doubles: stats_obj.get("doubles").and_then(|d| d.as_u64()).unwrap_or(0) as u32,
-                                    triples: stats_obj.get("triples").and_then(|t| t.as_u64()).unwrap_or(0) as u32,
-                                    stolen_bases: stats_obj.get("stolenBases").and_then(|sb| sb.as_u64()).unwrap_or(0) as u32,
-                                    walks: stats_obj.get("baseOnBalls").and_then(|bb| bb.as_u64()).unwrap_or(0) as u32,
-                                    strikeouts: stats_obj.get("strikeOuts").and_then(|so| so.as_u64()).unwrap_or(0) as u32,
-                                    avg: stats_obj.get("avg").and_then(|avg| avg.as_str()).map(String::from),
-                                    obp: stats_obj.get("obp").and_then(|obp| obp.as_str()).map(String::from),
-                                    slg: stats_obj.get("slg").and_then(|slg| slg.as_str()).map(String::from),
-                                });
+                                let mut line = parse_batting_line(name, stats_obj);
+                                line.position = player_obj["position"]["abbreviation"].as_str().map(String::from);
+                                line.batting_order = player_obj["battingOrder"].as_str().and_then(|order| order.parse().ok());
+                                batter_stats.push(line);
                             }
                         }
                     }
                 }
             }
+            // Lineup order, with any batters the boxscore doesn't place in it after, by name
+            batter_stats.sort_by(|a, b| {
+                (a.batting_order.is_none(), a.batting_order, &a.name).cmp(&(b.batting_order.is_none(), b.batting_order, &b.name))
+            });
             batter_stats
         } else {
-            println!("DEBUG: No batters array found in team data");
+            debug!("No batters array found in team data");
             Vec::new()
         };
         
         // Debug print pitcher data
-        println!("DEBUG: Pitchers data exists: {}", team_data.get("pitchers").is_some());
+        debug!("Pitchers data exists: {}", team_data.get("pitchers").is_some());
         if let Some(pitchers_array) = team_data.get("pitchers") {
             if let Some(arr) = pitchers_array.as_array() {
                 if !arr.is_empty() {
                     // Print the first pitcher object to see the structure
-                    println!("DEBUG: First pitcher structure: {}", serde_json::to_string_pretty(&arr[0]).unwrap_or_else(|_| "Error serializing".to_string()));
+                    debug!("First pitcher structure: {}", serde_json::to_string_pretty(&arr[0]).unwrap_or_else(|_| "Error serializing".to_string()));
                 }
             }
         }
@@ -1313,6 +2677,10 @@ impl MlbApi {
                                     walks: stats_obj.get("baseOnBalls").and_then(|bb| bb.as_u64()).unwrap_or(0) as u32,
                                     home_runs_allowed: stats_obj.get("homeRuns").and_then(|hr| hr.as_u64()).unwrap_or(0) as u32,
                                     era: stats_obj.get("era").and_then(|era| era.as_str()).map(String::from),
+                                    whip: stats_obj.get("whip").and_then(|whip| whip.as_str()).map(String::from),
+                                    decision: decisions.get(&pitcher_id)
+                                        .map(|code| code.to_string())
+                                        .or_else(|| parse_pitching_decision(stats_obj)),
                                 });
                             }
                         }
@@ -1321,12 +2689,12 @@ impl MlbApi {
             }
             pitcher_stats
         } else {
-            println!("DEBUG: No pitchers array found in team data");
+            debug!("No pitchers array found in team data");
             Vec::new()
         };
         
         // Print a debug message to show how many player stats we found
-        println!("DEBUG: Found {} batters and {} pitchers", batters.len(), pitchers.len());
+        debug!("Found {} batters and {} pitchers", batters.len(), pitchers.len());
         
         Ok(TeamStats {
             team_name,
@@ -1365,6 +2733,7 @@ impl Default for PitchingStats {
             strikeouts: 0,
             home_runs_allowed: 0,
             era: "0.00".to_string(),
+            whip: "-.--".to_string(),
         }
     }
 }
@@ -1377,33 +2746,140 @@ impl fmt::Display for GameStats {
     }
 }
 
+// Shown in place of team totals the boxscore didn't include
+const TOTALS_UNAVAILABLE: &str = "team totals unavailable";
+
+// Box score table columns, shared by the text and markdown tables
+const BATTER_COLUMNS: [&str; 9] = ["NAME", "AB", "H", "R", "HR", "RBI", "AVG", "OBP", "SLG"];
+const PITCHER_COLUMNS: [&str; 9] = ["NAME", "IP", "H", "R", "ER", "BB", "K", "ERA", "WHIP"];
+
+// Usual widths of the text tables' stat columns, which widen for longer values
+const BATTER_WIDTHS: [usize; 8] = [7, 3, 3, 3, 3, 5, 5, 5];
+const PITCHER_WIDTHS: [usize; 8] = [5, 3, 3, 3, 3, 3, 5, 5];
+
+impl TeamStats {
+    /// Render the team's box score with player names padded or truncated to `name_width`
+    pub fn table(&self, name_width: usize) -> String {
+        TeamTable(self, name_width).to_string()
+    }
+
+    // The team's batters and pitchers as markdown tables under a heading
+    fn to_markdown(&self) -> String {
+        let mut markdown = format!("### {}\n", markdown_cell(&self.team_name));
+        if !self.batters.is_empty() {
+            let mut rows: Vec<Vec<String>> = self.batters.iter().map(PlayerBattingStats::markdown_cells).collect();
+            rows.push(self.batting_totals().markdown_cells());
+            markdown.push_str(&format!("\n{}", markdown_table(&BATTER_COLUMNS, &rows)));
+        }
+        if !self.pitchers.is_empty() {
+            let mut rows: Vec<Vec<String>> = self.pitchers.iter().map(PlayerPitchingStats::markdown_cells).collect();
+            rows.push(self.pitching_totals().markdown_cells());
+            markdown.push_str(&format!("\n{}", markdown_table(&PITCHER_COLUMNS, &rows)));
+        }
+        markdown
+    }
+
+    // A "TEAM" row summing the batters' lines; leaving the average unset recomputes it from the
+    // summed hits and at-bats
+    /// The team's top `n` batters by `metric`, best first. Batters with none of the stat are left
+    /// out, and ties keep the box score's order.
+    pub fn leaders(&self, metric: BattingMetric, n: usize) -> Vec<&PlayerBattingStats> {
+        let mut batters: Vec<&PlayerBattingStats> = self.batters
+            .iter()
+            .filter(|batter| batter.sort_key(metric) > 0.0)
+            .collect();
+        batters.sort_by(|a, b| b.sort_key(metric).total_cmp(&a.sort_key(metric)));
+        batters.truncate(n);
+        batters
+    }
+    
+    fn batting_totals(&self) -> PlayerBattingStats {
+        let sum = |stat: fn(&PlayerBattingStats) -> u32| self.batters.iter().map(stat).sum();
+        PlayerBattingStats {
+            name: "TEAM".to_string(),
+            hits: sum(|b| b.hits),
+            at_bats: sum(|b| b.at_bats),
+            home_runs: sum(|b| b.home_runs),
+            rbi: sum(|b| b.rbi),
+            runs: sum(|b| b.runs),
+            doubles: sum(|b| b.doubles),
+            triples: sum(|b| b.triples),
+            stolen_bases: sum(|b| b.stolen_bases),
+            walks: sum(|b| b.walks),
+            strikeouts: sum(|b| b.strikeouts),
+            avg: None,
+            obp: None,
+            slg: None,
+            position: None,
+            batting_order: None,
+        }
+    }
+
+    // A "TEAM" row summing the pitchers' lines, with ERA and WHIP recomputed from the totals
+    fn pitching_totals(&self) -> PlayerPitchingStats {
+        let sum = |stat: fn(&PlayerPitchingStats) -> u32| self.pitchers.iter().map(stat).sum();
+        let innings: Vec<&str> = self.pitchers.iter().map(|p| p.innings_pitched.as_str()).collect();
+        PlayerPitchingStats {
+            name: "TEAM".to_string(),
+            innings_pitched: InningsPitched::sum(&innings).to_string(),
+            strikeouts: sum(|p| p.strikeouts),
+            earned_runs: sum(|p| p.earned_runs),
+            hits_allowed: sum(|p| p.hits_allowed),
+            runs_allowed: sum(|p| p.runs_allowed),
+            walks: sum(|p| p.walks),
+            home_runs_allowed: sum(|p| p.home_runs_allowed),
+            era: None,
+            whip: None,
+            decision: None,
+        }
+    }
+}
+
 impl fmt::Display for TeamStats {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "{}", self.team_name)?;
-        writeln!(f, "  BATTING: {}", self.batting)?;
-        writeln!(f, "  PITCHING: {}", self.pitching)?;
+        write!(f, "{}", TeamTable(self, get_config().name_width))
+    }
+}
+
+// A team's box score at a given name column width
+struct TeamTable<'a>(&'a TeamStats, usize);
+
+impl fmt::Display for TeamTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let TeamTable(stats, name_width) = *self;
+        writeln!(f, "{}", stats.team_name)?;
+        match &stats.batting {
+            Some(batting) => writeln!(f, "  BATTING: {}", batting)?,
+            None => writeln!(f, "  BATTING: {}", TOTALS_UNAVAILABLE)?,
+        }
+        match &stats.pitching {
+            Some(pitching) => writeln!(f, "  PITCHING: {}", pitching)?,
+            None => writeln!(f, "  PITCHING: {}", TOTALS_UNAVAILABLE)?,
+        }
         
-        if !self.batters.is_empty() {
+        if !stats.batters.is_empty() {
             writeln!(f, "  BATTERS:")?;
-            // Print header
-            writeln!(f, "    {:<25} {:<7} {:<3} {:<3} {:<3} {:<3} {:<5} {:<5} {:<5}", 
-                "NAME", "AB", "H", "R", "HR", "RBI", "AVG", "OBP", "SLG")?;
-            writeln!(f, "    {}", "-".repeat(70))?;
-            
-            for batter in &self.batters {
-                writeln!(f, "    {}", batter)?;
+            let mut table = Table::new(&BATTER_COLUMNS);
+            table.min_widths(&column_widths(name_width, &BATTER_WIDTHS));
+            for batter in &stats.batters {
+                table.row(batter.cells(name_width));
+            }
+            table.rule().row(stats.batting_totals().cells(name_width));
+            for line in table.lines() {
+                writeln!(f, "    {}", line)?;
             }
         }
         
-        if !self.pitchers.is_empty() {
+        if !stats.pitchers.is_empty() {
             writeln!(f, "  PITCHERS:")?;
-            // Print header
-            writeln!(f, "    {:<25} {:<5} {:<3} {:<3} {:<3} {:<3} {:<3} {:<5}", 
-                "NAME", "IP", "H", "R", "ER", "BB", "K", "ERA")?;
-            writeln!(f, "    {}", "-".repeat(60))?;
-            
-            for pitcher in &self.pitchers {
-                writeln!(f, "    {}", pitcher)?;
+            let mut table = Table::new(&PITCHER_COLUMNS);
+            table.min_widths(&column_widths(name_width, &PITCHER_WIDTHS));
+            for pitcher in &stats.pitchers {
+                table.row(pitcher.cells(name_width));
+            }
+            table.rule().row(stats.pitching_totals().cells(name_width));
+            for line in table.lines() {
+                writeln!(f, "    {}", line)?;
             }
         }
         
@@ -1411,6 +2887,11 @@ impl fmt::Display for TeamStats {
     }
 }
 
+// The name column's width followed by the stat columns' usual widths
+fn column_widths(name_width: usize, stat_widths: &[usize]) -> Vec<usize> {
+    std::iter::once(name_width).chain(stat_widths.iter().copied()).collect()
+}
+
 impl fmt::Display for BattingStats {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -1426,15 +2907,57 @@ impl fmt::Display for PitchingStats {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "IP: {}, H: {}, R: {}, ER: {}, BB: {}, SO: {}, HR: {}, ERA: {}",
+            "IP: {}, H: {}, R: {}, ER: {}, BB: {}, SO: {}, HR: {}, ERA: {}, WHIP: {}",
             self.innings_pitched, self.hits_allowed, self.runs_allowed, self.earned_runs,
-            self.walks, self.strikeouts, self.home_runs_allowed, self.era
+            self.walks, self.strikeouts, self.home_runs_allowed, self.era, self.whip
         )
     }
 }
 
 impl fmt::Display for PlayerBattingStats {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.row(get_config().name_width))
+    }
+}
+
+impl PlayerBattingStats {
+    /// The player's value of `metric`, for ranking. AVG is calculated from the game's hits and
+    /// at-bats when the box score doesn't give it; a missing OBP or SLG ("---") counts as zero.
+    pub fn sort_key(&self, metric: BattingMetric) -> f64 {
+        let rate = |value: &Option<String>| value.as_deref().and_then(parse_decimal_stat).map(f64::from);
+        match metric {
+            BattingMetric::Avg => rate(&self.avg).unwrap_or_else(|| {
+                if self.at_bats > 0 { self.hits as f64 / self.at_bats as f64 } else { 0.0 }
+            }),
+            BattingMetric::HomeRuns => self.home_runs as f64,
+            BattingMetric::Rbi => self.rbi as f64,
+            BattingMetric::Ops => rate(&self.obp).unwrap_or(0.0) + rate(&self.slg).unwrap_or(0.0),
+            BattingMetric::StolenBases => self.stolen_bases as f64,
+        }
+    }
+    
+    // The player's value of `metric` as shown, e.g. ".287" or "2"
+    fn metric_value(&self, metric: BattingMetric) -> String {
+        match metric {
+            BattingMetric::Avg | BattingMetric::Ops => RateStat(self.sort_key(metric) as f32).to_string(),
+            _ => (self.sort_key(metric) as u32).to_string(),
+        }
+    }
+    
+    /// Render the box score row with the name padded or truncated to `name_width`
+    pub fn row(&self, name_width: usize) -> String {
+        let mut table = Table::headless();
+        table.min_widths(&column_widths(name_width, &BATTER_WIDTHS)).row(self.cells(name_width));
+        table.lines().remove(0)
+    }
+    
+    // The text table row, with the name truncated to `name_width`
+    fn cells(&self, name_width: usize) -> Vec<String> {
+        std::iter::once(truncate_name(&self.name, name_width)).chain(self.stat_cells()).collect()
+    }
+    
+    // The values under each of BATTER_COLUMNS after the name
+    fn stat_cells(&self) -> [String; 8] {
         // Use provided AVG or calculate it
         let avg = if let Some(ref avg) = self.avg {
             avg.clone()
@@ -1451,24 +2974,67 @@ impl fmt::Display for PlayerBattingStats {
         // Use provided SLG or use placeholder
         let slg = self.slg.clone().unwrap_or_else(|| "---".to_string());
         
-        write!(
-            f,
-            "{:<25} {:<7} {:<3} {:<3} {:<3} {:<3} {:<5} {:<5} {:<5}",
-            truncate_name(&self.name, 25),
-            self.at_bats,
-            self.hits,
-            self.runs,
-            self.home_runs,
-            self.rbi,
+        [
+            self.at_bats.to_string(),
+            self.hits.to_string(),
+            self.runs.to_string(),
+            self.home_runs.to_string(),
+            self.rbi.to_string(),
             avg,
             obp,
-            slg
-        )
+            slg,
+        ]
+    }
+    
+    // The row as markdown table cells, with the full name
+    fn markdown_cells(&self) -> Vec<String> {
+        std::iter::once(self.name.clone()).chain(self.stat_cells()).collect()
+    }
+}
+
+impl fmt::Display for GameLeaders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "By {}:", self.metric)?;
+        for team in &self.teams {
+            let leaders: Vec<String> = team.leaders
+                .iter()
+                .map(|(name, value)| format!("{} {}", name, value))
+                .collect();
+            writeln!(f, "  {}: {}", team.team_name, if leaders.is_empty() { "none".to_string() } else { leaders.join(", ") })?;
+        }
+        Ok(())
     }
 }
 
 impl fmt::Display for PlayerPitchingStats {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.row(get_config().name_width))
+    }
+}
+
+impl PlayerPitchingStats {
+    /// Render the box score row with the name (and decision) padded or truncated to `name_width`
+    pub fn row(&self, name_width: usize) -> String {
+        let mut table = Table::headless();
+        table.min_widths(&column_widths(name_width, &PITCHER_WIDTHS)).row(self.cells(name_width));
+        table.lines().remove(0)
+    }
+    
+    // The text table row, with the name truncated to fit `name_width` alongside the decision
+    fn cells(&self, name_width: usize) -> Vec<String> {
+        // Keep the decision visible by truncating only the name part
+        let name = match self.decision {
+            Some(ref decision) => {
+                let suffix = format!(" ({})", decision);
+                format!("{}{}", truncate_name(&self.name, name_width.saturating_sub(suffix.len())), suffix)
+            }
+            None => truncate_name(&self.name, name_width),
+        };
+        std::iter::once(name).chain(self.stat_cells()).collect()
+    }
+    
+    // The values under each of PITCHER_COLUMNS after the name
+    fn stat_cells(&self) -> [String; 8] {
         // Use provided ERA or calculate it
         let era = if let Some(ref era) = self.era {
             era.clone()
@@ -1481,110 +3047,720 @@ impl fmt::Display for PlayerPitchingStats {
             }
         };
         
-        write!(
-            f,
-            "{:<25} {:<5} {:<3} {:<3} {:<3} {:<3} {:<3} {:<5}",
-            truncate_name(&self.name, 25),
-            self.innings_pitched,
-            self.hits_allowed,
-            self.runs_allowed,
-            self.earned_runs,
-            self.walks,
-            self.strikeouts,
-            era
-        )
+        // Use provided WHIP or calculate it
+        let whip = self.whip.clone()
+            .unwrap_or_else(|| format_whip(self.walks, self.hits_allowed, &self.innings_pitched));
+        
+        [
+            self.innings_pitched.clone(),
+            self.hits_allowed.to_string(),
+            self.runs_allowed.to_string(),
+            self.earned_runs.to_string(),
+            self.walks.to_string(),
+            self.strikeouts.to_string(),
+            era,
+            whip,
+        ]
+    }
+    
+    // The row as markdown table cells, with the full name and decision
+    fn markdown_cells(&self) -> Vec<String> {
+        let name = match self.decision {
+            Some(ref decision) => format!("{} ({})", self.name, decision),
+            None => self.name.clone(),
+        };
+        std::iter::once(name).chain(self.stat_cells()).collect()
     }
 }
 
-// Helper function to truncate player names to fit in display
-fn truncate_name(name: &str, max_len: usize) -> String {
-    if name.len() <= max_len {
-        name.to_string()
-    } else {
-        format!("{}...", &name[0..max_len-3])
-    }
+// A team's probable starter, present on schedule entries hydrated with probablePitcher
+fn probable_pitcher(team: &Value) -> Option<String> {
+    team["probablePitcher"]["fullName"].as_str().map(String::from)
 }
 
-// Helper function to parse innings pitched string to float
-fn parse_innings_pitched(ip: &str) -> f32 {
-    let parts: Vec<&str> = ip.split('.').collect();
-    if parts.len() == 2 {
-        let innings = parts[0].parse::<f32>().unwrap_or(0.0);
-        let fraction = parts[1].parse::<f32>().unwrap_or(0.0) / 3.0;
-        innings + fraction
-    } else {
-        ip.parse::<f32>().unwrap_or(0.0)
-    }
+// The schedule marks doubleheaders "Y" (traditional) or "S" (split admission), and other games "N"
+fn is_doubleheader(value: &Value) -> bool {
+    matches!(value.as_str(), Some("Y") | Some("S"))
 }
 
-impl fmt::Display for GameInnings {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "Game: {} vs {}", self.home_team.name, self.away_team.name)?;
-        writeln!(f, "Date: {}", self.game_date.split('T').next().unwrap_or(&self.game_date))?;
-        writeln!(f, "Status: {}", self.status)?;
-        
-        // Create a table header for innings
-        write!(f, "     ")?;
-        for i in 0..self.innings.len() {
-            write!(f, " {:2}", i + 1)?;
-        }
-        writeln!(f, "  | R")?;
-        
-        // Add a separator line
-        writeln!(f, "-----{}--+--", "-".repeat(self.innings.len() * 3))?;
-        
-        // Away team line
-        write!(f, "{:<3} |", "Awa")?;
-        for inning in &self.innings {
-            if let Some(runs) = inning.away {
-                write!(f, " {:2}", runs)?;
-            } else {
-                write!(f, "  -")?;
-            }
-        }
-        writeln!(f, "  | {}", self.away_runs.unwrap_or(0))?;
-        
-        // Home team line
-        write!(f, "{:<3} |", "Hom")?;
-        for inning in &self.innings {
-            if let Some(runs) = inning.home {
-                write!(f, " {:2}", runs)?;
-            } else {
-                write!(f, "  -")?;
-            }
-        }
-        writeln!(f, "  | {}", self.home_runs.unwrap_or(0))?;
-        
-        Ok(())
+// The half and inning of a live game from its linescore, e.g. "Top 5th"
+fn current_inning(status: &GameState, linescore: &Value) -> Option<String> {
+    if !status.is_live() {
+        return None;
     }
+    let half = linescore["inningState"].as_str()?;
+    let inning = linescore["currentInningOrdinal"].as_str()?;
+    Some(format!("{} {}", half, inning))
 }
 
-// Create a default instance for convenience using OnceLock instead of static mut
-static MLB_API_INSTANCE: OnceLock<MlbApi> = OnceLock::new();
+// An inning number as an ordinal, e.g. "7th"
+fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
 
-/// Initialize the MLB API instance if it hasn't been initialized yet
-fn get_mlb_api() -> &'static MlbApi {
-    MLB_API_INSTANCE.get_or_init(|| MlbApi::new())
+// A team's runs, hits or errors total from a linescore's "teams" entry
+fn total(team: &Value, stat: &str) -> Option<u32> {
+    team[stat].as_u64().map(|n| n as u32)
 }
 
-/// Get player information by ID
+// WHIP to two decimals, or a placeholder before any outs have been recorded
+fn format_whip(walks: u32, hits: u32, innings_pitched: &str) -> String {
+    calculate_whip(walks, hits, innings_pitched)
+        .map(|whip| format!("{:.2}", whip))
+        .unwrap_or_else(|| "-.--".to_string())
+}
+
+// Whether a live feed response describes a finished game
+fn feed_is_final(data: &Value) -> bool {
+    data["gameData"]["status"]["abstractGameState"] == "Final"
+}
+
+// Helper function to read the winning, losing and saving pitchers from a `decisions` object (at
+// the top level of a boxscore, or under a live feed's `liveData`), keyed by pitcher ID
+fn game_decisions(data: &Value) -> HashMap<u64, &'static str> {
+    [("winner", "W"), ("loser", "L"), ("save", "SV")]
+        .into_iter()
+        .filter_map(|(key, code)| Some((data["decisions"][key]["id"].as_u64()?, code)))
+        .collect()
+}
+
+// Read a team from the teams endpoint, taking its venue's hydrated location and timezone
+fn parse_team(team_data: &Value) -> Result<Team> {
+    let mut team: Team = serde_json::from_value(team_data.to_owned())?;
+    if team.venue.is_some() {
+        team.venue = Some(Venue::from_json(&team_data["venue"]));
+    }
+    Ok(team)
+}
+
+// Helper function to read a pitcher's decision from a boxscore pitching stats object.
+// The note looks like "(W, 5-2)" or "(S, 12)"; the counting stats are a fallback.
+fn parse_pitching_decision(stats_obj: &Value) -> Option<String> {
+    let from_note = stats_obj.get("note")
+        .and_then(|note| note.as_str())
+        .and_then(|note| note.trim_matches(|c| c == '(' || c == ')').split(',').next())
+        .and_then(|code| match code.trim() {
+            "W" => Some("W"),
+            "L" => Some("L"),
+            "S" | "SV" => Some("SV"),
+            "H" | "HLD" => Some("HLD"),
+            "BS" => Some("BS"),
+            _ => None,
+        });
+    
+    let from_counts = || {
+        let count = |key: &str| stats_obj.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+        [("wins", "W"), ("losses", "L"), ("saves", "SV"), ("holds", "HLD"), ("blownSaves", "BS")]
+            .into_iter()
+            .find(|(key, _)| count(key) > 0)
+            .map(|(_, code)| code)
+    };
+    
+    from_note.or_else(from_counts).map(String::from)
+}
+
+// Helper function to build a batting line from a stats object in the API's hitting format
+// Choose the requested game out of a team's games on one day. A single game counts as game 1.
+fn pick_game(games: Vec<Game>, game_number: Option<u32>) -> Result<Game> {
+    match (games.len(), game_number) {
+        (0, _) => Err(anyhow!("no game scheduled")),
+        (1, None) => Ok(games.into_iter().next().unwrap()),
+        (count, None) => Err(anyhow!("{} games scheduled; choose one with --game-number", count)),
+        (_, Some(number)) => games
+            .into_iter()
+            .find(|game| game.game_number.unwrap_or(1) == number)
+            .ok_or_else(|| anyhow!("no game {} scheduled", number)),
+    }
+}
+
+// An average or on-base percentage from the API, if it's a real one rather than a placeholder
+// like ".---"
+fn valid_average(value: &Value) -> Option<&str> {
+    value.as_str().filter(|avg| avg.parse::<Average>().is_ok())
+}
+
+// A slugging percentage or OPS from the API, which unlike an average can be above 1.000
+fn valid_rate_stat(value: &Value) -> Option<&str> {
+    value.as_str().filter(|rate| rate.parse::<RateStat>().is_ok())
+}
+
+fn parse_batting_line(name: String, stats_obj: &Value) -> PlayerBattingStats {
+    PlayerBattingStats {
+        name,
+        hits: stats_obj.get("hits").and_then(|h| h.as_u64()).unwrap_or(0) as u32,
+        at_bats: stats_obj.get("atBats").and_then(|ab| ab.as_u64()).unwrap_or(0) as u32,
+        home_runs: stats_obj.get("homeRuns").and_then(|hr| hr.as_u64()).unwrap_or(0) as u32,
+        rbi: stats_obj.get("rbi").and_then(|rbi| rbi.as_u64()).unwrap_or(0) as u32,
+        runs: stats_obj.get("runs").and_then(|r| r.as_u64()).unwrap_or(0) as u32,
+        doubles: stats_obj.get("doubles").and_then(|d| d.as_u64()).unwrap_or(0) as u32,
+        triples: stats_obj.get("triples").and_then(|t| t.as_u64()).unwrap_or(0) as u32,
+        stolen_bases: stats_obj.get("stolenBases").and_then(|sb| sb.as_u64()).unwrap_or(0) as u32,
+        walks: stats_obj.get("baseOnBalls").and_then(|bb| bb.as_u64()).unwrap_or(0) as u32,
+        strikeouts: stats_obj.get("strikeOuts").and_then(|so| so.as_u64()).unwrap_or(0) as u32,
+        avg: stats_obj.get("avg").and_then(valid_average).map(String::from),
+        obp: stats_obj.get("obp").and_then(valid_average).map(String::from),
+        slg: stats_obj.get("slg").and_then(valid_rate_stat).map(String::from),
+        position: None,
+        batting_order: None,
+    }
+}
+
+impl fmt::Display for Matchup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let batter = self.batter_name.clone().unwrap_or_else(|| format!("Batter {}", self.batter_id));
+        let pitcher = self.pitcher_name.clone().unwrap_or_else(|| format!("pitcher {}", self.pitcher_id));
+        
+        match self.career {
+            Some(ref line) => {
+                writeln!(f, "{} vs. {} (career):", batter, pitcher)?;
+                writeln!(f, "  {}-for-{}, {} 2B, {} 3B, {} HR, {} RBI, {} BB, {} K",
+                    line.hits, line.at_bats, line.doubles, line.triples, line.home_runs,
+                    line.rbi, line.walks, line.strikeouts)?;
+                writeln!(f, "  AVG/OBP/SLG: {}/{}/{}",
+                    line.avg.as_deref().unwrap_or("---"),
+                    line.obp.as_deref().unwrap_or("---"),
+                    line.slg.as_deref().unwrap_or("---"))
+            }
+            None => writeln!(f, "{} has never faced {}.", batter, pitcher),
+        }
+    }
+}
+
+impl fmt::Display for HeadToHead {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (a, b) = (self.team_label(self.team_a), self.team_label(self.team_b));
+        let summary = match self.a_wins.cmp(&self.b_wins) {
+            std::cmp::Ordering::Greater => format!("{} lead series {}-{}", a, self.a_wins, self.b_wins),
+            std::cmp::Ordering::Less => format!("{} lead series {}-{}", b, self.b_wins, self.a_wins),
+            std::cmp::Ordering::Equal => format!("Series tied {}-{}", self.a_wins, self.b_wins),
+        };
+        
+        // Compact form: {:#} is just the series record
+        if f.alternate() {
+            return write!(f, "{}", summary);
+        }
+        
+        writeln!(f, "{}", summary)?;
+        if self.games.is_empty() {
+            return writeln!(f, "No games scheduled between {} and {}.", a, b);
+        }
+        for game in &self.games {
+            writeln!(f, "  {:#}", game)?;
+        }
+        Ok(())
+    }
+}
+
+impl league::League for MlbApi {
+    type Game = Game;
+    
+    const NAME: &'static str = "MLB";
+    const PENDING_MESSAGE: &'static str = "Detailed information not available for games that haven't been completed.";
+    
+    fn game_id(game: &Game) -> u32 {
+        game.game_pk
+    }
+    
+    fn game_state(game: &Game) -> GameState {
+        game.status.clone()
+    }
+    
+    fn summary_line(game: &Game) -> String {
+        game.summary_line()
+    }
+    
+    fn team_ids(game: &Game) -> (u32, u32) {
+        (game.teams.away.team.id, game.teams.home.team.id)
+    }
+    
+    fn favorite_teams(config: &crate::config::Config) -> &[u32] {
+        &config.favorite_mlb_teams
+    }
+    
+    async fn games_by_date(&self, date: NaiveDate) -> Result<Vec<Game>> {
+        self.get_games_by_date(date).await
+    }
+    
+    async fn todays_games(&self) -> Result<Vec<Game>> {
+        self.get_todays_games().await
+    }
+    
+    async fn yesterdays_games(&self) -> Result<Vec<Game>> {
+        self.get_yesterdays_games().await
+    }
+    
+    async fn team_schedule(&self, team_id: u32, start_date: Option<String>, end_date: Option<String>) -> Result<Vec<Game>> {
+        self.get_team_schedule(team_id, start_date, end_date).await
+    }
+    
+    async fn team_abbreviations(&self) -> Result<Vec<(u32, String)>> {
+        Ok(self.get_all_teams().await?
+            .into_iter()
+            .filter_map(|team| team.abbreviation.map(|abbreviation| (team.id, abbreviation)))
+            .collect())
+    }
+    
+    /// The inning-by-inning breakdown, plus the box score with `detailed_stats`
+    async fn game_detail(&self, game: &Game, options: DetailOptions) -> Vec<DetailSection> {
+        let mut sections = vec![DetailSection {
+            heading: "Inning-by-Inning Breakdown",
+            what: "innings data",
+            body: self.get_final_game_innings(game.game_pk).await.map(|innings| render(&innings, options.compact)),
+        }];
+        
+        if options.detailed_stats {
+            sections.push(DetailSection {
+                heading: "Detailed Statistics",
+                what: "detailed game stats",
+                body: self.get_final_game_stats(game.game_pk).await.map(|stats| stats.to_string()),
+            });
+        }
+        
+        sections
+    }
+}
+
+impl CalendarEvent for Game {
+    fn uid(&self) -> String {
+        format!("mlb-{}@plaintext-sports", self.game_pk)
+    }
+
+    fn start(&self) -> String {
+        self.game_date.clone()
+    }
+
+    fn summary(&self) -> String {
+        format!("{} @ {}", self.teams.away.team.display_name(), self.teams.home.team.display_name())
+    }
+
+    fn location(&self) -> Option<String> {
+        self.venue.as_ref().map(|venue| venue.name.clone())
+    }
+}
+
+impl fmt::Display for LiveGameState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let batting = matches!(self.half.as_str(), "Top" | "Bottom");
+        if batting {
+            let outs = if self.outs == 1 { "out" } else { "outs" };
+            writeln!(f, "{} {}, {} {}", self.half, ordinal(self.inning), self.outs, outs)?;
+            writeln!(f, "Count: {}-{}", self.balls, self.strikes)?;
+        } else {
+            writeln!(f, "{} {}", self.half, ordinal(self.inning))?;
+        }
+        
+        let runners: Vec<String> = [("1st", &self.on_first), ("2nd", &self.on_second), ("3rd", &self.on_third)]
+            .into_iter()
+            .filter_map(|(base, runner)| runner.as_ref().map(|name| format!("{} ({})", base, name)))
+            .collect();
+        if runners.is_empty() {
+            writeln!(f, "Runners: bases empty")?;
+        } else {
+            writeln!(f, "Runners: {}", runners.join(", "))?;
+        }
+        
+        if batting {
+            writeln!(f, "At bat: {}", self.batter.as_deref().unwrap_or("-"))?;
+            writeln!(f, "Pitching: {}", self.pitcher.as_deref().unwrap_or("-"))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for LiveData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LiveData::InProgress(state) => write!(f, "{}", state),
+            LiveData::NotLive(status) => writeln!(f, "No live data: the game is {}", status),
+        }
+    }
+}
+
+impl fmt::Display for ScoringPlay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let half = if self.half == "bottom" { "B" } else { "T" };
+        write!(f, "{}{}: {}, {} {} - {} {}",
+            half, self.inning, self.description.trim_end_matches('.'),
+            self.away_team, self.away_score, self.home_team, self.home_score)
+    }
+}
+
+impl fmt::Display for DivisionStandings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.division)?;
+        writeln!(f, "  {:<22} {:>7} {:>5} {:>5} {:>4} {:>4} {:>4} {:>5} {:>5} {:>7}",
+            "Team", "W-L", "PCT", "GB", "STRK", "RS", "RA", "DIFF", "PYTH", "xW-L")?;
+        
+        for record in &self.teams {
+            let expected = record.expected_record()
+                .map(|(wins, losses)| format_record(wins, losses))
+                .unwrap_or_else(|| "---".to_string());
+            // An unbeaten team's 1.000 doesn't fit the ".nnn" form
+            let pct = match record.winning_pct() {
+                Some(pct) if pct >= 1.0 => "1.000".to_string(),
+                pct => format_decimal_stat(pct),
+            };
+            writeln!(f, "  {:<22} {:>7} {:>5} {:>5} {:>4} {:>4} {:>4} {:>+5} {:>5} {:>7}",
+                truncate_string(record.team.display_name(), 22),
+                record.record().to_string(),
+                pct,
+                record.games_back,
+                record.streak.map(|streak| streak.to_string()).unwrap_or_default(),
+                record.runs_scored,
+                record.runs_allowed,
+                record.run_differential(),
+                format_decimal_stat(record.pythagorean_pct()),
+                expected)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PlayerSeasonStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} Season", self.season)?;
+        if self.hitting.is_none() && self.pitching.is_none() {
+            return writeln!(f, "  No stats recorded.");
+        }
+        
+        // OPS+ and ERA+ get a column only when league averages were fetched, and are starred
+        // when the season is too short for them to mean much
+        let mut small_sample = false;
+        let mut plus = |value: Option<u32>, small: bool| {
+            value.map(|value| {
+                small_sample |= small;
+                format!(" {}{}", value, if small { "*" } else { "" })
+            })
+        };
+        
+        if let Some(ref hitting) = self.hitting {
+            let ops_plus = plus(hitting.ops_plus, hitting.at_bats < MIN_PLUS_AT_BATS);
+            writeln!(f, "  HITTING:")?;
+            writeln!(f, "    {:<4} {:<4} {:<5} {:<5} {:<5} {:<5} {:<3} {:<3}{}",
+                "G", "AB", "AVG", "OBP", "SLG", "OPS", "HR", "RBI", if ops_plus.is_some() { " OPS+" } else { "" })?;
+            writeln!(f, "    {}", "-".repeat(if ops_plus.is_some() { 46 } else { 41 }))?;
+            writeln!(f, "    {:<4} {:<4} {:<5} {:<5} {:<5} {:<5} {:<3} {:<3}{}",
+                hitting.games, hitting.at_bats, hitting.avg, hitting.obp, hitting.slg, hitting.ops,
+                hitting.home_runs, hitting.rbi, ops_plus.unwrap_or_default())?;
+        }
+        
+        if let Some(ref pitching) = self.pitching {
+            let innings = InningsPitched::new(&pitching.innings_pitched).map(|ip| ip.as_float()).unwrap_or(0.0);
+            let era_plus = plus(pitching.era_plus, innings < MIN_PLUS_INNINGS);
+            writeln!(f, "  PITCHING:")?;
+            writeln!(f, "    {:<4} {:<6} {:<5} {:<5} {:<3}{}", "G", "IP", "ERA", "WHIP", "K", if era_plus.is_some() { " ERA+" } else { "" })?;
+            writeln!(f, "    {}", "-".repeat(if era_plus.is_some() { 32 } else { 27 }))?;
+            writeln!(f, "    {:<4} {:<6} {:<5} {:<5} {:<3}{}",
+                pitching.games, pitching.innings_pitched, pitching.era, pitching.whip, pitching.strikeouts,
+                era_plus.unwrap_or_default())?;
+        }
+        
+        if small_sample {
+            writeln!(f, "  * Small sample (under {} AB or {} IP)", MIN_PLUS_AT_BATS, MIN_PLUS_INNINGS)?;
+        }
+        
+        Ok(())
+    }
+}
+
+impl fmt::Display for PlayerSplits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} Splits", self.season)?;
+        if self.hitting.is_none() && self.pitching.is_none() {
+            return writeln!(f, "  No stats recorded.");
+        }
+        
+        if let Some(ref hitting) = self.hitting {
+            writeln!(f, "  HITTING:")?;
+            writeln!(f, "    {:<7} {:<4} {:<4} {:<5} {:<5} {:<5} {:<5} {:<3} {:<3}",
+                "", "G", "AB", "AVG", "OBP", "SLG", "OPS", "HR", "RBI")?;
+            writeln!(f, "    {}", "-".repeat(49))?;
+            for (label, line) in [("vs LHP", &hitting.vs_left), ("vs RHP", &hitting.vs_right)] {
+                writeln!(f, "    {:<7} {:<4} {:<4} {:<5} {:<5} {:<5} {:<5} {:<3} {:<3}",
+                    label, line.games, line.at_bats, line.avg, line.obp, line.slg, line.ops,
+                    line.home_runs, line.rbi)?;
+            }
+        }
+        
+        if let Some(ref pitching) = self.pitching {
+            writeln!(f, "  PITCHING:")?;
+            writeln!(f, "    {:<7} {:<4} {:<6} {:<5} {:<5} {:<3}", "", "G", "IP", "ERA", "WHIP", "K")?;
+            writeln!(f, "    {}", "-".repeat(35))?;
+            for (label, line) in [("vs LHB", &pitching.vs_left), ("vs RHB", &pitching.vs_right)] {
+                writeln!(f, "    {:<7} {:<4} {:<6} {:<5} {:<5} {:<3}",
+                    label, line.games, line.innings_pitched, line.era, line.whip, line.strikeouts)?;
+            }
+        }
+        
+        Ok(())
+    }
+}
+
+impl fmt::Display for PitchingStaff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} Pitching Staff", self.season)?;
+        if self.pitchers.is_empty() {
+            return writeln!(f, "  No pitchers found.");
+        }
+        
+        writeln!(f, "  {:<25} {:<4} {:>3} {:>3} {:>7} {:>3} {:>6} {:>5}",
+            "NAME", "ROLE", "G", "GS", "W-L", "SV", "IP", "ERA")?;
+        for pitcher in &self.pitchers {
+            writeln!(f, "  {:<25} {:<4} {:>3} {:>3} {:>7} {:>3} {:>6} {:>5}",
+                truncate_name(&pitcher.name, 25),
+                pitcher.role.to_string(),
+                pitcher.games,
+                pitcher.games_started,
+                format_record(pitcher.wins, pitcher.losses),
+                pitcher.saves,
+                pitcher.innings_pitched,
+                pitcher.era.as_deref().unwrap_or("-.--"))?;
+        }
+        
+        let leaders: Vec<String> = [
+            self.leader("W", |pitcher| pitcher.wins),
+            self.leader("L", |pitcher| pitcher.losses),
+            self.leader("SV", |pitcher| pitcher.saves),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !leaders.is_empty() {
+            writeln!(f, "\n  Leaders: {}", leaders.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Roster {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} Roster", self.season)?;
+        if self.players.is_empty() {
+            return writeln!(f, "  No players found.");
+        }
+        
+        for group in ["Pitchers", "Catchers", "Infielders", "Outfielders", "Other"] {
+            let players: Vec<&RosterEntry> = self.players.iter().filter(|player| player.position().group() == group).collect();
+            if players.is_empty() {
+                continue;
+            }
+            writeln!(f, "  {}:", group)?;
+            for player in players {
+                write!(f, "    {:>3} {:<3} {}",
+                    player.jersey_number.as_deref().map(|number| format!("#{}", number)).unwrap_or_default(),
+                    player.position,
+                    player.name)?;
+                if player.status != "Active" {
+                    write!(f, " ({})", player.status)?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Helper function to truncate player names to fit in display
+fn truncate_name(name: &str, max_len: usize) -> String {
+    if name.len() <= max_len {
+        name.to_string()
+    } else {
+        format!("{}...", &name[0..max_len-3])
+    }
+}
+
+// Helper function to parse innings pitched string to float
+fn parse_innings_pitched(ip: &str) -> f32 {
+    let parts: Vec<&str> = ip.split('.').collect();
+    if parts.len() == 2 {
+        let innings = parts[0].parse::<f32>().unwrap_or(0.0);
+        let fraction = parts[1].parse::<f32>().unwrap_or(0.0) / 3.0;
+        innings + fraction
+    } else {
+        ip.parse::<f32>().unwrap_or(0.0)
+    }
+}
+
+impl GameInnings {
+    /// Whether the home team won without batting in the last inning: the game is final, the
+    /// bottom half has no runs recorded, and the home team led after the top half
+    pub fn home_did_not_bat(&self) -> bool {
+        let Some((last, earlier)) = self.innings.split_last() else {
+            return false;
+        };
+        let home_runs: u32 = earlier.iter().filter_map(|inning| inning.home).sum();
+        let away_runs: u32 = self.innings.iter().filter_map(|inning| inning.away).sum();
+        self.status == GameState::Final && last.home.is_none() && home_runs > away_runs
+    }
+
+    /// The line score as a GitHub-flavored markdown table, with a column per inning
+    pub fn to_markdown(&self) -> String {
+        let innings: Vec<String> = (1..=self.innings.len()).map(|inning| inning.to_string()).collect();
+        let mut headers = vec!["TEAM"];
+        headers.extend(innings.iter().map(String::as_str));
+        headers.extend(["R", "H", "E"]);
+        
+        let (first, second) = in_order((&self.away_team, false), (&self.home_team, true), get_config().home_first);
+        let rows: Vec<Vec<String>> = [first, second]
+            .into_iter()
+            .map(|(team, home)| {
+                let (innings, totals) = self.line_cells(home);
+                std::iter::once(team.short_label().to_string()).chain(innings).chain(totals).collect()
+            })
+            .collect();
+        markdown_table(&headers, &rows)
+    }
+
+    // A team's runs in each inning, then its runs, hits and errors
+    fn line_cells(&self, home: bool) -> (Vec<String>, [String; 3]) {
+        let last = self.innings.len().saturating_sub(1);
+        let home_did_not_bat = home && self.home_did_not_bat();
+        let innings = self.innings.iter().enumerate().map(|(index, inning)| {
+            match if home { inning.home } else { inning.away } {
+                Some(runs) => runs.to_string(),
+                // The home team's skipped last at-bat is an X, unlike an inning that wasn't played
+                None if home_did_not_bat && index == last => "X".to_string(),
+                None => "-".to_string(),
+            }
+        });
+        
+        let (runs, hits, errors) = if home {
+            (self.home_runs, self.home_hits, self.home_errors)
+        } else {
+            (self.away_runs, self.away_hits, self.away_errors)
+        };
+        // Hits and errors aren't in every source, unlike runs
+        let count = |total: Option<u32>| total.map_or_else(|| "-".to_string(), |n| n.to_string());
+        (innings.collect(), [runs.unwrap_or(0).to_string(), count(hits), count(errors)])
+    }
+}
+
+impl fmt::Display for GameInnings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_ordered(f, get_config().home_first)
+    }
+}
+
+impl TeamOrdered for GameInnings {
+    fn fmt_ordered(&self, f: &mut fmt::Formatter<'_>, home_first: bool) -> fmt::Result {
+        // Compact single-line form: {:#}
+        if f.alternate() {
+            let (first, second) = in_order(
+                (&self.away_team, self.away_runs),
+                (&self.home_team, self.home_runs),
+                home_first,
+            );
+            return write!(
+                f,
+                "{} {} {} {} {} ({}, {} inn)",
+                first.0.short_label(),
+                first.1.unwrap_or(0),
+                separator(home_first),
+                second.0.short_label(),
+                second.1.unwrap_or(0),
+                self.status,
+                self.innings.len()
+            );
+        }
+
+        writeln!(f, "Game: {} vs {}", self.home_team.name, self.away_team.name)?;
+        writeln!(f, "Date: {}", self.game_date.split('T').next().unwrap_or(&self.game_date))?;
+        writeln!(f, "Status: {}", self.status)?;
+        
+        // A column per inning between the team label and the R/H/E totals, each set off by a divider
+        let innings = self.innings.len();
+        let mut headers = vec![String::new(), "|".to_string()];
+        headers.extend((1..=innings).map(|inning| inning.to_string()));
+        headers.extend(["|", "R", "H", "E"].map(String::from));
+        let mut table = Table::new(&headers);
+        let mut widths = vec![3, 1];
+        widths.extend(std::iter::repeat_n(2, innings));
+        widths.extend([1, 2, 2, 2]);
+        table.min_widths(&widths);
+        for column in (2..innings + 2).chain(innings + 3..innings + 6) {
+            table.align_right(column);
+        }
+        
+        // One line per team, in display order
+        let (first, second) = in_order(("Awa", false), ("Hom", true), home_first);
+        for (label, home) in [first, second] {
+            let (innings, totals) = self.line_cells(home);
+            let mut cells = vec![label.to_string(), "|".to_string()];
+            cells.extend(innings);
+            cells.push("|".to_string());
+            cells.extend(totals);
+            table.row(cells);
+        }
+        write!(f, "{}", table)
+    }
+}
+
+// Create a default instance for convenience using OnceLock instead of static mut
+static MLB_API_INSTANCE: OnceLock<MlbApi> = OnceLock::new();
+
+/// The shared MLB API client behind the free functions, initialized on first use
+pub fn get_mlb_api() -> &'static MlbApi {
+    MLB_API_INSTANCE.get_or_init(|| MlbApi::new())
+}
+
+/// Get player information by ID
 pub async fn get_player(player_id: u32) -> Result<Player> {
     get_mlb_api().get_player(player_id).await
 }
 
+/// Get a player's hitting and pitching stats for a season
+pub async fn get_player_season_stats(player_id: u32, season: u32) -> Result<PlayerSeasonStats> {
+    get_mlb_api().get_player_season_stats(player_id, season).await
+}
+
+/// Get the league-wide OBP, SLG and ERA for a season
+pub async fn get_league_averages(season: u32) -> Result<LeagueAverages> {
+    get_mlb_api().get_league_averages(season).await
+}
+
+/// Get a player's splits against left- and right-handed opponents for a season
+pub async fn get_player_splits(player_id: u32, season: u32) -> Result<PlayerSplits> {
+    get_mlb_api().get_player_splits(player_id, season).await
+}
+
 /// Get team information by ID
-pub async fn get_team(team_id: u32) -> Result<Team> {
-    get_mlb_api().get_team(team_id).await
+pub async fn get_team(team_id: impl Into<TeamId>) -> Result<Team> {
+    get_mlb_api().get_team(team_id.into().0).await
+}
+
+/// Get all MLB teams, sorted by division then name
+pub async fn get_all_teams() -> Result<Vec<Team>> {
+    get_mlb_api().get_all_teams().await
 }
 
 /// Get team schedule by ID
-pub async fn get_team_schedule(team_id: u32, start_date: Option<String>, end_date: Option<String>) -> Result<Vec<Game>> {
-    get_mlb_api().get_team_schedule(team_id, start_date, end_date).await
+pub async fn get_team_schedule(team_id: impl Into<TeamId>, start_date: Option<String>, end_date: Option<String>) -> Result<Vec<Game>> {
+    get_mlb_api().get_team_schedule(team_id.into().0, start_date, end_date).await
+}
+
+/// Find a team's game on a date, using `game_number` to pick one game of a doubleheader
+pub async fn find_team_game(team_id: impl Into<TeamId>, date: NaiveDate, game_number: Option<u32>) -> Result<Game> {
+    get_mlb_api().find_team_game(team_id.into().0, date, game_number).await
 }
 
 /// Get game information by ID
-pub async fn get_game(game_id: u64) -> Result<Game> {
-    get_mlb_api().get_game(game_id).await
+pub async fn get_game(game_id: impl Into<GameId>) -> Result<Game> {
+    get_mlb_api().get_game(u64::from(game_id.into().0)).await
+}
+
+/// Read a game from a saved feed/live response
+pub fn read_game_file(path: &std::path::Path) -> Result<Game> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+    let data: Value = serde_json::from_str(&contents)?;
+    get_mlb_api().parse_game_from_value(&data)
 }
 
 /// Get all MLB games for today
@@ -1597,19 +3773,70 @@ pub async fn get_yesterdays_games() -> Result<Vec<Game>> {
     get_mlb_api().get_yesterdays_games().await
 }
 
+/// Get all MLB games for a specific date
+pub async fn get_games_by_date(date: NaiveDate) -> Result<Vec<Game>> {
+    get_mlb_api().get_games_by_date(date).await
+}
+
 /// Get detailed game statistics by ID
-pub async fn get_game_stats(game_id: u32) -> Result<GameStats> {
-    get_mlb_api().get_game_stats(game_id).await
+pub async fn get_game_stats(game_id: impl Into<GameId>) -> Result<GameStats> {
+    get_mlb_api().get_game_stats(game_id.into().0).await
+}
+
+/// Get detailed game statistics for a finished game, using the on-disk cache
+pub async fn get_final_game_stats(game_id: impl Into<GameId>) -> Result<GameStats> {
+    get_mlb_api().get_final_game_stats(game_id.into().0).await
 }
 
 /// Get inning-by-inning breakdown for a game
-pub async fn get_game_innings(game_id: u32) -> Result<GameInnings> {
-    get_mlb_api().get_game_innings(game_id).await
+pub async fn get_game_innings(game_id: impl Into<GameId>) -> Result<GameInnings> {
+    get_mlb_api().get_game_innings(game_id.into().0).await
+}
+
+/// Get the plays on which runs scored in a game, in the order they happened
+pub async fn get_scoring_plays(game_id: impl Into<GameId>) -> Result<Vec<ScoringPlay>> {
+    get_mlb_api().get_scoring_plays(game_id.into().0).await
+}
+
+/// Get a batter's career line against a pitcher
+pub async fn get_matchup(batter_id: u32, pitcher_id: u32) -> Result<Matchup> {
+    get_mlb_api().get_matchup(batter_id, pitcher_id).await
+}
+
+/// Get two teams' season series record
+pub async fn head_to_head(team_a: impl Into<TeamId>, team_b: impl Into<TeamId>, season: u32) -> Result<HeadToHead> {
+    get_mlb_api().head_to_head(team_a.into().0, team_b.into().0, season).await
+}
+
+/// Get a compact score bug for a game
+pub async fn get_scorebug(game_id: impl Into<GameId>) -> Result<Scorebug> {
+    get_mlb_api().get_scorebug(u64::from(game_id.into().0)).await
+}
+
+/// Get the live situation of a game being played
+pub async fn get_live_game_state(game_id: impl Into<GameId>) -> Result<LiveData> {
+    get_mlb_api().get_live_game_state(game_id.into().0).await
+}
+
+/// Get the standings for every division, for both leagues and this season unless given
+pub async fn get_standings(league_id: Option<u32>, season: Option<u32>) -> Result<Vec<DivisionStandings>> {
+    get_mlb_api().get_standings(league_id, season).await
+}
+
+/// Get a team's roster for a season
+pub async fn get_team_roster(team_id: impl Into<TeamId>, season: u32) -> Result<Roster> {
+    get_mlb_api().get_team_roster(team_id.into().0, season).await
+}
+
+/// Get a team's pitching staff with each pitcher's season line, for this season unless one is given
+pub async fn get_team_pitching_staff(team_id: impl Into<TeamId>, season: Option<u32>) -> Result<PitchingStaff> {
+    get_mlb_api().get_team_pitching_staff(team_id.into().0, season).await
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::order::Ordered;
     
     fn mock_player() -> Player {
         Player {
@@ -1634,6 +3861,9 @@ mod tests {
                 venue: Some(Venue {
                     id: 1,
                     name: "Test Venue".to_string(),
+                    city: None,
+                    state: None,
+                    timezone: None,
                 }),
                 league: Some(League {
                     id: 1,
@@ -1643,6 +3873,7 @@ mod tests {
                     id: 1,
                     name: "Test Division".to_string(),
                 }),
+                record: None,
             }),
             position: Some(Position {
                 code: "P".to_string(),
@@ -1687,6 +3918,9 @@ mod tests {
             venue: Some(Venue {
                 id: 1,
                 name: "Test Venue".to_string(),
+                city: None,
+                state: None,
+                timezone: None,
             }),
             league: Some(League {
                 id: 1,
@@ -1696,6 +3930,7 @@ mod tests {
                 id: 1,
                 name: "Test Division".to_string(),
             }),
+            record: None,
         }
     }
 
@@ -1723,8 +3958,10 @@ mod tests {
                         venue: None,
                         league: None,
                         division: None,
+                        record: None,
                     },
                     is_winner: Some(false),
+                    probable_pitcher: None,
                 },
                 home: GameTeam {
                     score: Some(5),
@@ -1744,14 +3981,25 @@ mod tests {
                         venue: None,
                         league: None,
                         division: None,
+                        record: None,
                     },
                     is_winner: Some(true),
+                    probable_pitcher: None,
                 },
             },
             venue: Some(Venue {
                 id: 1,
                 name: "Wrigley Field".to_string(),
+                city: None,
+                state: None,
+                timezone: None,
             }),
+            series_game_number: None,
+            games_in_series: None,
+            game_number: None,
+            doubleheader: false,
+            current_inning: None,
+            game_type: None,
         }
     }
 
@@ -1773,6 +4021,49 @@ mod tests {
         assert!(display.contains("Test Venue"));
     }
 
+    #[test]
+    fn test_parse_team_venue() {
+        let team = parse_team(&serde_json::json!({
+            "id": 111,
+            "name": "Boston Red Sox",
+            "venue": {
+                "id": 3,
+                "name": "Fenway Park",
+                "link": "/api/v1/venues/3",
+                "location": {"city": "Boston", "state": "Massachusetts", "stateAbbrev": "MA", "country": "USA"},
+                "timeZone": {"id": "America/New_York", "offset": -4, "tz": "EDT"}
+            }
+        })).unwrap();
+        let venue = team.venue.as_ref().unwrap();
+        assert_eq!(venue.city.as_deref(), Some("Boston"));
+        assert_eq!(venue.state.as_deref(), Some("MA"));
+        assert_eq!(venue.timezone.as_deref(), Some("America/New_York"));
+        assert!(team.to_string().contains("Venue: Fenway Park, Boston, MA\n"));
+
+        // Game data only carries the venue's name
+        let team = parse_team(&serde_json::json!({
+            "id": 111,
+            "name": "Boston Red Sox",
+            "venue": {"id": 3, "name": "Fenway Park"}
+        })).unwrap();
+        assert!(team.venue.as_ref().unwrap().city.is_none());
+        assert!(team.to_string().contains("Venue: Fenway Park\n"));
+    }
+
+    #[test]
+    fn test_team_record_display() {
+        let mut team = mock_team();
+        assert!(!team.to_string().contains("Record:"));
+        
+        team.record = Some(Record::new(42, 34));
+        assert!(team.to_string().contains("Abbreviation: TST\nRecord: 42-34 (.553)\n"));
+        
+        // The record is left out of the JSON for teams without one
+        assert_eq!(serde_json::to_value(&team).unwrap()["record"]["wins"], 42);
+        team.record = None;
+        assert!(serde_json::to_value(&team).unwrap().get("record").is_none());
+    }
+
     #[test]
     fn test_get_team_schedule() {
         // Create a mock game
@@ -1797,13 +4088,104 @@ mod tests {
         assert!(display.contains("Chicago Cubs"));
     }
 
+    fn mock_batter(name: &str) -> PlayerBattingStats {
+        PlayerBattingStats {
+            name: name.to_string(),
+            hits: 0,
+            at_bats: 0,
+            home_runs: 0,
+            rbi: 0,
+            runs: 0,
+            doubles: 0,
+            triples: 0,
+            stolen_bases: 0,
+            walks: 0,
+            strikeouts: 0,
+            avg: None,
+            obp: None,
+            slg: None,
+            position: None,
+            batting_order: None,
+        }
+    }
+
+    #[test]
+    fn test_batting_sort_key() {
+        let mut batter = mock_batter("Test Batter");
+        batter.hits = 2;
+        batter.at_bats = 4;
+        batter.home_runs = 1;
+        batter.rbi = 3;
+        batter.stolen_bases = 2;
+        batter.obp = Some(".380".to_string());
+        batter.slg = Some(".560".to_string());
+        
+        // Without a season AVG it's the game's hits over at-bats
+        assert_eq!(batter.sort_key(BattingMetric::Avg), 0.5);
+        batter.avg = Some(".287".to_string());
+        assert!((batter.sort_key(BattingMetric::Avg) - 0.287).abs() < 1e-6);
+        assert_eq!(batter.sort_key(BattingMetric::HomeRuns), 1.0);
+        assert_eq!(batter.sort_key(BattingMetric::Rbi), 3.0);
+        assert!((batter.sort_key(BattingMetric::Ops) - 0.940).abs() < 1e-6);
+        assert_eq!(batter.sort_key(BattingMetric::StolenBases), 2.0);
+        
+        // Placeholders count as zero rather than failing
+        batter.avg = Some("---".to_string());
+        batter.obp = Some("---".to_string());
+        assert_eq!(batter.sort_key(BattingMetric::Avg), 0.5);
+        assert!((batter.sort_key(BattingMetric::Ops) - 0.560).abs() < 1e-6);
+        assert_eq!(mock_batter("Bench").sort_key(BattingMetric::Avg), 0.0);
+        
+        assert_eq!("hr".parse::<BattingMetric>(), Ok(BattingMetric::HomeRuns));
+        assert_eq!("OPS".parse::<BattingMetric>(), Ok(BattingMetric::Ops));
+        assert!("era".parse::<BattingMetric>().is_err());
+    }
+
+    #[test]
+    fn test_team_leaders() {
+        let batters: Vec<PlayerBattingStats> = [("A", 0, 1, ".250", "1.100"), ("B", 2, 0, ".310", ".900"), ("C", 1, 3, ".290", ".780"), ("D", 1, 0, "---", "---")]
+            .into_iter()
+            .map(|(name, home_runs, stolen_bases, avg, slg)| {
+                let mut batter = mock_batter(name);
+                batter.home_runs = home_runs;
+                batter.rbi = home_runs * 2;
+                batter.stolen_bases = stolen_bases;
+                batter.avg = Some(avg.to_string());
+                batter.obp = Some(".350".to_string());
+                batter.slg = Some(slg.to_string());
+                batter
+            })
+            .collect();
+        let team = TeamStats { team_name: "Test Team".to_string(), batting: None, pitching: None, batters, pitchers: Vec::new() };
+        let names = |metric: BattingMetric, n: usize| -> Vec<&str> {
+            team.leaders(metric, n).iter().map(|batter| batter.name.as_str()).collect()
+        };
+        
+        assert_eq!(names(BattingMetric::Avg, 3), vec!["B", "C", "A"]);
+        // Ties keep the box score's order, and batters without any are left out
+        assert_eq!(names(BattingMetric::HomeRuns, 3), vec!["B", "C", "D"]);
+        assert_eq!(names(BattingMetric::Rbi, 2), vec!["B", "C"]);
+        assert_eq!(names(BattingMetric::Ops, 3), vec!["A", "B", "C"]);
+        assert_eq!(names(BattingMetric::StolenBases, 3), vec!["C", "A"]);
+        
+        let game = GameStats {
+            away_team_stats: team,
+            home_team_stats: TeamStats { team_name: "Other Team".to_string(), batting: None, pitching: None, batters: Vec::new(), pitchers: Vec::new() },
+        };
+        assert_eq!(
+            game.leaders(BattingMetric::Ops, 2).to_string(),
+            "By OPS:\n  Test Team: A 1.450, B 1.250\n  Other Team: none\n"
+        );
+        assert_eq!(game.leaders(BattingMetric::Avg, 1).to_string(), "By AVG:\n  Test Team: B .310\n  Other Team: none\n");
+    }
+
     #[test]
     fn test_game_stats_display() {
         // Create mock game stats
         let game_stats = GameStats {
             away_team_stats: TeamStats {
                 team_name: "Test Away Team".to_string(),
-                batting: BattingStats {
+                batting: Some(BattingStats {
                     runs: 3,
                     hits: 8,
                     home_runs: 1,
@@ -1813,8 +4195,8 @@ mod tests {
                     obp: ".333".to_string(),
                     slg: ".400".to_string(),
                     ops: ".733".to_string(),
-                },
-                pitching: PitchingStats {
+                }),
+                pitching: Some(PitchingStats {
                     innings_pitched: "9.0".to_string(),
                     hits_allowed: 6,
                     runs_allowed: 2,
@@ -1823,7 +4205,8 @@ mod tests {
                     strikeouts: 10,
                     home_runs_allowed: 0,
                     era: "2.00".to_string(),
-                },
+                    whip: "1.00".to_string(),
+                }),
                 batters: vec![
                     PlayerBattingStats {
                         name: "Test Batter 1".to_string(),
@@ -1840,6 +4223,8 @@ mod tests {
                         avg: Some(".750".to_string()),
                         obp: Some(".750".to_string()),
                         slg: Some("1.500".to_string()),
+                        position: None,
+                        batting_order: None,
                     },
                     PlayerBattingStats {
                         name: "Test Batter 2".to_string(),
@@ -1856,6 +4241,8 @@ mod tests {
                         avg: Some(".500".to_string()),
                         obp: Some(".500".to_string()),
                         slg: Some(".750".to_string()),
+                        position: None,
+                        batting_order: None,
                     },
                     PlayerBattingStats {
                         name: "Test Batter 3".to_string(),
@@ -1872,6 +4259,8 @@ mod tests {
                         avg: Some(".333".to_string()),
                         obp: Some(".500".to_string()),
                         slg: Some(".333".to_string()),
+                        position: None,
+                        batting_order: None,
                     },
                 ],
                 pitchers: vec![
@@ -1885,6 +4274,8 @@ mod tests {
                         walks: 2,
                         home_runs_allowed: 0,
                         era: Some("1.50".to_string()),
+                        whip: None,
+                        decision: None,
                     },
                     PlayerPitchingStats {
                         name: "Test Pitcher 2".to_string(),
@@ -1896,12 +4287,14 @@ mod tests {
                         walks: 1,
                         home_runs_allowed: 0,
                         era: Some("3.00".to_string()),
+                        whip: None,
+                        decision: None,
                     },
                 ],
             },
             home_team_stats: TeamStats {
                 team_name: "Test Home Team".to_string(),
-                batting: BattingStats {
+                batting: Some(BattingStats {
                     runs: 2,
                     hits: 6,
                     home_runs: 0,
@@ -1911,8 +4304,8 @@ mod tests {
                     obp: ".300".to_string(),
                     slg: ".333".to_string(),
                     ops: ".633".to_string(),
-                },
-                pitching: PitchingStats {
+                }),
+                pitching: Some(PitchingStats {
                     innings_pitched: "9.0".to_string(),
                     hits_allowed: 8,
                     runs_allowed: 3,
@@ -1921,7 +4314,8 @@ mod tests {
                     strikeouts: 8,
                     home_runs_allowed: 1,
                     era: "3.00".to_string(),
-                },
+                    whip: "1.11".to_string(),
+                }),
                 batters: vec![
                     PlayerBattingStats {
                         name: "Test Batter 3".to_string(),
@@ -1938,6 +4332,8 @@ mod tests {
                         avg: Some(".500".to_string()),
                         obp: Some(".500".to_string()),
                         slg: Some(".750".to_string()),
+                        position: None,
+                        batting_order: None,
                     },
                     PlayerBattingStats {
                         name: "Test Batter 4".to_string(),
@@ -1954,6 +4350,8 @@ mod tests {
                         avg: Some(".333".to_string()),
                         obp: Some(".500".to_string()),
                         slg: Some(".333".to_string()),
+                        position: None,
+                        batting_order: None,
                     },
                 ],
                 pitchers: vec![
@@ -1967,6 +4365,8 @@ mod tests {
                         walks: 2,
                         home_runs_allowed: 0,
                         era: Some("1.50".to_string()),
+                        whip: None,
+                        decision: None,
                     },
                     PlayerPitchingStats {
                         name: "Test Pitcher 2".to_string(),
@@ -1978,6 +4378,8 @@ mod tests {
                         walks: 1,
                         home_runs_allowed: 0,
                         era: Some("3.00".to_string()),
+                        whip: None,
+                        decision: None,
                     },
                 ],
             },
@@ -1990,12 +4392,30 @@ mod tests {
         assert!(display.contains("Test Home Team"));
         assert!(display.contains("R: 3, H: 8, HR: 1"));
         assert!(display.contains("IP: 9.0, H: 6, R: 2"));
+        assert!(display.contains(", ERA: 2.00, WHIP: 1.00"));
+        assert!(display.contains("ERA   WHIP"));
         assert!(display.contains("BATTERS:"));
         assert!(display.contains("PITCHERS:"));
         assert!(display.contains("Test Batter 1"));
         assert!(display.contains("Test Pitcher 1"));
         assert!(display.contains("Test Batter 3"));
         assert!(display.contains("Test Pitcher 2"));
+        
+        // Pitchers without a WHIP from the API get one calculated from walks, hits and innings
+        let row = game_stats.away_team_stats.pitchers[0].row(25);
+        assert!(row.ends_with(" 1.50  1.00 "), "{}", row);
+        
+        // Team rows sum the players above them: 4+4+3 AB, 3+2+1 H, 1+1+1 R, 1+0+0 HR, 2+1+0 RBI,
+        // with the average recomputed as 6/11 rather than averaging .750, .500 and .333
+        let table = game_stats.away_team_stats.table(25);
+        let batters = table.lines().position(|line| line == "  BATTERS:").unwrap();
+        let pitchers = table.lines().position(|line| line == "  PITCHERS:").unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[pitchers - 1], "    TEAM                      11      6   3   1   3   .545  ---   ---  ");
+        assert_eq!(lines[pitchers - 2].trim(), "-".repeat(67));
+        assert_eq!(pitchers - batters, 8);
+        // 6.0 + 3.0 IP, 4+2 H, 1+1 R, 1+1 ER, 2+1 BB, 7+3 K, for a 2.00 ERA and 1.00 WHIP
+        assert_eq!(lines.last().copied(), Some("    TEAM                      9.0   6   2   2   3   10  2.00  1.00 "));
     }
 
     #[test]
@@ -2021,6 +4441,7 @@ mod tests {
                 venue: None,
                 league: None,
                 division: None,
+                record: None,
             },
             away_team: Team {
                 id: 2,
@@ -2038,6 +4459,7 @@ mod tests {
                 venue: None,
                 league: None,
                 division: None,
+                record: None,
             },
             innings: vec![
                 InningData {
@@ -2053,6 +4475,10 @@ mod tests {
             ],
             home_runs: Some(1),
             away_runs: Some(2),
+            home_hits: Some(5),
+            away_hits: Some(10),
+            home_errors: Some(1),
+            away_errors: None,
         };
 
         let display = format!("{}", game_innings);
@@ -2061,8 +4487,412 @@ mod tests {
         assert!(display.contains("Game: Home Team vs Away Team"));
         assert!(display.contains("Date: 2024-03-28"));
         assert!(display.contains("Status: Final"));
-        assert!(display.contains("Awa |  0  2  | 2"));
-        assert!(display.contains("Hom |  1  0  | 1"));
+        
+        // R/H/E totals stay aligned under their headings with double-digit hits
+        let lines: Vec<&str> = display.lines().collect();
+        assert_eq!(lines[3], "    |  1  2 |  R  H  E");
+        assert_eq!(lines[4], "----+-------+---------");
+        assert_eq!(lines[5], "Awa |  0  2 |  2 10  -");
+        assert_eq!(lines[6], "Hom |  1  0 |  1  5  1");
+
+        let compact = format!("{:#}", game_innings);
+        assert_eq!(compact, "Away Team 2 @ Home Team 1 (Final, 2 inn)");
+
+        // Home team first puts the home line-score row on top
+        let home_first = format!("{}", Ordered(&game_innings, true));
+        assert!(home_first.contains("Hom |  1  0 |  1  5  1\nAwa |  0  2 |  2 10  -"));
+        assert_eq!(format!("{}", Ordered(&game_innings, false)), display);
+        assert_eq!(format!("{:#}", Ordered(&game_innings, true)), "Home Team 1 vs Away Team 2 (Final, 2 inn)");
+    }
+
+    // A game between the test teams with the given (away, home) runs per inning
+    fn mock_innings(status: GameState, line: &[(Option<u32>, Option<u32>)]) -> GameInnings {
+        GameInnings {
+            game_pk: 1,
+            game_date: "2024-07-04".to_string(),
+            status,
+            home_team: mock_team(),
+            away_team: mock_team(),
+            innings: line.iter().enumerate().map(|(index, &(away, home))| InningData {
+                inning: index as u32 + 1,
+                away,
+                home,
+            }).collect(),
+            home_runs: Some(line.iter().filter_map(|inning| inning.1).sum()),
+            away_runs: Some(line.iter().filter_map(|inning| inning.0).sum()),
+            home_hits: None,
+            away_hits: None,
+            home_errors: None,
+            away_errors: None,
+        }
+    }
+
+    #[test]
+    fn test_game_innings_walk_off() {
+        let mut line = vec![(Some(0), Some(0)); 8];
+        line.push((Some(1), Some(2)));
+        let innings = mock_innings(GameState::Final, &line);
+        
+        assert!(!innings.home_did_not_bat());
+        let display = innings.to_string();
+        assert!(display.contains("\nHom |  0  0  0  0  0  0  0  0  2 |  2  -  -"), "{}", display);
+    }
+
+    #[test]
+    fn test_game_innings_home_did_not_bat() {
+        let mut line = vec![(Some(0), Some(0)); 7];
+        line.push((Some(0), Some(3)));
+        line.push((Some(1), None));
+        let innings = mock_innings(GameState::Final, &line);
+        
+        assert!(innings.home_did_not_bat());
+        let display = innings.to_string();
+        assert!(display.contains("\nAwa |  0  0  0  0  0  0  0  0  1 |  1  -  -"), "{}", display);
+        assert!(display.contains("\nHom |  0  0  0  0  0  0  0  3  X |  3  -  -"), "{}", display);
+        
+        // The same goes for the 7th inning of a seven-inning doubleheader game
+        let seven = mock_innings(GameState::Final, &[(Some(0), Some(1)), (Some(0), Some(0)), (Some(0), Some(0)),
+            (Some(0), Some(0)), (Some(0), Some(0)), (Some(0), Some(0)), (Some(0), None)]);
+        assert!(seven.to_string().contains("\nHom |  1  0  0  0  0  0  X |  1  -  -"));
+        
+        // A bottom half that wasn't played because the game stopped early is still a dash
+        let suspended = mock_innings(GameState::Suspended, &line);
+        assert!(!suspended.home_did_not_bat());
+        assert!(suspended.to_string().contains("  3  - |  3  -  -"));
+        let trailing = mock_innings(GameState::Final, &[(Some(2), Some(0)), (Some(0), None)]);
+        assert!(!trailing.home_did_not_bat());
+    }
+
+    #[test]
+    fn test_game_display_winner() {
+        let game = mock_game();
+        assert_eq!(game.winner().map(|team| team.name.as_str()), Some("Cubs"));
+        assert!(!game.is_tie());
+        
+        let display = format!("{}", game);
+        assert!(display.contains("Winner: Cubs"));
+        assert!(!display.contains("Tie"));
+    }
+
+    #[test]
+    fn test_winner_without_flags() {
+        // A final game with scores but no isWinner flags goes to the higher score
+        let mut game = mock_game();
+        game.teams.away.is_winner = None;
+        game.teams.home.is_winner = None;
+        assert_eq!(game.winner().map(|team| team.name.as_str()), Some("Cubs"));
+        assert!(format!("{}", game).contains("Winner: Cubs"));
+        
+        game.teams.away.score = Some(7);
+        assert_eq!(game.winner().map(|team| team.name.as_str()), Some("White Sox"));
+        
+        // Without both scores there's nothing to compare
+        game.teams.home.score = None;
+        assert!(game.winner().is_none());
+        
+        // Suspended games have no winner whatever the score
+        game.teams.home.score = Some(2);
+        game.status = GameState::Suspended;
+        assert!(game.winner().is_none());
+    }
+
+    #[test]
+    fn test_game_display_tie() {
+        let mut game = mock_game();
+        game.teams.away.score = Some(4);
+        game.teams.home.score = Some(4);
+        game.teams.away.is_winner = None;
+        game.teams.home.is_winner = None;
+        
+        assert!(game.winner().is_none());
+        assert!(game.is_tie());
+        
+        let display = format!("{}", game);
+        assert!(display.contains("Result: Tie"));
+        assert!(!display.contains("Winner:"));
+        assert_eq!(format!("{:#}", game), "2024-03-28 CWS 4 @ CHC 4 (Final, Tie)");
+        
+        // Level scores mid-game aren't a tie
+        game.status = GameState::Live;
+        assert!(!game.is_tie());
+        assert!(!format!("{}", game).contains("Tie"));
+    }
+
+    #[test]
+    fn test_parse_schedule_game_series() {
+        let fixture = serde_json::json!({
+            "gamePk": 745612,
+            "gameDate": "2024-03-29T20:20:00Z",
+            "status": {"abstractGameState": "Final", "detailedState": "Final"},
+            "seriesGameNumber": 2,
+            "gamesInSeries": 3,
+            "teams": {
+                "away": {"score": 4, "isWinner": true, "team": {"id": 158, "name": "Milwaukee Brewers", "abbreviation": "MIL"}},
+                "home": {"score": 2, "isWinner": false, "team": {"id": 121, "name": "New York Mets", "abbreviation": "NYM"}}
+            },
+            "venue": {"id": 3289, "name": "Citi Field"}
+        });
+        
+        let api = MlbApi::new();
+        let game = api.parse_schedule_game(&fixture);
+        assert_eq!(game.series_game_number, Some(2));
+        assert_eq!(game.games_in_series, Some(3));
+        assert!(format!("{}", game).contains("Series: Game 2 of 3"));
+        
+        // Series context is omitted when the schedule doesn't include it
+        let mut fixture = fixture;
+        fixture.as_object_mut().unwrap().remove("seriesGameNumber");
+        fixture.as_object_mut().unwrap().remove("gamesInSeries");
+        let game = api.parse_schedule_game(&fixture);
+        assert!(game.series_game_number.is_none());
+        assert!(!format!("{}", game).contains("Series:"));
+        assert!(!format!("{}", game).contains("doubleheader"));
+    }
+
+    #[test]
+    fn test_series_label() {
+        let mut game = mock_game();
+        for (code, label) in [
+            ("F", Some("Wild Card Series")),
+            ("D", Some("Division Series")),
+            ("L", Some("League Championship Series")),
+            ("W", Some("World Series")),
+            ("R", None),
+            ("S", None),
+        ] {
+            game.game_type = Some(code.to_string());
+            assert_eq!(game.series_label(), label, "{}", code);
+            assert_eq!(game.is_postseason(), label.is_some(), "{}", code);
+        }
+        game.game_type = None;
+        assert_eq!(game.series_label(), None);
+        
+        // Playoff games say which round they're in, alongside the game of the series
+        game.game_type = Some("W".to_string());
+        assert_eq!(format!("{:#}", game), "2024-03-28 CWS 3 @ CHC 5 (Final, World Series)");
+        assert!(format!("{}", game).contains("Series: World Series\n"));
+        game.series_game_number = Some(3);
+        game.games_in_series = Some(7);
+        assert!(format!("{}", game).contains("Series: World Series, Game 3 of 7"));
+        
+        // The schedule's gameType is picked up
+        let fixture = serde_json::json!({
+            "gamePk": 775296,
+            "gameDate": "2024-10-25T00:08:00Z",
+            "gameType": "W",
+            "status": {"abstractGameState": "Final"},
+            "teams": {
+                "away": {"score": 3, "isWinner": false, "team": {"id": 147, "name": "New York Yankees", "abbreviation": "NYY"}},
+                "home": {"score": 6, "isWinner": true, "team": {"id": 119, "name": "Los Angeles Dodgers", "abbreviation": "LAD"}}
+            }
+        });
+        let game = MlbApi::new().parse_schedule_game(&fixture);
+        assert_eq!(game.game_type.as_deref(), Some("W"));
+        assert!(game.is_postseason());
+    }
+
+    #[test]
+    fn test_head_to_head() {
+        let yankees = serde_json::json!({"id": 147, "name": "New York Yankees", "teamName": "Yankees", "abbreviation": "NYY"});
+        let red_sox = serde_json::json!({"id": 111, "name": "Boston Red Sox", "teamName": "Red Sox", "abbreviation": "BOS"});
+        let game = |game_pk: u32, state: &str, (away, away_runs): (&Value, u32), (home, home_runs): (&Value, u32)| {
+            let finished = state == "Final";
+            serde_json::json!({
+                "gamePk": game_pk,
+                "gameDate": "2024-06-14T23:05:00Z",
+                "status": {"abstractGameState": state},
+                "teams": {
+                    "away": {"score": away_runs, "isWinner": finished && away_runs > home_runs, "team": away},
+                    "home": {"score": home_runs, "isWinner": finished && home_runs > away_runs, "team": home}
+                }
+            })
+        };
+        let fixture = serde_json::json!({"dates": [
+            {"games": [game(1, "Final", (&yankees, 5), (&red_sox, 3))]},
+            {"games": [game(2, "Final", (&yankees, 2), (&red_sox, 6)), game(3, "Final", (&red_sox, 1), (&yankees, 4))]},
+            {"games": [game(4, "Preview", (&red_sox, 0), (&yankees, 0))]}
+        ]});
+        
+        let api = MlbApi::new();
+        let series = HeadToHead::tally(147, 111, api.parse_schedule(&fixture));
+        assert_eq!((series.a_wins, series.b_wins), (2, 1));
+        assert_eq!(format!("{:#}", series), "Yankees lead series 2-1");
+        
+        // The game still to be played is listed but not counted
+        let listing = format!("{}", series);
+        assert_eq!(listing.lines().count(), 5);
+        assert!(listing.ends_with("BOS 0 @ NYY 0 (Scheduled)\n"), "{}", listing);
+        
+        // The record reads from the leader's side, whichever team was asked about first
+        let series = HeadToHead::tally(111, 147, api.parse_schedule(&fixture));
+        assert_eq!(format!("{:#}", series), "Yankees lead series 2-1");
+        assert_eq!(format!("{:#}", HeadToHead::tally(147, 111, Vec::new())), "Series tied 0-0");
+    }
+
+    #[test]
+    fn test_parse_schedule_game_doubleheader() {
+        let api = MlbApi::new();
+        let fixture = serde_json::json!({
+            "gamePk": 746001,
+            "gameDate": "2024-07-04T23:10:00Z",
+            "status": {"abstractGameState": "Final", "detailedState": "Final"},
+            "gameNumber": 2,
+            "doubleHeader": "S",
+            "teams": {
+                "away": {"score": 1, "team": {"id": 145, "name": "Chicago White Sox", "abbreviation": "CWS"}},
+                "home": {"score": 6, "team": {"id": 116, "name": "Detroit Tigers", "abbreviation": "DET"}}
+            },
+            "venue": {"id": 2394, "name": "Comerica Park"}
+        });
+        let game = api.parse_schedule_game(&fixture);
+        assert_eq!(game.game_number, Some(2));
+        assert!(game.doubleheader);
+        assert!(format!("{}", game).ends_with("Game 2 of doubleheader\n"));
+        
+        // The opener is labelled too, since the schedule marks it as part of a doubleheader
+        let mut fixture = fixture;
+        fixture["gameNumber"] = serde_json::json!(1);
+        fixture["doubleHeader"] = serde_json::json!("Y");
+        assert!(format!("{}", api.parse_schedule_game(&fixture)).contains("Game 1 of doubleheader"));
+        
+        // An ordinary game is game 1 of the day but not of a doubleheader
+        fixture["doubleHeader"] = serde_json::json!("N");
+        assert!(!format!("{}", api.parse_schedule_game(&fixture)).contains("doubleheader"));
+    }
+
+    #[test]
+    fn test_parse_schedule_game_probable_pitchers() {
+        let api = MlbApi::new();
+        let fixture = serde_json::json!({
+            "gamePk": 746100,
+            "gameDate": "2024-07-05T23:10:00Z",
+            "status": {"abstractGameState": "Preview", "detailedState": "Scheduled"},
+            "teams": {
+                "away": {"team": {"id": 145, "name": "Chicago White Sox"}, "probablePitcher": {"id": 656302, "fullName": "Dylan Cease"}},
+                "home": {"team": {"id": 116, "name": "Detroit Tigers"}, "probablePitcher": {"id": 669373, "fullName": "Tarik Skubal"}}
+            }
+        });
+        let game = api.parse_schedule_game(&fixture);
+        assert_eq!(game.teams.away.probable_pitcher.as_deref(), Some("Dylan Cease"));
+        assert!(format!("{}", game).contains("Probable: Dylan Cease vs Tarik Skubal\n"));
+        
+        // A starter still to be announced shows as TBD
+        let mut fixture = fixture;
+        fixture["teams"]["home"].as_object_mut().unwrap().remove("probablePitcher");
+        assert!(format!("{}", api.parse_schedule_game(&fixture)).contains("Probable: Dylan Cease vs TBD\n"));
+        
+        // Nothing is printed once neither side has one, or once the game is over
+        fixture["teams"]["away"].as_object_mut().unwrap().remove("probablePitcher");
+        assert!(!format!("{}", api.parse_schedule_game(&fixture)).contains("Probable"));
+        fixture["teams"]["away"]["probablePitcher"] = serde_json::json!({"fullName": "Dylan Cease"});
+        fixture["status"]["abstractGameState"] = serde_json::json!("Final");
+        assert!(!format!("{}", api.parse_schedule_game(&fixture)).contains("Probable"));
+    }
+
+    #[test]
+    fn test_pick_doubleheader_game() {
+        let api = MlbApi::new();
+        let game = |pk: u32, number: u32| api.parse_schedule_game(&serde_json::json!({
+            "gamePk": pk,
+            "gameDate": "2024-07-04T17:05:00Z",
+            "gameNumber": number,
+            "status": {"abstractGameState": "Final"},
+            "teams": {
+                "away": {"team": {"id": 145, "name": "Chicago White Sox"}},
+                "home": {"team": {"id": 116, "name": "Detroit Tigers"}}
+            }
+        }));
+        let doubleheader = || vec![game(746001, 1), game(746002, 2)];
+        
+        assert_eq!(pick_game(doubleheader(), Some(2)).unwrap().game_pk, 746002);
+        assert_eq!(pick_game(doubleheader(), Some(1)).unwrap().game_pk, 746001);
+        let err = pick_game(doubleheader(), None).unwrap_err().to_string();
+        assert!(err.contains("2 games scheduled"), "{}", err);
+        assert!(pick_game(doubleheader(), Some(3)).is_err());
+        
+        // With only one game the number is optional
+        assert_eq!(pick_game(vec![game(746003, 1)], None).unwrap().game_pk, 746003);
+        assert!(pick_game(Vec::new(), None).is_err());
+    }
+
+    #[test]
+    fn test_game_display_compact() {
+        let game = mock_game();
+
+        let full = format!("{}", game);
+        assert!(full.contains("Game ID: "));
+        assert!(full.lines().count() > 1);
+
+        let compact = format!("{:#}", game);
+        assert_eq!(compact, "2024-03-28 CWS 3 @ CHC 5 (Final)");
+    }
+
+    #[test]
+    fn test_summary_line() {
+        assert_eq!(mock_game().summary_line(), "CWS 3 @ CHC 5 (Final)");
+        
+        // Live games from the schedule carry the inning from the linescore
+        let fixture = serde_json::json!({
+            "gamePk": 745613,
+            "gameDate": "2024-03-30T20:10:00Z",
+            "status": {"abstractGameState": "Live", "detailedState": "In Progress"},
+            "teams": {
+                "away": {"score": 5, "team": {"id": 147, "name": "New York Yankees", "abbreviation": "NYY"}},
+                "home": {"score": 3, "team": {"id": 111, "name": "Boston Red Sox", "abbreviation": "BOS"}}
+            },
+            "linescore": {"currentInningOrdinal": "7th", "inningState": "Bottom"}
+        });
+        let game = MlbApi::new().parse_schedule_game(&fixture);
+        assert_eq!(game.current_inning.as_deref(), Some("Bottom 7th"));
+        assert_eq!(game.summary_line(), "NYY 5 @ BOS 3 (Live, Bottom 7th)");
+        
+        // Games that haven't started have no score to show
+        let mut game = mock_game();
+        game.status = GameState::Scheduled;
+        assert_eq!(game.summary_line(), "CWS @ CHC (Scheduled)");
+    }
+
+    #[test]
+    fn test_to_ics() {
+        let mut first = mock_game();
+        first.venue = Some(Venue { id: 17, name: "Wrigley Field, Chicago".to_string(), city: None, state: None, timezone: None });
+        let mut second = mock_game();
+        second.game_pk = 12346;
+        second.game_date = "2024-03-29".to_string();
+        second.venue = None;
+
+        let ics = crate::calendar::to_ics(&[first, second]);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert_eq!(ics.matches("END:VEVENT").count(), 2);
+
+        // Start times are in UTC, with the end a few hours later
+        assert!(ics.contains("\r\nUID:mlb-12345@plaintext-sports\r\n"));
+        assert!(ics.contains("\r\nDTSTART:20240328T130500Z\r\nDTEND:20240328T160500Z\r\n"));
+        assert!(ics.contains("\r\nSUMMARY:White Sox @ Cubs\r\n"));
+        assert!(ics.contains("\r\nLOCATION:Wrigley Field\\, Chicago\r\n"));
+
+        // A game without a start time is an all-day event
+        assert!(ics.contains("\r\nDTSTART;VALUE=DATE:20240329\r\n"));
+        assert_eq!(ics.matches("LOCATION:").count(), 1);
+    }
+
+    #[test]
+    fn test_game_display_home_first() {
+        let game = mock_game();
+
+        let away_first = format!("{}", Ordered(&game, false));
+        assert!(away_first.contains("  Away: Chicago White Sox (3)\n  Home: Chicago Cubs (5)"));
+        assert_eq!(format!("{:#}", Ordered(&game, false)), "2024-03-28 CWS 3 @ CHC 5 (Final)");
+
+        let home_first = format!("{}", Ordered(&game, true));
+        assert!(home_first.contains("  Home: Chicago Cubs (5)\n  Away: Chicago White Sox (3)"));
+        assert_eq!(format!("{:#}", Ordered(&game, true)), "2024-03-28 CHC 5 vs CWS 3 (Final)");
+
+        // The winner doesn't depend on the order
+        assert!(away_first.contains("Winner: Cubs"));
+        assert!(home_first.contains("Winner: Cubs"));
     }
 
     #[test]
@@ -2124,4 +4954,923 @@ mod tests {
         assert!(display.contains("Chicago Cubs"));
         assert!(display.contains("Date: 2024-03-28"));
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_parse_scoring_plays() {
+        let api = MlbApi::new();
+        let play = |inning: u64, half: &str, scoring: bool, description: &str, away: u64, home: u64| serde_json::json!({
+            "result": {"description": description, "awayScore": away, "homeScore": home},
+            "about": {"inning": inning, "halfInning": half, "isTopInning": half == "top", "isScoringPlay": scoring}
+        });
+        let data = serde_json::json!({
+            "allPlays": [
+                play(1, "top", false, "Juan Soto walks.", 0, 0),
+                play(1, "bottom", true, "Rafael Devers singles on a line drive to left field. Jarren Duran scores.", 0, 1),
+                play(3, "top", true, "Aaron Judge homers (2) on a fly ball to left field. Juan Soto scores. Anthony Volpe scores.", 3, 1),
+                play(3, "bottom", false, "Triston Casas grounds out.", 3, 1)
+            ]
+        });
+        
+        let plays = api.parse_scoring_plays(&data, "NYY", "BOS");
+        assert_eq!(plays.len(), 2);
+        assert_eq!(plays[0].to_string(), "B1: Rafael Devers singles on a line drive to left field. Jarren Duran scores, NYY 0 - BOS 1");
+        assert_eq!(
+            plays[1].to_string(),
+            "T3: Aaron Judge homers (2) on a fly ball to left field. Juan Soto scores. Anthony Volpe scores, NYY 3 - BOS 1"
+        );
+        
+        // The same plays give the line score, with an unplayed half-inning left empty
+        let innings = api.parse_play_by_play_innings(&data);
+        let line: Vec<(u32, Option<u32>, Option<u32>)> = innings.iter().map(|inning| (inning.inning, inning.away, inning.home)).collect();
+        assert_eq!(line, vec![(1, Some(0), Some(1)), (2, None, None), (3, Some(3), Some(0))]);
+        
+        assert!(api.parse_scoring_plays(&serde_json::json!({}), "NYY", "BOS").is_empty());
+    }
+    
+    #[test]
+    fn test_parse_matchup() {
+        let fixture = serde_json::json!({
+            "stats": [
+                {
+                    "type": {"displayName": "vsPlayer"},
+                    "group": {"displayName": "hitting"},
+                    "splits": [
+                        {
+                            "season": "2023",
+                            "stat": {"atBats": 10, "hits": 3, "homeRuns": 1, "avg": ".300"},
+                            "batter": {"id": 545361, "fullName": "Mike Trout"},
+                            "pitcher": {"id": 543037, "fullName": "Gerrit Cole"}
+                        }
+                    ]
+                },
+                {
+                    "type": {"displayName": "vsPlayerTotal"},
+                    "group": {"displayName": "hitting"},
+                    "splits": [
+                        {
+                            "stat": {
+                                "atBats": 25, "hits": 7, "doubles": 2, "triples": 0, "homeRuns": 3,
+                                "rbi": 5, "baseOnBalls": 4, "strikeOuts": 9,
+                                "avg": ".280", "obp": ".379", "slg": ".720"
+                            },
+                            "batter": {"id": 545361, "fullName": "Mike Trout"},
+                            "pitcher": {"id": 543037, "fullName": "Gerrit Cole"}
+                        }
+                    ]
+                }
+            ]
+        });
+        
+        let api = MlbApi::new();
+        let matchup = api.parse_matchup(&fixture, 545361, 543037);
+        let career = matchup.career.as_ref().unwrap();
+        assert_eq!(career.at_bats, 25);
+        assert_eq!(career.hits, 7);
+        assert_eq!(career.home_runs, 3);
+        
+        let display = format!("{}", matchup);
+        assert!(display.contains("Mike Trout vs. Gerrit Cole (career):"));
+        assert!(display.contains("7-for-25, 2 2B, 0 3B, 3 HR, 5 RBI, 4 BB, 9 K"));
+        assert!(display.contains("AVG/OBP/SLG: .280/.379/.720"));
+    }
+
+    #[test]
+    fn test_parse_matchup_no_history() {
+        let fixture = serde_json::json!({
+            "stats": [
+                {"type": {"displayName": "vsPlayerTotal"}, "group": {"displayName": "hitting"}, "splits": []}
+            ]
+        });
+        
+        let api = MlbApi::new();
+        let matchup = api.parse_matchup(&fixture, 545361, 543037);
+        assert!(matchup.career.is_none());
+        assert_eq!(format!("{}", matchup).trim(), "Batter 545361 has never faced pitcher 543037.");
+        
+        // An empty response is treated the same way
+        let matchup = api.parse_matchup(&serde_json::json!({}), 545361, 543037);
+        assert!(matchup.career.is_none());
+    }
+
+    #[test]
+    fn test_parse_standings() {
+        let fixture = serde_json::json!({
+            "records": [
+                {
+                    "division": {"id": 202, "name": "American League Central"},
+                    "teamRecords": [
+                        {
+                            "team": {"id": 145, "name": "Chicago White Sox"},
+                            "wins": 0, "losses": 0, "gamesBack": "-",
+                            "runsScored": 0, "runsAllowed": 0
+                        },
+                        {
+                            "team": {"id": 116, "name": "Detroit Tigers"},
+                            "wins": 86, "losses": 76, "gamesBack": "6.5",
+                            "streak": {"streakCode": "L2"},
+                            "runsScored": 682, "runsAllowed": 642
+                        },
+                        {
+                            "team": {"id": 114, "name": "Cleveland Guardians"},
+                            "wins": 92, "losses": 69, "gamesBack": "-",
+                            "streak": {"streakCode": "W3"},
+                            "runsScored": 708, "runsAllowed": 621
+                        }
+                    ]
+                }
+            ]
+        });
+        
+        let api = MlbApi::new();
+        let standings = api.parse_standings(&fixture);
+        assert_eq!(standings.len(), 1);
+        assert_eq!(standings[0].division, "American League Central");
+        
+        // Sorted by winning percentage, with the team that hasn't played last
+        let order: Vec<u32> = standings[0].teams.iter().map(|record| record.team.id).collect();
+        assert_eq!(order, vec![114, 116, 145]);
+        
+        let cleveland = &standings[0].teams[0];
+        assert_eq!(cleveland.record(), Record::new(92, 69));
+        assert_eq!(cleveland.run_differential(), 87);
+        assert_eq!(cleveland.expected_record(), Some((90, 71)));
+        assert_eq!(cleveland.streak, Some(Streak { kind: crate::types::StreakKind::Win, length: 3 }));
+        
+        // Before any runs are scored there's nothing to base an expectation on
+        let chicago = &standings[0].teams[2];
+        assert_eq!(chicago.winning_pct(), None);
+        assert_eq!(chicago.pythagorean_pct(), None);
+        assert_eq!(chicago.expected_record(), None);
+        assert_eq!(chicago.streak, None);
+        
+        let display = standings[0].to_string();
+        let lines: Vec<&str> = display.lines().collect();
+        assert_eq!(lines[2], "  Cleveland Guardians      92-69  .571     -   W3  708  621   +87  .560   90-71");
+        assert_eq!(lines[3], "  Detroit Tigers           86-76  .531   6.5   L2  682  642   +40  .528   85-77");
+        assert_eq!(lines[4], "  Chicago White Sox          0-0   ---     -         0    0    +0   ---     ---");
+        
+        assert!(api.parse_standings(&serde_json::json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_game_stats_without_team_totals() {
+        let api = MlbApi::new();
+        let team = |name: &str| serde_json::json!({
+            "team": {"name": name},
+            "batters": [660271],
+            "players": {
+                "ID660271": {
+                    "person": {"fullName": "Shohei Ohtani"},
+                    "stats": {"batting": {"atBats": 4, "hits": 2, "runs": 1, "homeRuns": 1, "rbi": 3}}
+                }
+            }
+        });
+        let boxscore = serde_json::json!({
+            "teams": {"away": team("Los Angeles Dodgers"), "home": team("San Diego Padres")}
+        });
+        
+        let stats = api.parse_game_stats(&boxscore, game_decisions(&boxscore)).unwrap();
+        assert!(stats.away_team_stats.batting.is_none());
+        assert!(stats.away_team_stats.pitching.is_none());
+        assert_eq!(stats.away_team_stats.batters.len(), 1);
+        
+        let display = stats.to_string();
+        assert!(display.contains("  BATTING: team totals unavailable\n  PITCHING: team totals unavailable"));
+        assert!(!display.contains("R: 0, H: 0"));
+        assert!(display.contains("Shohei Ohtani"));
+    }
+
+    #[test]
+    fn test_batters_in_batting_order() {
+        let batter = |name: &str, order: Option<&str>| {
+            let mut player = serde_json::json!({
+                "person": {"fullName": name},
+                "stats": {"batting": {"atBats": 3, "hits": 1}}
+            });
+            if let Some(order) = order {
+                player["battingOrder"] = serde_json::json!(order);
+            }
+            player
+        };
+        let fixture = serde_json::json!({
+            "team": {"name": "Chicago White Sox"},
+            "batters": [3, 1, 4, 2],
+            "players": {
+                "ID1": batter("Zach Leadoff", Some("100")),
+                "ID2": batter("Pinch Hitter", Some("101")),
+                "ID3": batter("Andrew Cleanup", Some("400")),
+                "ID4": batter("Garrett Crochet", None)
+            }
+        });
+        
+        let api = MlbApi::new();
+        let stats = api.extract_team_stats(&fixture, &HashMap::new()).unwrap();
+        let lineup: Vec<&str> = stats.batters.iter().map(|batter| batter.name.as_str()).collect();
+        assert_eq!(lineup, vec!["Zach Leadoff", "Pinch Hitter", "Andrew Cleanup", "Garrett Crochet"]);
+        assert_eq!(stats.batters[0].batting_order, Some(100));
+        assert_eq!(stats.batters[3].batting_order, None);
+    }
+
+    #[test]
+    fn test_game_stats_to_csv() {
+        let api = MlbApi::new();
+        let boxscore = serde_json::json!({
+            "teams": {
+                "away": {
+                    "team": {"name": "Seattle Mariners"},
+                    "batters": [115135],
+                    "pitchers": [],
+                    "players": {
+                        "ID115135": {
+                            "person": {"fullName": "Griffey, Jr."},
+                            "position": {"abbreviation": "CF"},
+                            "stats": {"batting": {"atBats": 4, "hits": 2, "runs": 1, "homeRuns": 1, "rbi": 3, "avg": ".284"}}
+                        }
+                    }
+                },
+                "home": {
+                    "team": {"name": "Oakland Athletics"},
+                    "batters": [],
+                    "pitchers": [450308],
+                    "players": {
+                        "ID450308": {
+                            "person": {"fullName": "Dave Stewart"},
+                            "stats": {"pitching": {
+                                "inningsPitched": "7.0", "hits": 6, "runs": 2, "earnedRuns": 2,
+                                "baseOnBalls": 1, "strikeOuts": 8, "homeRuns": 1, "era": "3.21"
+                            }}
+                        }
+                    }
+                }
+            }
+        });
+        
+        let csv = api.parse_game_stats(&boxscore, game_decisions(&boxscore)).unwrap().to_csv();
+        assert_eq!(
+            csv,
+            "name,team,pos,ab,h,r,hr,rbi,avg\n\
+             \"Griffey, Jr.\",Seattle Mariners,CF,4,2,1,1,3,.284\n\
+             \n\
+             name,team,ip,h,r,er,bb,k,hr,era\n\
+             Dave Stewart,Oakland Athletics,7.0,6,2,2,1,8,1,3.21\n"
+        );
+    }
+
+    #[test]
+    fn test_game_stats_to_markdown() {
+        let api = MlbApi::new();
+        let boxscore = serde_json::json!({
+            "teams": {
+                "away": {
+                    "team": {"name": "Seattle Mariners"},
+                    "batters": [115135],
+                    "pitchers": [],
+                    "players": {
+                        "ID115135": {
+                            "person": {"fullName": "Ken | Griffey"},
+                            "stats": {"batting": {"atBats": 4, "hits": 2, "runs": 1, "homeRuns": 1, "rbi": 3, "avg": ".284"}}
+                        }
+                    }
+                },
+                "home": {
+                    "team": {"name": "Oakland Athletics"},
+                    "batters": [],
+                    "pitchers": [450308],
+                    "players": {
+                        "ID450308": {
+                            "person": {"fullName": "Dave Stewart"},
+                            "stats": {"pitching": {
+                                "inningsPitched": "7.0", "hits": 6, "runs": 2, "earnedRuns": 2,
+                                "baseOnBalls": 1, "strikeOuts": 8, "homeRuns": 1, "era": "3.21", "whip": "1.00"
+                            }}
+                        }
+                    }
+                }
+            }
+        });
+        
+        let markdown = api.parse_game_stats(&boxscore, game_decisions(&boxscore)).unwrap().to_markdown();
+        assert_eq!(
+            markdown,
+            "### Seattle Mariners\n\
+             \n\
+             | NAME | AB | H | R | HR | RBI | AVG | OBP | SLG |\n\
+             |---|---|---|---|---|---|---|---|---|\n\
+             | Ken \\| Griffey | 4 | 2 | 1 | 1 | 3 | .284 | --- | --- |\n\
+             | TEAM | 4 | 2 | 1 | 1 | 3 | .500 | --- | --- |\n\
+             \n\
+             ### Oakland Athletics\n\
+             \n\
+             | NAME | IP | H | R | ER | BB | K | ERA | WHIP |\n\
+             |---|---|---|---|---|---|---|---|---|\n\
+             | Dave Stewart | 7.0 | 6 | 2 | 2 | 1 | 8 | 3.21 | 1.00 |\n\
+             | TEAM | 7.0 | 6 | 2 | 2 | 1 | 8 | 2.57 | 1.00 |\n"
+        );
+        
+        // Every table row has as many cells as the header, counting escaped pipes as text
+        for line in markdown.lines().filter(|line| line.starts_with('|')) {
+            assert_eq!(line.replace("\\|", "").matches('|').count(), 10, "{}", line);
+        }
+        
+        // The line score has a column per inning, with the home team's skipped at-bat as an X
+        let innings = mock_innings(GameState::Final, &[(Some(0), Some(2)), (Some(1), None)]);
+        assert_eq!(
+            innings.to_markdown(),
+            "| TEAM | 1 | 2 | R | H | E |\n\
+             |---|---|---|---|---|---|\n\
+             | TST | 0 | 1 | 1 | - | - |\n\
+             | TST | 2 | X | 2 | - | - |\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_batting_line_averages() {
+        let line = parse_batting_line("Test Batter".to_string(), &serde_json::json!({
+            "atBats": 0, "hits": 0, "avg": ".---", "obp": "1.000", "slg": ".000"
+        }));
+        assert_eq!(line.avg, None);
+        assert_eq!(line.obp.as_deref(), Some("1.000"));
+        
+        let line = parse_batting_line("Test Batter".to_string(), &serde_json::json!({"avg": ".287"}));
+        assert_eq!(line.avg.as_deref(), Some(".287"));
+    }
+
+    #[test]
+    fn test_parse_player_season_stats() {
+        let api = MlbApi::new();
+        let fixture = serde_json::json!({
+            "stats": [
+                {
+                    "type": {"displayName": "season"},
+                    "group": {"displayName": "pitching"},
+                    "splits": [{"season": "2024", "stat": {
+                        "gamesPlayed": 32, "inningsPitched": "198.2", "era": "2.95", "whip": "1.03", "strikeOuts": 225
+                    }}]
+                }
+            ]
+        });
+        
+        // A pitcher who never batted has no hitting line
+        let stats = api.parse_player_season_stats(&fixture, 543037, 2024);
+        assert!(stats.hitting.is_none());
+        let pitching = stats.pitching.as_ref().unwrap();
+        assert_eq!(pitching.strikeouts, 225);
+        assert_eq!(pitching.whip, "1.03");
+        
+        let display = stats.to_string();
+        let lines: Vec<&str> = display.lines().collect();
+        assert_eq!(lines[0], "2024 Season");
+        assert_eq!(lines[1], "  PITCHING:");
+        assert_eq!(lines[2], "    G    IP     ERA   WHIP  K  ");
+        assert_eq!(lines[4], "    32   198.2  2.95  1.03  225");
+        assert!(!display.contains("HITTING"));
+        
+        let hitter = serde_json::json!({
+            "stats": [
+                {
+                    "group": {"displayName": "hitting"},
+                    "splits": [{"stat": {
+                        "gamesPlayed": 157, "atBats": 597, "avg": ".287", "obp": ".359", "slg": ".523",
+                        "ops": ".882", "homeRuns": 33, "rbi": 98
+                    }}]
+                },
+                {"group": {"displayName": "pitching"}, "splits": []}
+            ]
+        });
+        let stats = api.parse_player_season_stats(&hitter, 545361, 2024);
+        assert!(stats.pitching.is_none());
+        assert!(stats.to_string().contains("    157  597  .287  .359  .523  .882  33  98 "));
+        
+        let stats = api.parse_player_season_stats(&serde_json::json!({}), 1, 2024);
+        assert_eq!(stats.to_string(), "2024 Season\n  No stats recorded.\n");
+    }
+
+    #[test]
+    fn test_era_plus() {
+        // Known seasons: a 2.95 ERA against a 4.08 league is a 138 ERA+
+        assert_eq!(era_plus(2.95, 4.08), Some(138));
+        assert_eq!(era_plus(4.08, 4.08), Some(100));
+        assert_eq!(era_plus(8.16, 4.08), Some(50));
+        
+        // A scoreless season is capped rather than infinite, and a missing league ERA gives nothing
+        assert_eq!(era_plus(0.0, 4.08), Some(999));
+        assert_eq!(era_plus(0.01, 4.08), Some(999));
+        assert_eq!(era_plus(3.50, 0.0), None);
+        assert_eq!(era_plus(f64::NAN, 4.08), None);
+    }
+
+    #[test]
+    fn test_ops_plus() {
+        // .359 OBP and .523 SLG against a .312/.399 league
+        assert_eq!(ops_plus(0.359, 0.523, 0.312, 0.399), Some(146));
+        assert_eq!(ops_plus(0.312, 0.399, 0.312, 0.399), Some(100));
+        
+        // A hitless season floors at zero instead of going negative
+        assert_eq!(ops_plus(0.0, 0.0, 0.312, 0.399), Some(0));
+        assert_eq!(ops_plus(0.359, 0.523, 0.0, 0.399), None);
+    }
+
+    #[test]
+    fn test_parse_league_averages() {
+        let api = MlbApi::new();
+        let fixture = serde_json::json!({
+            "stats": [
+                {
+                    "group": {"displayName": "hitting"},
+                    "splits": [
+                        {"stat": {"hits": 1400, "baseOnBalls": 500, "hitByPitch": 60, "sacFlies": 40, "atBats": 5500, "totalBases": 2200}},
+                        {"stat": {"hits": 1300, "baseOnBalls": 450, "hitByPitch": 50, "sacFlies": 40, "atBats": 5400, "totalBases": 2100}}
+                    ]
+                },
+                {
+                    "group": {"displayName": "pitching"},
+                    "splits": [
+                        {"stat": {"earnedRuns": 650, "inningsPitched": "1440.1"}},
+                        {"stat": {"earnedRuns": 660, "inningsPitched": "1439.2"}}
+                    ]
+                }
+            ]
+        });
+        
+        let league = api.parse_league_averages(&fixture, 2024).unwrap();
+        assert_eq!(league.season, 2024);
+        assert!((league.obp - 3760.0 / 12040.0).abs() < 1e-9);
+        assert!((league.slg - 4300.0 / 10900.0).abs() < 1e-9);
+        assert!((league.era - 9.0 * 1310.0 / 2880.0).abs() < 1e-4);
+        
+        assert!(api.parse_league_averages(&serde_json::json!({}), 2024).is_err());
+    }
+
+    #[test]
+    fn test_season_stats_with_league_averages() {
+        let league = LeagueAverages { season: 2024, obp: 0.312, slg: 0.399, era: 4.08 };
+        let stats = PlayerSeasonStats {
+            player_id: 1,
+            season: 2024,
+            hitting: Some(SeasonHittingStats::from_stat(&serde_json::json!({
+                "gamesPlayed": 157, "atBats": 597, "avg": ".287", "obp": ".359", "slg": ".523", "ops": ".882", "homeRuns": 33, "rbi": 98
+            }))),
+            pitching: Some(SeasonPitchingStats::from_stat(&serde_json::json!({
+                "gamesPlayed": 4, "inningsPitched": "6.0", "era": "2.95", "whip": "1.03", "strikeOuts": 7
+            }))),
+        }
+        .with_league_averages(&league);
+        assert_eq!(stats.hitting.as_ref().unwrap().ops_plus, Some(146));
+        assert_eq!(stats.pitching.as_ref().unwrap().era_plus, Some(138));
+        
+        let display = stats.to_string();
+        let lines: Vec<&str> = display.lines().collect();
+        assert_eq!(lines[2], "    G    AB   AVG   OBP   SLG   OPS   HR  RBI OPS+");
+        assert_eq!(lines[3], format!("    {}", "-".repeat(46)));
+        assert_eq!(lines[4], "    157  597  .287  .359  .523  .882  33  98  146");
+        
+        // Six innings is too few for ERA+ to mean much, so it's starred with a note
+        assert_eq!(lines[6], "    G    IP     ERA   WHIP  K   ERA+");
+        assert_eq!(lines[8], "    4    6.0    2.95  1.03  7   138*");
+        assert_eq!(lines[9], "  * Small sample (under 100 AB or 20 IP)");
+        
+        // A placeholder rate leaves the stat out
+        let mut stats = stats;
+        stats.pitching.as_mut().unwrap().era = "-.--".to_string();
+        let stats = stats.with_league_averages(&league);
+        assert_eq!(stats.pitching.as_ref().unwrap().era_plus, None);
+        assert!(!stats.to_string().contains("ERA+"));
+    }
+
+    #[test]
+    fn test_parse_player_splits() {
+        let api = MlbApi::new();
+        let fixture = serde_json::json!({
+            "stats": [
+                {
+                    "type": {"displayName": "statSplits"},
+                    "group": {"displayName": "hitting"},
+                    "splits": [
+                        {"split": {"code": "vl", "description": "vs Left"}, "stat": {
+                            "gamesPlayed": 60, "atBats": 150, "avg": ".240", "obp": ".310", "slg": ".400",
+                            "ops": ".710", "homeRuns": 5, "rbi": 20
+                        }},
+                        {"split": {"code": "vr", "description": "vs Right"}, "stat": {
+                            "gamesPlayed": 150, "atBats": 450, "avg": ".301", "obp": ".380", "slg": ".560",
+                            "ops": ".940", "homeRuns": 28, "rbi": 78
+                        }}
+                    ]
+                },
+                {
+                    "type": {"displayName": "statSplits"},
+                    "group": {"displayName": "pitching"},
+                    "splits": []
+                }
+            ]
+        });
+        
+        let splits = api.parse_player_splits(&fixture, 545361, 2024);
+        assert!(splits.pitching.is_none());
+        let hitting = splits.hitting.as_ref().unwrap();
+        assert_eq!(hitting.vs_left.avg, ".240");
+        assert_eq!(hitting.vs_right.home_runs, 28);
+        
+        let display = splits.to_string();
+        let lines: Vec<&str> = display.lines().collect();
+        assert_eq!(lines[0], "2024 Splits");
+        assert_eq!(lines[1], "  HITTING:");
+        assert_eq!(lines[2], "            G    AB   AVG   OBP   SLG   OPS   HR  RBI");
+        assert_eq!(lines[4], "    vs LHP  60   150  .240  .310  .400  .710  5   20 ");
+        assert_eq!(lines[5], "    vs RHP  150  450  .301  .380  .560  .940  28  78 ");
+        assert!(!display.contains("PITCHING"));
+        
+        // A reliever who never faced a left-handed batter gets a zero line on that side
+        let reliever = serde_json::json!({
+            "stats": [{
+                "group": {"displayName": "pitching"},
+                "splits": [{"split": {"code": "vr"}, "stat": {
+                    "gamesPlayed": 3, "inningsPitched": "2.1", "era": "3.86", "whip": "1.29", "strikeOuts": 4
+                }}]
+            }]
+        });
+        let splits = api.parse_player_splits(&reliever, 1, 2024);
+        assert!(splits.hitting.is_none());
+        let pitching = splits.pitching.as_ref().unwrap();
+        assert_eq!(pitching.vs_left.games, 0);
+        assert_eq!(pitching.vs_left.innings_pitched, "0.0");
+        let display = splits.to_string();
+        assert!(display.contains("    vs LHB  0    0.0    -.--  -.--  0  \n"));
+        assert!(display.contains("    vs RHB  3    2.1    3.86  1.29  4  \n"));
+        
+        let splits = api.parse_player_splits(&serde_json::json!({}), 1, 2024);
+        assert_eq!(splits.to_string(), "2024 Splits\n  No stats recorded.\n");
+    }
+
+    #[test]
+    fn test_parse_roster() {
+        let api = MlbApi::new();
+        let player = |id: u32, name: &str, number: &str, position: &str, status: &str| serde_json::json!({
+            "person": {"id": id, "fullName": name},
+            "jerseyNumber": number,
+            "position": {"abbreviation": position},
+            "status": {"code": "A", "description": status}
+        });
+        let fixture = serde_json::json!({
+            "roster": [
+                player(672282, "Andrew Benintendi", "23", "LF", "Active"),
+                player(669203, "Garrett Crochet", "45", "P", "Active"),
+                player(641313, "Korey Lee", "26", "C", "Active"),
+                player(650391, "Nicky Lopez", "8", "2B", "Active"),
+                player(673357, "Luis Robert Jr.", "88", "CF", "Injured 10-Day"),
+                player(660271, "Shohei Ohtani", "", "TWP", "Active")
+            ]
+        });
+        let roster = Roster { team_id: 145, season: 2024, players: api.parse_roster(&fixture) };
+        assert_eq!(roster.players.len(), 6);
+        assert_eq!(roster.players[0].player_id, 672282);
+        assert_eq!(roster.players[5].jersey_number, None);
+        
+        let output = format!("{}", roster);
+        assert!(output.contains("  Pitchers:\n    #45 P   Garrett Crochet\n  Catchers:"));
+        assert!(output.contains("  Infielders:\n     #8 2B  Nicky Lopez\n"));
+        assert!(output.contains("  Outfielders:\n    #23 LF  Andrew Benintendi\n    #88 CF  Luis Robert Jr. (Injured 10-Day)\n"));
+        assert!(output.ends_with("  Other:\n        TWP Shohei Ohtani\n"));
+    }
+
+    #[test]
+    fn test_parse_pitching_staff() {
+        let pitcher = |name: &str, position: &str, stat: serde_json::Value| serde_json::json!({
+            "person": {"fullName": name, "stats": [{"splits": [{"stat": stat}]}]},
+            "position": {"type": position}
+        });
+        let fixture = serde_json::json!({
+            "roster": [
+                pitcher("Long Reliever", "Pitcher", serde_json::json!({
+                    "gamesPitched": 40, "gamesStarted": 2, "wins": 3, "losses": 2, "saves": 1,
+                    "inningsPitched": "70.1", "era": "3.84"
+                })),
+                pitcher("Closer", "Pitcher", serde_json::json!({
+                    "gamesPitched": 60, "gamesStarted": 0, "wins": 4, "losses": 5, "saves": 31,
+                    "inningsPitched": "61.0", "era": "2.51"
+                })),
+                pitcher("Ace Starter", "Pitcher", serde_json::json!({
+                    "gamesPitched": 32, "gamesStarted": 32, "wins": 15, "losses": 8, "saves": 0,
+                    "inningsPitched": "198.2", "era": "2.95"
+                })),
+                pitcher("Back End Starter", "Pitcher", serde_json::json!({
+                    "gamesPitched": 20, "gamesStarted": 18, "wins": 6, "losses": 9, "saves": 0,
+                    "inningsPitched": "101.0", "era": "5.10"
+                })),
+                pitcher("Utility Infielder", "Infielder", serde_json::json!({
+                    "gamesPitched": 1, "inningsPitched": "1.0", "era": "9.00"
+                })),
+            ]
+        });
+        
+        let api = MlbApi::new();
+        let staff = PitchingStaff { team_id: 145, season: 2024, pitchers: api.parse_pitching_staff(&fixture) };
+        let names: Vec<&str> = staff.pitchers.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Ace Starter", "Back End Starter", "Long Reliever", "Closer"]);
+        assert_eq!(staff.pitchers[1].role, PitcherRole::Starter);
+        assert_eq!(staff.pitchers[2].role, PitcherRole::Reliever);
+        
+        let display = staff.to_string();
+        let lines: Vec<&str> = display.lines().collect();
+        assert_eq!(lines[0], "2024 Pitching Staff");
+        assert_eq!(lines[2], "  Ace Starter               SP    32  32    15-8   0  198.2  2.95");
+        assert_eq!(lines[5], "  Closer                    RP    60   0     4-5  31   61.0  2.51");
+        assert!(display.ends_with("Leaders: W: Ace Starter (15), L: Back End Starter (9), SV: Closer (31)\n"));
+        
+        assert!(api.parse_pitching_staff(&serde_json::json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_urls_with_fixed_clock() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 28).unwrap();
+        let api = MlbApi::with_clock(Arc::new(crate::clock::FixedClock(today)));
+        
+        // The team schedule defaults to the clock's current month
+        let url = api.team_schedule_url(145, None, None).unwrap();
+        assert!(url.ends_with("/schedule?teamId=145&startDate=2024-03-01&endDate=2024-03-31&sportId=1&hydrate=probablePitcher"));
+        
+        // A start date without an end runs to the end of the start's month
+        let url = api.team_schedule_url(145, Some("2024-04-01".to_string()), None).unwrap();
+        assert!(url.contains("startDate=2024-04-01&endDate=2024-04-30"));
+        
+        // Every 31-day month ends on the 31st, December included
+        for month in [1, 3, 5, 7, 8, 10, 12] {
+            let today = NaiveDate::from_ymd_opt(2024, month, 15).unwrap();
+            let api = MlbApi::with_clock(Arc::new(crate::clock::FixedClock(today)));
+            let url = api.team_schedule_url(145, None, None).unwrap();
+            assert!(url.contains(&format!("startDate=2024-{:02}-01&endDate=2024-{:02}-31&", month, month)), "{}", url);
+        }
+        
+        // February ends on the 29th in a leap year and the 28th otherwise
+        for (today, end) in [((2024, 2, 10), "2024-02-29"), ((2023, 2, 10), "2023-02-28")] {
+            let today = NaiveDate::from_ymd_opt(today.0, today.1, today.2).unwrap();
+            let api = MlbApi::with_clock(Arc::new(crate::clock::FixedClock(today)));
+            let url = api.team_schedule_url(145, None, None).unwrap();
+            assert!(url.contains(&format!("&endDate={}&", end)));
+        }
+        
+        let yesterday = api.clock.today().pred_opt().unwrap();
+        let url = api.games_by_date_url(yesterday).unwrap();
+        assert!(url.contains("/schedule?sportId=1&date=2024-03-27&"));
+    }
+
+    #[test]
+    fn test_parse_scorebug() {
+        let api = MlbApi::new();
+        let feed = |state: &str, linescore: Value| serde_json::json!({
+            "gameData": {
+                "status": {"abstractGameState": state},
+                "datetime": {"dateTime": "2024-03-28T23:05:00Z"},
+                "teams": {"away": {"abbreviation": "CWS"}, "home": {"abbreviation": "CHC"}}
+            },
+            "liveData": {"linescore": linescore}
+        });
+        
+        let live = api.parse_scorebug(&feed("Live", serde_json::json!({
+            "currentInning": 7,
+            "inningState": "Bottom",
+            "outs": 2,
+            "teams": {"away": {"runs": 3}, "home": {"runs": 5}}
+        })));
+        assert_eq!(live.away, "CWS");
+        assert_eq!(live.away_score, Some(3));
+        assert_eq!(live.home_score, Some(5));
+        assert_eq!(live.state, ScorebugState::Live { period: "B7".to_string(), detail: Some("2 out".to_string()) });
+        
+        // No outs are shown between half-innings
+        let middle = api.parse_scorebug(&feed("Live", serde_json::json!({"currentInning": 4, "inningState": "Middle", "outs": 3})));
+        assert_eq!(middle.state, ScorebugState::Live { period: "M4".to_string(), detail: None });
+        
+        let final_bug = api.parse_scorebug(&feed("Final", serde_json::json!({"teams": {"away": {"runs": 3}, "home": {"runs": 5}}})));
+        assert_eq!(final_bug.state, ScorebugState::Final);
+        assert!(final_bug.to_string().contains("| CHC   5 |"));
+        
+        let scheduled = api.parse_scorebug(&feed("Preview", serde_json::json!({})));
+        assert!(matches!(scheduled.state, ScorebugState::Scheduled(_)));
+        assert!(scheduled.away_score.is_none());
+    }
+
+    #[test]
+    fn test_parse_live_data() {
+        let api = MlbApi::new();
+        let feed = |state: &str, live_data: Value| serde_json::json!({
+            "gameData": {
+                "gamePk": 745444,
+                "status": {"abstractGameState": state},
+                "teams": {"away": {"id": 145, "name": "Chicago White Sox"}, "home": {"id": 112, "name": "Chicago Cubs"}}
+            },
+            "liveData": live_data
+        });
+        
+        let live = api.parse_live_data(&feed("Live", serde_json::json!({
+            "linescore": {
+                "currentInning": 7,
+                "inningState": "Bottom",
+                "balls": 0, "strikes": 0, "outs": 0,
+                "offense": {
+                    "batter": {"fullName": "Previous Batter"},
+                    "first": {"id": 1, "fullName": "Ian Happ"},
+                    "third": {"id": 2, "fullName": "Dansby Swanson"}
+                },
+                "defense": {"pitcher": {"fullName": "Garrett Crochet"}}
+            },
+            "plays": {"currentPlay": {
+                "count": {"balls": 2, "strikes": 1, "outs": 1},
+                "matchup": {"batter": {"fullName": "Seiya Suzuki"}, "pitcher": {"fullName": "Garrett Crochet"}}
+            }}
+        }))).unwrap();
+        let LiveData::InProgress(ref state) = live else { panic!("expected live data, got {:?}", live) };
+        assert_eq!((state.inning, state.half.as_str()), (7, "Bottom"));
+        assert_eq!((state.balls, state.strikes, state.outs), (2, 1, 1));
+        assert_eq!(state.on_first.as_deref(), Some("Ian Happ"));
+        assert!(state.on_second.is_none());
+        assert_eq!(state.batter.as_deref(), Some("Seiya Suzuki"));
+        assert_eq!(
+            live.to_string(),
+            "Bottom 7th, 1 out\nCount: 2-1\nRunners: 1st (Ian Happ), 3rd (Dansby Swanson)\n\
+             At bat: Seiya Suzuki\nPitching: Garrett Crochet\n"
+        );
+        
+        // Between half-innings there's no count or matchup to show
+        let middle = api.parse_live_data(&feed("Live", serde_json::json!({
+            "linescore": {"currentInning": 3, "inningState": "Middle", "outs": 3}
+        }))).unwrap();
+        assert_eq!(middle.to_string(), "Middle 3rd\nRunners: bases empty\n");
+        
+        // Finished and upcoming games have no live data rather than a stale situation
+        let final_game = api.parse_live_data(&feed("Final", serde_json::json!({
+            "linescore": {"currentInning": 9, "inningState": "Bottom", "outs": 3}
+        }))).unwrap();
+        assert!(matches!(final_game, LiveData::NotLive(GameState::Final)));
+        assert_eq!(final_game.to_string(), "No live data: the game is Final\n");
+        let scheduled = api.parse_live_data(&feed("Preview", serde_json::json!({}))).unwrap();
+        assert!(matches!(scheduled, LiveData::NotLive(GameState::Scheduled)));
+    }
+
+    #[test]
+    fn test_pitching_decisions() {
+        let fixture = serde_json::json!({
+            "team": {"name": "Chicago Cubs"},
+            "teamStats": {},
+            "batters": [],
+            "pitchers": [1, 2, 3, 4],
+            "players": {
+                "ID1": {
+                    "person": {"fullName": "John Smith"},
+                    "stats": {"pitching": {"inningsPitched": "7.0", "strikeOuts": 8, "note": "(W, 5-2)"}}
+                },
+                "ID2": {
+                    "person": {"fullName": "Carl Middle"},
+                    "stats": {"pitching": {"inningsPitched": "1.0", "holds": 1}}
+                },
+                "ID3": {
+                    "person": {"fullName": "Bob Jones"},
+                    "stats": {"pitching": {"inningsPitched": "1.0", "strikeOuts": 2, "note": "(S, 12)"}}
+                },
+                "ID4": {
+                    "person": {"fullName": "Mop Up"},
+                    "stats": {"pitching": {"inningsPitched": "0.0"}}
+                }
+            }
+        });
+        
+        let api = MlbApi::new();
+        let stats = api.extract_team_stats(&fixture, &HashMap::new()).unwrap();
+        let decisions: Vec<Option<&str>> = stats.pitchers.iter().map(|p| p.decision.as_deref()).collect();
+        assert_eq!(decisions, vec![Some("W"), Some("HLD"), Some("SV"), None]);
+        
+        assert!(format!("{}", stats.pitchers[0]).starts_with("John Smith (W) "));
+        assert!(format!("{}", stats.pitchers[2]).starts_with("Bob Jones (SV) "));
+        assert!(format!("{}", stats.pitchers[3]).starts_with("Mop Up "));
+        
+        // Long names are shortened so the decision still fits the column
+        let long = PlayerPitchingStats {
+            name: "Christopher Longlastname-Smith".to_string(),
+            innings_pitched: "9.0".to_string(),
+            strikeouts: 10,
+            earned_runs: 0,
+            hits_allowed: 3,
+            runs_allowed: 0,
+            walks: 1,
+            home_runs_allowed: 0,
+            era: None,
+            whip: None,
+            decision: Some("W".to_string()),
+        };
+        let display = format!("{}", long);
+        assert!(display.starts_with("Christopher Longla... (W) "));
+    }
+
+    #[test]
+    fn test_game_decisions() {
+        let pitching = |id: u64, name: &str| (format!("ID{}", id), serde_json::json!({
+            "person": {"id": id, "fullName": name},
+            "stats": {"pitching": {"inningsPitched": "3.0"}}
+        }));
+        let team = |name: &str, pitchers: Vec<(String, Value)>| serde_json::json!({
+            "team": {"name": name},
+            "batters": [],
+            "pitchers": pitchers.iter().map(|(key, _)| key[2..].parse::<u64>().unwrap()).collect::<Vec<_>>(),
+            "players": pitchers.into_iter().collect::<serde_json::Map<String, Value>>()
+        });
+        let boxscore = serde_json::json!({
+            "teams": {
+                "away": team("New York Yankees", vec![pitching(543037, "Gerrit Cole"), pitching(622663, "Luis Gil")]),
+                "home": team("Boston Red Sox", vec![pitching(608379, "Brayan Bello"), pitching(519242, "Kenley Jansen")])
+            },
+            "decisions": {
+                "winner": {"id": 543037, "fullName": "Gerrit Cole"},
+                "loser": {"id": 608379, "fullName": "Brayan Bello"},
+                "save": {"id": 622663, "fullName": "Luis Gil"}
+            }
+        });
+        
+        let api = MlbApi::new();
+        let stats = api.parse_game_stats(&boxscore, game_decisions(&boxscore)).unwrap();
+        let name_column = |pitcher: &PlayerPitchingStats| pitcher.row(20)[..20].trim_end().to_string();
+        assert!(name_column(&stats.away_team_stats.pitchers[0]).ends_with(" (W)"));
+        assert!(name_column(&stats.away_team_stats.pitchers[1]).ends_with(" (SV)"));
+        assert!(name_column(&stats.home_team_stats.pitchers[0]).ends_with(" (L)"));
+        assert_eq!(stats.home_team_stats.pitchers[1].decision, None);
+    }
+
+    #[test]
+    fn test_parse_game_from_value() {
+        // Trimmed from a saved feed/live response
+        let feed = serde_json::json!({
+            "gamePk": 745444,
+            "gameData": {
+                "game": {"pk": 745444, "doubleHeader": "N", "gameNumber": 1, "seriesGameNumber": 1, "gamesInSeries": 3},
+                "gamePk": 745444,
+                "gameDate": "2024-03-28T20:10:00Z",
+                "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                "teams": {
+                    "away": {"id": 145, "name": "Chicago White Sox", "abbreviation": "CWS", "locationName": "Chicago"},
+                    "home": {"id": 112, "name": "Chicago Cubs", "abbreviation": "CHC", "locationName": "Chicago"}
+                },
+                "venue": {"id": 17, "name": "Wrigley Field"}
+            },
+            "liveData": {
+                "linescore": {"teams": {"away": {"runs": 3, "hits": 7}, "home": {"runs": 5, "hits": 9}}}
+            }
+        });
+        
+        let game = MlbApi::new().parse_game_from_value(&feed).unwrap();
+        assert_eq!(game.game_pk, 745444);
+        assert_eq!(game.status, GameState::Final);
+        assert_eq!(game.teams.away.team.abbreviation.as_deref(), Some("CWS"));
+        assert_eq!(game.teams.away.score, Some(3));
+        assert_eq!(game.teams.home.score, Some(5));
+        assert_eq!(game.games_in_series, Some(3));
+        assert_eq!(game.venue.as_ref().map(|v| v.name.as_str()), Some("Wrigley Field"));
+        assert_eq!(game.winner().map(|t| t.id), Some(112));
+        
+        assert!(MlbApi::new().parse_game_from_value(&serde_json::json!({})).is_err());
+    }
+
+    #[test]
+    fn test_name_width() {
+        let batter = PlayerBattingStats {
+            name: "Christopher Longlastname-Smith".to_string(),
+            hits: 2,
+            at_bats: 4,
+            home_runs: 1,
+            rbi: 3,
+            runs: 1,
+            doubles: 0,
+            triples: 0,
+            stolen_bases: 0,
+            walks: 0,
+            strikeouts: 1,
+            avg: Some(".300".to_string()),
+            obp: None,
+            slg: None,
+            position: None,
+            batting_order: None,
+        };
+        assert!(batter.row(25).starts_with("Christopher Longlastna... 4 "));
+        assert!(batter.row(30).starts_with("Christopher Longlastname-Smith 4 "));
+        
+        // The header lines up with the rows at any width
+        let team = TeamStats {
+            team_name: "Test Team".to_string(),
+            batting: None,
+            pitching: None,
+            batters: vec![batter],
+            pitchers: Vec::new(),
+        };
+        let table = team.table(30);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[6], "    Christopher Longlastname-Smith 4       2   1   1   3   .300  ---   ---  ");
+        assert_eq!(lines[4].find("AB"), lines[6].find(" 4 ").map(|i| i + 1));
+        assert_eq!(lines[5].trim(), "-".repeat(72));
+        
+        // A value too long for its column widens it, keeping the columns after it aligned
+        let mut team = team;
+        team.batters[0].home_runs = 1000;
+        let table = team.table(30);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[4], "    NAME                           AB      H   R   HR   RBI AVG   OBP   SLG  ");
+        assert_eq!(lines[6], "    Christopher Longlastname-Smith 4       2   1   1000 3   .300  ---   ---  ");
+        assert_eq!(lines[8], "    TEAM                           4       2   1   1000 3   .500  ---   ---  ");
+    }
+}
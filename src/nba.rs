@@ -1,9 +1,35 @@
 use anyhow::{anyhow, Result};
-use reqwest::{Client, header};
+use reqwest::{Client, StatusCode, header};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fmt;
-use chrono::{Local, NaiveDate};
-use std::sync::OnceLock;
+use crate::mlb::GameState;
+use crate::utils::{determine_winner, format_decimal_stat, format_record, parse_iso8601_date, truncate_string, Table};
+use chrono::{Datelike, NaiveDate};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use crate::cache::DiskCache;
+use crate::calendar::CalendarEvent;
+use crate::league::{DetailOptions, DetailSection, League};
+use crate::clock::{Clock, SystemClock};
+use crate::color::{paint, Style};
+use crate::config::get_config;
+use crate::error::SportError;
+use crate::order::{in_order, separator, TeamOrdered};
+use crate::pagination::{paginate, paginate_up_to, Page};
+use crate::scorebug::{self, Scorebug, ScorebugState};
+use crate::types::{GameId, TeamId};
+use std::time::{Duration, Instant};
+
+// Injury reports change daily, so cached responses are only reused briefly
+const INJURIES_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+// Shortest player search worth sending; balldontlie matches on any part of a name
+const MIN_SEARCH_LEN: usize = 2;
+
+// Injury reports keyed by team filter, with the time they were fetched
+type InjuriesCache = HashMap<Option<u32>, (Instant, Vec<PlayerInjury>)>;
 
 // Base URL for the balldontlie API
 fn get_nba_api_base_url() -> String {
@@ -12,29 +38,44 @@ fn get_nba_api_base_url() -> String {
 
 // API key for the balldontlie API
 fn get_nba_api_key() -> String {
-    crate::config::get_config().nba_api_key.clone()
+    crate::config::nba_api_key().to_string()
 }
 
 /// NBA API client
 pub struct NbaApi {
     client: Client,
+    clock: Arc<dyn Clock>,
+    injuries_cache: Mutex<InjuriesCache>,
+    // The team list only changes between seasons, so it's fetched once per client
+    teams_cache: OnceLock<Vec<Team>>,
+    cache: Option<DiskCache>,
 }
 
 impl NbaApi {
-    /// Create a new NBA API client
-    pub fn new() -> Self {
-        let client = create_client().expect("Failed to create HTTP client");
-        Self { client }
+    /// Create a new NBA API client, or `SportError::MissingApiKey` if no API key is configured
+    pub fn new() -> Result<Self> {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+    
+    /// Create a new NBA API client that uses the given clock for "today"
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Result<Self> {
+        Ok(Self {
+            client: create_client()?,
+            clock,
+            injuries_cache: Mutex::new(HashMap::new()),
+            teams_cache: OnceLock::new(),
+            cache: DiskCache::from_config().filter(|_| get_config().cache_enabled),
+        })
     }
     
     /// Get team information by ID
     pub async fn get_team(&self, team_id: u32) -> Result<Team> {
         let url = format!("{}/teams/{}", get_nba_api_base_url(), team_id);
-        let response = self.client.get(&url)
-            .header(header::AUTHORIZATION, get_nba_api_key())
-            .send()
-            .await?;
+        let response = self.fetch(&url).await?;
             
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(SportError::NotFound(format!("Team {}", team_id)).into());
+        }
         if !response.status().is_success() {
             return Err(anyhow!("Failed to get team: HTTP {}", response.status()));
         }
@@ -43,14 +84,58 @@ impl NbaApi {
         Ok(team_response.data)
     }
     
+    /// Get all current NBA teams, sorted by division then name
+    pub async fn get_all_teams(&self) -> Result<Vec<Team>> {
+        if let Some(teams) = self.teams_cache.get() {
+            return Ok(teams.clone());
+        }
+
+        let url = format!("{}/teams", get_nba_api_base_url());
+        let response = self.fetch(&url).await?;
+            
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to get teams: HTTP {}", response.status()));
+        }
+        
+        let teams_response: SingleResponse<Vec<Team>> = response.json().await?;
+        
+        // Defunct franchises are listed without a division
+        let mut teams: Vec<Team> = teams_response.data
+            .into_iter()
+            .filter(|team| !team.division.is_empty())
+            .collect();
+        teams.sort_by(|a, b| a.division.cmp(&b.division).then_with(|| a.full_name.cmp(&b.full_name)));
+        
+        Ok(self.teams_cache.get_or_init(|| teams).clone())
+    }
+    
+    /// Get game information by ID
+    pub async fn get_game(&self, game_id: u32) -> Result<Game> {
+        let url = format!("{}/games/{}", get_nba_api_base_url(), game_id);
+        let response = self.fetch(&url).await?;
+            
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to get game: HTTP {}", response.status()));
+        }
+        
+        let game_response: SingleResponse<Game> = response.json().await?;
+        Ok(game_response.data)
+    }
+    
+    /// Get a game's points by quarter (and overtime) for each team, or only the final totals if
+    /// the API doesn't have them
+    pub async fn get_game_line_score(&self, game_id: u32) -> Result<LineScore> {
+        Ok(self.get_game(game_id).await?.line_score())
+    }
+    
     /// Get player information by ID
     pub async fn get_player(&self, player_id: u32) -> Result<Player> {
         let url = format!("{}/players/{}", get_nba_api_base_url(), player_id);
-        let response = self.client.get(&url)
-            .header(header::AUTHORIZATION, get_nba_api_key())
-            .send()
-            .await?;
+        let response = self.fetch(&url).await?;
             
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(SportError::NotFound(format!("Player {}", player_id)).into());
+        }
         if !response.status().is_success() {
             return Err(anyhow!("Failed to get player: HTTP {}", response.status()));
         }
@@ -59,38 +144,69 @@ impl NbaApi {
         Ok(player_response.data)
     }
     
+    /// Find players whose first or last name matches a search, following every page of results
+    pub async fn search_players(&self, query: &str) -> Result<Vec<Player>> {
+        let query = query.trim();
+        if query.chars().count() < MIN_SEARCH_LEN {
+            return Err(anyhow!("Player search needs at least {} characters, got '{}'", MIN_SEARCH_LEN, query));
+        }
+        
+        let url = reqwest::Url::parse_with_params(
+            &format!("{}/players", get_nba_api_base_url()),
+            &[("search", query), ("per_page", "100")],
+        )?.to_string();
+        paginate(|cursor| with_cursor(&url, cursor), |url| self.fetch_page(url, "players")).await
+    }
+    
+    /// Get a player's per-game averages for a season, or None if they didn't play that season
+    pub async fn get_player_season_averages(&self, player_id: u32, season: u32) -> Result<Option<SeasonAverages>> {
+        let url = format!(
+            "{}/season_averages?season={}&player_ids[]={}",
+            get_nba_api_base_url(), season, player_id
+        );
+        let response = self.fetch(&url).await?;
+            
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to get season averages: HTTP {}", response.status()));
+        }
+        
+        let averages_response: SingleResponse<Vec<SeasonAverages>> = response.json().await?;
+        Ok(averages_response.data.into_iter().next())
+    }
+    
+    /// Get the regular season standings for both conferences
+    pub async fn get_standings(&self, season: u32) -> Result<Standings> {
+        let url = format!("{}/standings?season={}", get_nba_api_base_url(), season);
+        let response = self.fetch(&url).await?;
+            
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to get standings: HTTP {}", response.status()));
+        }
+        
+        let standings_response: SingleResponse<Vec<StandingsRow>> = response.json().await?;
+        Ok(group_standings(season, standings_response.data))
+    }
+    
     /// Get all NBA games for today
     pub async fn get_todays_games(&self) -> Result<Vec<Game>> {
-        let today = Local::now().date_naive();
+        let today = self.clock.today();
         self.get_games_by_date(today).await
     }
     
     /// Get all NBA games from yesterday
     pub async fn get_yesterdays_games(&self) -> Result<Vec<Game>> {
-        let yesterday = Local::now().date_naive().pred_opt().ok_or_else(|| anyhow!("Failed to calculate yesterday's date"))?;
+        let yesterday = self.clock.today().pred_opt().ok_or_else(|| anyhow!("Failed to calculate yesterday's date"))?;
         self.get_games_by_date(yesterday).await
     }
     
     /// Get games by date
     pub async fn get_games_by_date(&self, date: NaiveDate) -> Result<Vec<Game>> {
-        let date_str = date.format("%Y-%m-%d").to_string();
-        let url = format!("{}/games?dates[]={}", get_nba_api_base_url(), date_str);
-        
-        let response = self.client.get(&url)
-            .header(header::AUTHORIZATION, get_nba_api_key())
-            .send()
-            .await?;
-            
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to get games: HTTP {}", response.status()));
-        }
-        
-        let games_response: PaginatedResponse<Game> = response.json().await?;
-        Ok(games_response.data)
+        let url = games_by_date_url(date);
+        paginate(|cursor| with_cursor(&url, cursor), |url| self.fetch_page(url, "games")).await
     }
     
-    /// Get team games
-    pub async fn get_team_games(&self, team_id: u32, start_date: Option<String>, end_date: Option<String>) -> Result<Vec<Game>> {
+    /// Get team games, stopping once `max_games` have been fetched
+    pub async fn get_team_games(&self, team_id: u32, start_date: Option<String>, end_date: Option<String>, max_games: Option<usize>) -> Result<TeamGames> {
         let mut url = format!("{}/games?team_ids[]={}", get_nba_api_base_url(), team_id);
         
         if let Some(start) = start_date {
@@ -101,54 +217,214 @@ impl NbaApi {
             url.push_str(&format!("&end_date={}", end));
         }
         
-        let response = self.client.get(&url)
-            .header(header::AUTHORIZATION, get_nba_api_key())
-            .send()
-            .await?;
-            
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to get team games: HTTP {}", response.status()));
-        }
+        // Remember the last page's size so its Meta can be handed back with the games
+        let per_page = AtomicU32::new(0);
+        let paged = paginate_up_to(
+            |cursor| with_cursor(&url, cursor),
+            |url| {
+                let per_page = &per_page;
+                async move {
+                    let page: PaginatedResponse<Game> = self.fetch_paginated(url, "team games").await?;
+                    per_page.store(page.meta.per_page, Ordering::Relaxed);
+                    Ok(Page { items: page.data, next: page.meta.next_cursor })
+                }
+            },
+            max_games,
+        ).await?;
         
-        let games_response: PaginatedResponse<Game> = response.json().await?;
-        Ok(games_response.data)
+        Ok(TeamGames {
+            games: paged.items,
+            meta: Meta { next_cursor: paged.next, per_page: per_page.into_inner() },
+            truncated: paged.truncated,
+        })
     }
     
     /// Get player stats for a game
     pub async fn get_game_player_stats(&self, game_id: u32) -> Result<Vec<PlayerStats>> {
         let url = format!("{}/stats?game_ids[]={}", get_nba_api_base_url(), game_id);
+        paginate(|cursor| with_cursor(&url, cursor), |url| self.fetch_page(url, "player stats")).await
+    }
+    
+    /// Get player statistics for a finished game, reading each page through the response cache
+    pub async fn get_final_game_player_stats(&self, game_id: u32) -> Result<Vec<PlayerStats>> {
+        let url = format!("{}/stats?game_ids[]={}", get_nba_api_base_url(), game_id);
+        paginate(|cursor| with_cursor(&url, cursor), |url| self.fetch_final_page(url, "player stats")).await
+    }
+    
+    /// Get current player injuries, optionally limited to a single team
+    pub async fn get_player_injuries(&self, team_id: Option<u32>) -> Result<Vec<PlayerInjury>> {
+        if let Some((fetched_at, injuries)) = self.injuries_cache.lock().unwrap().get(&team_id) {
+            if fetched_at.elapsed() < INJURIES_CACHE_TTL {
+                return Ok(injuries.clone());
+            }
+        }
         
-        let response = self.client.get(&url)
-            .header(header::AUTHORIZATION, get_nba_api_key())
-            .send()
-            .await?;
+        let mut url = format!("{}/player_injuries?per_page=100", get_nba_api_base_url());
+        if let Some(id) = team_id {
+            url.push_str(&format!("&team_ids[]={}", id));
+        }
+        
+        let injuries: Vec<PlayerInjury> =
+            paginate(|cursor| with_cursor(&url, cursor), |url| self.fetch_page(url, "player injuries")).await?;
+        self.injuries_cache
+            .lock()
+            .unwrap()
+            .insert(team_id, (Instant::now(), injuries.clone()));
+        Ok(injuries)
+    }
+    
+    // Send an authorized GET request, or answer it from a recorded fixture when replaying
+    async fn fetch(&self, url: &str) -> Result<reqwest::Response> {
+        match get_config().replay_dir {
+            Some(ref dir) => crate::replay::response(dir, "nba", &get_nba_api_base_url(), url),
+            None => {
+                let response = crate::retry::send_with_retry(get_config().http_retries, || {
+                    self.client.get(url)
+                        .header(header::AUTHORIZATION, get_nba_api_key())
+                        .send()
+                }).await?;
+                crate::replay::dump(get_config().dump_raw_dir.as_deref(), "nba", &get_nba_api_base_url(), url, response).await
+            }
+        }
+    }
+    
+    /// Helper method to fetch one page of a cursor-paginated endpoint
+    async fn fetch_page<T: DeserializeOwned>(&self, url: String, what: &str) -> Result<Page<T, u32>> {
+        let page = self.fetch_paginated(url, what).await?;
+        Ok(Page { items: page.data, next: page.meta.next_cursor })
+    }
+    
+    // Like `fetch_page`, but through the response cache, storing a page once every stat line on
+    // it says the game is final
+    async fn fetch_final_page<T: DeserializeOwned>(&self, url: String, what: &str) -> Result<Page<T, u32>> {
+        let response = crate::cache::fetch_final(self.cache.as_ref(), &url, stats_are_final, self.fetch(&url)).await?;
+        
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to get {}: HTTP {}", what, response.status()));
+        }
+        
+        let page: PaginatedResponse<T> = response.json().await?;
+        Ok(Page { items: page.data, next: page.meta.next_cursor })
+    }
+    
+    // Fetch one page of a cursor-paginated endpoint along with its Meta
+    async fn fetch_paginated<T: DeserializeOwned>(&self, url: String, what: &str) -> Result<PaginatedResponse<T>> {
+        let response = self.fetch(&url).await?;
             
         if !response.status().is_success() {
-            return Err(anyhow!("Failed to get player stats: HTTP {}", response.status()));
+            return Err(anyhow!("Failed to get {}: HTTP {}", what, response.status()));
         }
         
-        let stats_response: PaginatedResponse<PlayerStats> = response.json().await?;
-        Ok(stats_response.data)
+        Ok(response.json().await?)
+    }
+}
+
+// Whether a page of a game's stat lines describes a finished game. An empty page says nothing
+// about the game, so it isn't taken as final.
+fn stats_are_final(data: &serde_json::Value) -> bool {
+    data["data"].as_array().is_some_and(|lines| {
+        !lines.is_empty() && lines.iter().all(|line| line["game"]["status"] == "Final")
+    })
+}
+
+// Add the pagination cursor to a URL that already has a query string
+fn with_cursor(url: &str, cursor: Option<&u32>) -> String {
+    match cursor {
+        Some(cursor) => format!("{}&cursor={}", url, cursor),
+        None => url.to_string(),
+    }
+}
+
+// balldontlie sometimes returns null where a value is expected (e.g. a player's position).
+// Fields using this fall back to their default instead of failing the whole response.
+// Unknown fields are ignored, so newly added API fields don't break parsing either.
+fn null_as_default<'de, D, T>(deserializer: D) -> std::result::Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+// Split standings rows into the two conferences, each sorted by conference rank, filling in
+// games back from the conference leader's record when the API leaves it out
+fn group_standings(season: u32, rows: Vec<StandingsRow>) -> Standings {
+    let conference = |name: &str| {
+        let mut teams: Vec<&StandingsRow> = rows.iter().filter(|row| row.team.conference == name).collect();
+        // Teams without a rank go after the ranked ones, best record first
+        teams.sort_by(|a, b| {
+            let rank = |row: &StandingsRow| row.conference_rank.unwrap_or(u32::MAX);
+            rank(a).cmp(&rank(b)).then_with(|| row_pct(b).total_cmp(&row_pct(a)))
+        });
+        
+        let leader = teams.first().map(|leader| (leader.wins as f64, leader.losses as f64));
+        teams
+            .iter()
+            .enumerate()
+            .map(|(index, row)| StandingsRecord {
+                team: row.team.clone(),
+                wins: row.wins,
+                losses: row.losses,
+                conference_rank: row.conference_rank.unwrap_or(index as u32 + 1),
+                games_back: row.games_behind.unwrap_or_else(|| {
+                    leader.map_or(0.0, |(wins, losses)| ((wins - row.wins as f64) + (row.losses as f64 - losses)) / 2.0)
+                }),
+            })
+            .collect()
+    };
+    
+    Standings { season, east: conference("East"), west: conference("West") }
+}
+
+// Winning percentage of a standings row, counting a team that hasn't played as .000
+fn row_pct(row: &StandingsRow) -> f64 {
+    let games = row.wins + row.losses;
+    if games == 0 { 0.0 } else { row.wins as f64 / games as f64 }
+}
+
+/// The NBA season in progress (or most recently finished) on a date, named for the year it began
+pub fn current_season(date: NaiveDate) -> u32 {
+    // Seasons tip off in October
+    if date.month() >= 10 {
+        date.year() as u32
+    } else {
+        date.year() as u32 - 1
     }
 }
 
+// Build the games URL for all games on a date
+fn games_by_date_url(date: NaiveDate) -> String {
+    format!("{}/games?dates[]={}", get_nba_api_base_url(), date.format("%Y-%m-%d"))
+}
+
 // Create a default instance for convenience
 static NBA_API_INSTANCE: OnceLock<NbaApi> = OnceLock::new();
 
-/// Get the NBA API instance
-fn get_nba_api() -> &'static NbaApi {
-    NBA_API_INSTANCE.get_or_init(|| NbaApi::new())
+/// The shared NBA API client behind the free functions, initialized on first use. Fails
+/// with `SportError::MissingApiKey` until an API key is configured.
+pub fn get_nba_api() -> Result<&'static NbaApi> {
+    if let Some(api) = NBA_API_INSTANCE.get() {
+        return Ok(api);
+    }
+    let api = NbaApi::new()?;
+    Ok(NBA_API_INSTANCE.get_or_init(|| api))
 }
 
 /// NBA Team information
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct Team {
     pub id: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub abbreviation: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub city: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub conference: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub division: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub full_name: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub name: String,
 }
 
@@ -166,12 +442,16 @@ impl fmt::Display for Team {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Player {
     pub id: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub first_name: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub last_name: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub position: String,
     pub height_feet: Option<u32>,
     pub height_inches: Option<u32>,
     pub weight_pounds: Option<u32>,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub team: Team,
 }
 
@@ -195,43 +475,450 @@ impl fmt::Display for Player {
     }
 }
 
+impl Player {
+    /// One-line listing for search results, e.g. "237: LeBron James — Los Angeles Lakers (F)"
+    pub fn search_line(&self) -> String {
+        let team = if self.team.full_name.is_empty() { "No team" } else { &self.team.full_name };
+        let position = if self.position.is_empty() { "-" } else { &self.position };
+        format!("{}: {} {} — {} ({})", self.id, self.first_name, self.last_name, team, position)
+    }
+}
+
+/// NBA player's per-game averages for a season
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SeasonAverages {
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub pts: f64,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub reb: f64,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub ast: f64,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub stl: f64,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub blk: f64,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub fg_pct: f64,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub fg3_pct: f64,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub ft_pct: f64,
+    /// Minutes per game as "MM:SS"
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub min: String,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub games_played: u32,
+}
+
+impl fmt::Display for SeasonAverages {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Shooting percentages come back as fractions, e.g. 0.54 for 54%
+        write!(
+            f,
+            "PPG: {:.1}, RPG: {:.1}, APG: {:.1}, SPG: {:.1}, BPG: {:.1}, FG%: {:.1}, 3P%: {:.1}, FT%: {:.1}, MIN: {}, GP: {}",
+            self.pts,
+            self.reb,
+            self.ast,
+            self.stl,
+            self.blk,
+            self.fg_pct * 100.0,
+            self.fg3_pct * 100.0,
+            self.ft_pct * 100.0,
+            self.min,
+            self.games_played
+        )
+    }
+}
+
+// A team's line in a standings response
+#[derive(Debug, Deserialize)]
+struct StandingsRow {
+    team: Team,
+    #[serde(default, deserialize_with = "null_as_default")]
+    wins: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
+    losses: u32,
+    #[serde(default)]
+    conference_rank: Option<u32>,
+    #[serde(default, alias = "games_back")]
+    games_behind: Option<f64>,
+}
+
+/// A team's line in the conference standings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandingsRecord {
+    pub team: Team,
+    pub wins: u32,
+    pub losses: u32,
+    pub conference_rank: u32,
+    /// Games behind the conference leader (0 for the leader)
+    pub games_back: f64,
+}
+
+impl StandingsRecord {
+    /// Winning percentage, or None before the team has played
+    pub fn win_pct(&self) -> Option<f32> {
+        let games = self.wins + self.losses;
+        (games > 0).then(|| self.wins as f32 / games as f32)
+    }
+}
+
+/// Regular season standings for both conferences, each sorted by conference rank
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Standings {
+    pub season: u32,
+    pub east: Vec<StandingsRecord>,
+    pub west: Vec<StandingsRecord>,
+}
+
+impl fmt::Display for Standings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (conference, teams)) in [("Eastern Conference", &self.east), ("Western Conference", &self.west)].into_iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}", conference)?;
+            writeln!(f, "  {:>2} {:<24} {:>7} {:>5} {:>5}", "#", "Team", "W-L", "PCT", "GB")?;
+            
+            for record in teams {
+                // An unbeaten team's 1.000 doesn't fit the ".nnn" form
+                let pct = match record.win_pct() {
+                    Some(pct) if pct >= 1.0 => "1.000".to_string(),
+                    pct => format_decimal_stat(pct),
+                };
+                let games_back = if record.games_back == 0.0 {
+                    "-".to_string()
+                } else {
+                    format!("{:.1}", record.games_back)
+                };
+                writeln!(f, "  {:>2} {:<24} {:>7} {:>5} {:>5}",
+                    record.conference_rank,
+                    truncate_string(&record.team.full_name, 24),
+                    format_record(record.wins, record.losses),
+                    pct,
+                    games_back)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// NBA Game information
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Game {
     pub id: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub date: String,
     pub home_team: Team,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub home_team_score: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub period: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub postseason: bool,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub season: u32,
+    /// Raw status from the API: "Final", the quarter being played, or the scheduled start time.
+    /// Use `game_state` to tell where the game stands.
+    #[serde(default, deserialize_with = "null_as_default")]
     pub status: String,
     pub time: Option<String>,
     pub visitor_team: Team,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub visitor_team_score: u32,
+    /// Points scored in each quarter and overtime, where the API includes them
+    #[serde(flatten)]
+    pub quarters: QuarterScores,
+}
+
+/// Each team's points by period, as the API's `home_q1` ... `visitor_ot3` fields. A period that
+/// hasn't been played, or that the API didn't report, is None.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuarterScores {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_q1: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_q2: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_q3: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_q4: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_ot1: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_ot2: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_ot3: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visitor_q1: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visitor_q2: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visitor_q3: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visitor_q4: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visitor_ot1: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visitor_ot2: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visitor_ot3: Option<u32>,
+}
+
+/// A game's points by period for each team, with the final totals
+#[derive(Debug, Clone, Serialize)]
+pub struct LineScore {
+    pub game_id: u32,
+    pub visitor_team: String,
+    pub home_team: String,
+    /// The visitor's and home team's points in each period played, quarters then overtimes.
+    /// Empty if the API didn't report them, leaving only the totals.
+    pub periods: Vec<(u32, u32)>,
+    pub visitor_total: u32,
+    pub home_total: u32,
+}
+
+impl Game {
+    /// Returns true if the game is finished
+    pub fn is_final(&self) -> bool {
+        self.game_state().is_final()
+    }
+    
+    /// The points each team scored by period. Periods are only listed through the one being played
+    /// and while both teams' points are known, so without quarter data there are only the totals.
+    pub fn line_score(&self) -> LineScore {
+        let q = &self.quarters;
+        let periods = [
+            (q.visitor_q1, q.home_q1),
+            (q.visitor_q2, q.home_q2),
+            (q.visitor_q3, q.home_q3),
+            (q.visitor_q4, q.home_q4),
+            (q.visitor_ot1, q.home_ot1),
+            (q.visitor_ot2, q.home_ot2),
+            (q.visitor_ot3, q.home_ot3),
+        ];
+        LineScore {
+            game_id: self.id,
+            visitor_team: self.visitor_team.abbreviation.clone(),
+            home_team: self.home_team.abbreviation.clone(),
+            periods: periods
+                .into_iter()
+                .take(self.period as usize)
+                .map_while(|(visitor, home)| Some((visitor?, home?)))
+                .collect(),
+            visitor_total: self.visitor_team_score,
+            home_total: self.home_team_score,
+        }
+    }
+    
+    /// Map the free-form status string onto a game state. Before tip-off the status holds the
+    /// start time and the period is 0; once play starts it holds the quarter and clock.
+    pub fn game_state(&self) -> GameState {
+        match self.status.as_str() {
+            "Final" => GameState::Final,
+            "Postponed" => GameState::Postponed,
+            "Cancelled" | "Canceled" => GameState::Cancelled,
+            _ if self.period == 0 => GameState::Scheduled,
+            _ => GameState::InProgress,
+        }
+    }
+    
+    /// Returns the winning team, if the game is finished and wasn't tied
+    pub fn winner(&self) -> Option<&Team> {
+        if !self.is_final() {
+            return None;
+        }
+        
+        match determine_winner(Some(self.visitor_team_score), Some(self.home_team_score)) {
+            Some(0) => Some(&self.visitor_team),
+            Some(_) => Some(&self.home_team),
+            None => None,
+        }
+    }
+    
+    /// Build a compact score bug for the game. Possession isn't available from the API, so live
+    /// games show the quarter and game clock.
+    pub fn scorebug(&self) -> Scorebug {
+        let state = if self.is_final() {
+            ScorebugState::Final
+        } else if self.period == 0 {
+            // Before tip-off the status holds the scheduled start time
+            ScorebugState::Scheduled(scorebug::start_time(&self.status))
+        } else {
+            ScorebugState::Live { period: self.period_label(), detail: self.clock().map(String::from) }
+        };
+        
+        let started = !matches!(state, ScorebugState::Scheduled(_));
+        Scorebug {
+            away: self.visitor_team.abbreviation.clone(),
+            away_score: Some(self.visitor_team_score).filter(|_| started),
+            home: self.home_team.abbreviation.clone(),
+            home_score: Some(self.home_team_score).filter(|_| started),
+            state,
+        }
+    }
+    
+    /// One-line result such as "BOS 108 @ LAL 110 (Final)", with the quarter and clock for live games
+    pub fn summary_line(&self) -> String {
+        let state = self.game_state();
+        let status = match state {
+            GameState::InProgress => match self.clock() {
+                Some(clock) => format!("Live, {} {}", self.period_label(), clock),
+                None => format!("Live, {}", self.period_label()),
+            },
+            _ => state.to_string(),
+        };
+        if state == GameState::Scheduled {
+            return format!("{} @ {} ({})", self.visitor_team.abbreviation, self.home_team.abbreviation, status);
+        }
+        format!(
+            "{} {} @ {} {} ({})",
+            self.visitor_team.abbreviation,
+            self.visitor_team_score,
+            self.home_team.abbreviation,
+            self.home_team_score,
+            status
+        )
+    }
+    
+    // Quarter being played, e.g. "Q3", "OT" or "2OT"
+    fn period_label(&self) -> String {
+        match self.period {
+            1..=4 => format!("Q{}", self.period),
+            5 => "OT".to_string(),
+            n => format!("{}OT", n - 4),
+        }
+    }
+    
+    // Game clock while a quarter is being played
+    fn clock(&self) -> Option<&str> {
+        self.time.as_deref().map(str::trim).filter(|time| !time.is_empty())
+    }
 }
 
 impl fmt::Display for Game {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let game_status = if self.status == "Final" {
-            "Final".to_string()
-        } else {
-            format!("In Progress - {} {}", self.period, self.time.as_deref().unwrap_or(""))
+        self.fmt_ordered(f, get_config().home_first)
+    }
+}
+
+impl fmt::Display for LineScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_ordered(f, get_config().home_first)
+    }
+}
+
+impl TeamOrdered for LineScore {
+    fn fmt_ordered(&self, f: &mut fmt::Formatter<'_>, home_first: bool) -> fmt::Result {
+        // "Q1".."Q4", then "OT" for a single overtime or "OT1", "OT2", ... for more
+        let overtimes = self.periods.len().saturating_sub(4);
+        let label = |period: usize| match period {
+            1..=4 => format!("Q{}", period),
+            _ if overtimes == 1 => "OT".to_string(),
+            _ => format!("OT{}", period - 4),
+        };
+        
+        let periods = self.periods.len();
+        let mut headers = vec![String::new()];
+        headers.extend((1..=periods).map(label));
+        headers.extend(["|", "T"].map(String::from));
+        let mut table = Table::new(&headers);
+        let mut widths = vec![3];
+        widths.extend(std::iter::repeat_n(3, periods));
+        widths.extend([1, 3]);
+        table.min_widths(&widths);
+        for column in (1..=periods).chain([periods + 2]) {
+            table.align_right(column);
+        }
+        
+        let (first, second) = in_order(
+            (&self.visitor_team, self.visitor_total, false),
+            (&self.home_team, self.home_total, true),
+            home_first,
+        );
+        for (team, total, home) in [first, second] {
+            let mut cells = vec![team.clone()];
+            cells.extend(self.periods.iter().map(|&(visitor_points, home_points)| {
+                if home { home_points } else { visitor_points }.to_string()
+            }));
+            cells.push("|".to_string());
+            cells.push(total.to_string());
+            table.row(cells);
+        }
+        write!(f, "{}", table.lines().join("\n"))
+    }
+}
+
+impl TeamOrdered for Game {
+    fn fmt_ordered(&self, f: &mut fmt::Formatter<'_>, home_first: bool) -> fmt::Result {
+        let (first, second) = in_order(
+            (&self.visitor_team, self.visitor_team_score),
+            (&self.home_team, self.home_team_score),
+            home_first,
+        );
+        let game_status = match self.game_state() {
+            GameState::Scheduled => format!("Scheduled - {}", scorebug::start_time(&self.status)),
+            GameState::InProgress => format!("In Progress - {} {}", self.period, self.time.as_deref().unwrap_or("")),
+            state => state.to_string(),
         };
         
+        // With color on, the winner's score is green, the loser is dimmed and a live status is yellow
+        let winner_id = self.winner().map(|team| team.id);
+        let score = |(team, score): (&Team, u32)| {
+            if winner_id == Some(team.id) {
+                paint(score, Style::Winner)
+            } else {
+                score.to_string()
+            }
+        };
+        let live = self.game_state().is_live();
+        let status = |status: &str| if live { paint(status, Style::Live) } else { status.to_string() };
+
+        // Compact single-line form: {:#}
+        if f.alternate() {
+            return write!(
+                f,
+                "{} {} {} {} {} {} ({})",
+                self.date.split('T').next().unwrap_or(&self.date),
+                first.0.abbreviation,
+                score(first),
+                separator(home_first),
+                second.0.abbreviation,
+                score(second),
+                status(game_status.trim_end())
+            );
+        }
+        
+        let line = |(team, points): (&Team, u32)| {
+            let line = format!("{}: {}", team.name, score((team, points)));
+            if winner_id.is_some_and(|id| id != team.id) {
+                paint(line, Style::Dimmed)
+            } else {
+                line
+            }
+        };
         write!(
             f,
-            "{} @ {} - {}\n{}: {}\n{}: {}\nSeason: {}, {}",
-            self.visitor_team.abbreviation,
-            self.home_team.abbreviation,
+            "{} {} {} - {}\n{}\n{}\nSeason: {}, {}",
+            first.0.abbreviation,
+            separator(home_first),
+            second.0.abbreviation,
             self.date,
-            self.visitor_team.name,
-            self.visitor_team_score,
-            self.home_team.name,
-            self.home_team_score,
+            line(first),
+            line(second),
             self.season,
-            game_status
-        )
+            status(&game_status)
+        )?;
+        
+        // A lead in an unfinished game isn't a result yet
+        if self.is_final() {
+            match self.winner() {
+                Some(winner) => write!(f, "\nWinner: {}", winner.full_name)?,
+                None => write!(f, "\nResult: Tie")?,
+            }
+        }
+        
+        Ok(())
     }
 }
 
@@ -248,6 +935,16 @@ pub struct Meta {
     pub per_page: u32,
 }
 
+/// A team's games, possibly cut short by a game limit
+#[derive(Debug)]
+pub struct TeamGames {
+    pub games: Vec<Game>,
+    /// Meta from the last page fetched; `next_cursor` is set when there are more games
+    pub meta: Meta,
+    /// Whether the limit stopped the fetch before the schedule ran out
+    pub truncated: bool,
+}
+
 /// Single item response
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SingleResponse<T> {
@@ -277,6 +974,9 @@ pub struct PlayerStats {
     pub turnover: Option<u32>,
     pub pf: Option<u32>,
     pub pts: Option<u32>,
+    /// Plus/minus, which only some versions of the stats response include
+    #[serde(default, alias = "plus_minus")]
+    pub pm: Option<i32>,
     pub player: PlayerStatsPlayer,
     pub team: Team,
     pub game: GameSummary,
@@ -285,24 +985,37 @@ pub struct PlayerStats {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PlayerStatsPlayer {
     pub id: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub first_name: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub last_name: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub position: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub team_id: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GameSummary {
     pub id: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub date: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub home_team_id: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub visitor_team_id: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub home_team_score: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub visitor_team_score: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub season: u32,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub status: String,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub period: u32,
     pub time: Option<String>,
+    #[serde(default, deserialize_with = "null_as_default")]
     pub postseason: bool,
 }
 
@@ -327,12 +1040,234 @@ impl fmt::Display for PlayerStats {
             self.ftm.unwrap_or(0),
             self.fta.unwrap_or(0),
             minutes
+        )?;
+        
+        if let Some(pm) = self.pm {
+            write!(f, ", +/-: {:+}", pm)?;
+        }
+        Ok(())
+    }
+}
+
+impl PlayerStats {
+    /// Rebounding split, turnovers and fouls, shown beneath the main line in detailed output
+    pub fn detail_line(&self) -> String {
+        format!(
+            "{} oreb, {} dreb, {} tov, {} pf",
+            self.oreb.unwrap_or(0),
+            self.dreb.unwrap_or(0),
+            self.turnover.unwrap_or(0),
+            self.pf.unwrap_or(0)
         )
     }
 }
 
-/// Format and display player statistics for a game, ordered by away team followed by home team
-pub fn display_game_player_stats(_game_id: u32, stats: &[PlayerStats]) -> String {
+impl League for NbaApi {
+    type Game = Game;
+    
+    const NAME: &'static str = "NBA";
+    const PENDING_MESSAGE: &'static str = "Detailed player statistics not available for games that haven't been completed.";
+    
+    fn game_id(game: &Game) -> u32 {
+        game.id
+    }
+    
+    fn game_state(game: &Game) -> GameState {
+        game.game_state()
+    }
+    
+    fn summary_line(game: &Game) -> String {
+        game.summary_line()
+    }
+    
+    fn team_ids(game: &Game) -> (u32, u32) {
+        (game.visitor_team.id, game.home_team.id)
+    }
+    
+    fn favorite_teams(config: &crate::config::Config) -> &[u32] {
+        &config.favorite_nba_teams
+    }
+    
+    async fn games_by_date(&self, date: NaiveDate) -> Result<Vec<Game>> {
+        self.get_games_by_date(date).await
+    }
+    
+    async fn todays_games(&self) -> Result<Vec<Game>> {
+        self.get_todays_games().await
+    }
+    
+    async fn yesterdays_games(&self) -> Result<Vec<Game>> {
+        self.get_yesterdays_games().await
+    }
+    
+    async fn team_schedule(&self, team_id: u32, start_date: Option<String>, end_date: Option<String>) -> Result<Vec<Game>> {
+        Ok(self.get_team_games(team_id, start_date, end_date, None).await?.games)
+    }
+    
+    async fn team_abbreviations(&self) -> Result<Vec<(u32, String)>> {
+        Ok(self.get_all_teams().await?
+            .into_iter()
+            .map(|team| (team.id, team.abbreviation))
+            .collect())
+    }
+    
+    /// Player statistics, ordered by away team followed by home team, with the game's leaders beneath
+    async fn game_detail(&self, game: &Game, options: DetailOptions) -> Vec<DetailSection> {
+        vec![DetailSection {
+            heading: "Line Score",
+            what: "line score",
+            body: Ok(game.line_score().to_string()),
+        }, DetailSection {
+            heading: "Player Statistics",
+            what: "player stats",
+            body: self.get_final_game_player_stats(game.id).await.map(|stats| {
+                let box_score = display_game_player_stats(game.id, &stats, options.detailed_stats);
+                if stats.is_empty() {
+                    box_score
+                } else {
+                    format!("{}\n{}", box_score, game_leaders(&stats))
+                }
+            }),
+        }]
+    }
+}
+
+impl CalendarEvent for Game {
+    fn uid(&self) -> String {
+        format!("nba-{}@plaintext-sports", self.id)
+    }
+    
+    fn start(&self) -> String {
+        // Before tip-off the status holds the scheduled start time; otherwise only the day is known
+        if parse_iso8601_date(&self.status).is_ok() {
+            self.status.clone()
+        } else {
+            self.date.split('T').next().unwrap_or(&self.date).to_string()
+        }
+    }
+    
+    fn summary(&self) -> String {
+        // City alone is ambiguous (two teams in Los Angeles), so the abbreviation is included
+        let label = |team: &Team| {
+            if team.city.is_empty() {
+                team.abbreviation.clone()
+            } else {
+                format!("{} ({})", team.city, team.abbreviation)
+            }
+        };
+        format!("{} @ {}", label(&self.visitor_team), label(&self.home_team))
+    }
+    
+    fn location(&self) -> Option<String> {
+        None
+    }
+}
+
+/// NBA player injury report entry
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PlayerInjury {
+    pub player: PlayerStatsPlayer,
+    pub return_date: Option<String>,
+    pub description: Option<String>,
+    pub status: String,
+}
+
+impl PlayerInjury {
+    /// Returns the injury named in the report description, e.g. "ankle" from "... Doncic (ankle) is out ..."
+    pub fn injury(&self) -> Option<&str> {
+        let description = self.description.as_deref()?;
+        let start = description.find('(')? + 1;
+        let end = start + description[start..].find(')')?;
+        Some(description[start..end].trim()).filter(|injury| !injury.is_empty())
+    }
+}
+
+impl fmt::Display for PlayerInjury {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.status)?;
+        if let Some(injury) = self.injury() {
+            write!(f, " ({})", injury)?;
+        }
+        if let Some(ref return_date) = self.return_date {
+            write!(f, ", expected return {}", return_date)?;
+        }
+        Ok(())
+    }
+}
+
+/// A team's record and point differential across a set of completed games
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamGamesSummary {
+    pub wins: u32,
+    pub losses: u32,
+    pub point_differential: i64,
+}
+
+impl TeamGamesSummary {
+    /// Number of completed games counted in the summary
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses
+    }
+    
+    /// Average point differential per game, if any games were completed
+    pub fn average_differential(&self) -> Option<f64> {
+        if self.games() == 0 {
+            None
+        } else {
+            Some(self.point_differential as f64 / self.games() as f64)
+        }
+    }
+}
+
+impl fmt::Display for TeamGamesSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.average_differential() {
+            Some(average) => write!(
+                f,
+                "Record in these games: {}-{} (avg. point differential: {:+.1})",
+                self.wins, self.losses, average
+            ),
+            None => write!(f, "Record in these games: no completed games"),
+        }
+    }
+}
+
+/// Summarize a team's record and point differential over the completed games in a list
+pub fn summarize_team_games(team_id: u32, games: &[Game]) -> TeamGamesSummary {
+    let mut summary = TeamGamesSummary { wins: 0, losses: 0, point_differential: 0 };
+    
+    for game in games.iter().filter(|game| game.is_final()) {
+        let (scored, allowed) = if game.home_team.id == team_id {
+            (game.home_team_score, game.visitor_team_score)
+        } else if game.visitor_team.id == team_id {
+            (game.visitor_team_score, game.home_team_score)
+        } else {
+            continue;
+        };
+        
+        match scored.cmp(&allowed) {
+            std::cmp::Ordering::Greater => summary.wins += 1,
+            std::cmp::Ordering::Less => summary.losses += 1,
+            std::cmp::Ordering::Equal => continue,
+        }
+        summary.point_differential += scored as i64 - allowed as i64;
+    }
+    
+    summary
+}
+
+/// Returns a player's injury status from an injury report, or "Healthy" if they aren't listed
+pub fn injury_status(injuries: &[PlayerInjury], player_id: u32) -> String {
+    injuries
+        .iter()
+        .find(|injury| injury.player.id == player_id)
+        .map(|injury| injury.to_string())
+        .unwrap_or_else(|| "Healthy".to_string())
+}
+
+/// Format and display player statistics for a game, ordered by away team followed by home team.
+/// With `detailed`, each player also gets a line with offensive/defensive rebounds, turnovers and fouls.
+pub fn display_game_player_stats(_game_id: u32, stats: &[PlayerStats], detailed: bool) -> String {
     if stats.is_empty() {
         return "No player statistics available for this game.".to_string();
     }
@@ -359,13 +1294,25 @@ pub fn display_game_player_stats(_game_id: u32, stats: &[PlayerStats]) -> String
     home_team_players.sort_by(|a, b| b.pts.unwrap_or(0).cmp(&a.pts.unwrap_or(0)));
     
     let mut output = String::new();
+    let player_line = |player: &PlayerStats| {
+        let tag = match stat_category_count(player) {
+            0..=1 => "",
+            2 => " (double-double)",
+            _ => " (triple-double)",
+        };
+        if detailed {
+            format!("{}{}\n    {}\n", player, tag, player.detail_line())
+        } else {
+            format!("{}{}\n", player, tag)
+        }
+    };
     
     // Display away team stats
     if !away_team_players.is_empty() {
         output.push_str(&format!("\n{} ({}):\n", away_team_players[0].team.full_name, away_team_players[0].team.abbreviation));
         output.push_str("--------------------------------------------------\n");
         for player in &away_team_players {
-            output.push_str(&format!("{}\n", player));
+            output.push_str(&player_line(player));
         }
     }
     
@@ -374,19 +1321,47 @@ pub fn display_game_player_stats(_game_id: u32, stats: &[PlayerStats]) -> String
         output.push_str(&format!("\n{} ({}):\n", home_team_players[0].team.full_name, home_team_players[0].team.abbreviation));
         output.push_str("--------------------------------------------------\n");
         for player in &home_team_players {
-            output.push_str(&format!("{}\n", player));
+            output.push_str(&player_line(player));
         }
     }
     
     output
 }
 
+// The top scorer, rebounder and assister across both teams, e.g.
+// "Leaders — PTS: Tatum 34, REB: Davis 14, AST: James 11"; ties go to the alphabetically-first last name
+fn game_leaders(stats: &[PlayerStats]) -> String {
+    let leader = |value: fn(&PlayerStats) -> Option<u32>| {
+        stats
+            .iter()
+            .filter(|player| value(player).is_some())
+            .min_by(|a, b| value(b).cmp(&value(a)).then_with(|| a.player.last_name.cmp(&b.player.last_name)))
+            .map(|player| format!("{} {}", player.player.last_name, value(player).unwrap_or(0)))
+            .unwrap_or_else(|| "-".to_string())
+    };
+    
+    format!(
+        "Leaders — PTS: {}, REB: {}, AST: {}",
+        leader(|player| player.pts),
+        leader(|player| player.reb),
+        leader(|player| player.ast)
+    )
+}
+
+// Number of points/rebounds/assists/steals/blocks categories in which a player reached double digits
+fn stat_category_count(stats: &PlayerStats) -> usize {
+    [stats.pts, stats.reb, stats.ast, stats.stl, stats.blk]
+        .iter()
+        .filter(|value| value.unwrap_or(0) >= 10)
+        .count()
+}
+
 /// Create an authorized client for the balldontlie API
 fn create_client() -> Result<Client> {
     let api_key = get_nba_api_key();
     
     if api_key.is_empty() {
-        return Err(anyhow!("NBA API key is required. Please set the NBA_API_KEY environment variable."));
+        return Err(SportError::MissingApiKey.into());
     }
     
     let mut headers = header::HeaderMap::new();
@@ -397,50 +1372,86 @@ fn create_client() -> Result<Client> {
     
     let client = Client::builder()
         .default_headers(headers)
+        .timeout(Duration::from_secs(get_config().request_timeout_secs))
         .build()?;
     
     Ok(client)
 }
 
 /// Get team information by ID
-pub async fn get_team(team_id: u32) -> Result<Team> {
-    get_nba_api().get_team(team_id).await
+pub async fn get_team(team_id: impl Into<TeamId>) -> Result<Team> {
+    get_nba_api()?.get_team(team_id.into().0).await
+}
+
+/// Get game information by ID
+pub async fn get_game(game_id: impl Into<GameId>) -> Result<Game> {
+    get_nba_api()?.get_game(game_id.into().0).await
+}
+
+/// Get a game's points by quarter for each team
+pub async fn get_game_line_score(game_id: impl Into<GameId>) -> Result<LineScore> {
+    get_nba_api()?.get_game_line_score(game_id.into().0).await
+}
+
+/// Get all current NBA teams, sorted by division then name
+pub async fn get_all_teams() -> Result<Vec<Team>> {
+    get_nba_api()?.get_all_teams().await
 }
 
 /// Get player information by ID
 pub async fn get_player(player_id: u32) -> Result<Player> {
-    get_nba_api().get_player(player_id).await
+    get_nba_api()?.get_player(player_id).await
+}
+
+/// Find players whose name matches a search
+pub async fn search_players(query: &str) -> Result<Vec<Player>> {
+    get_nba_api()?.search_players(query).await
+}
+
+/// Get a player's per-game averages for a season, or None if they didn't play that season
+pub async fn get_player_season_averages(player_id: u32, season: u32) -> Result<Option<SeasonAverages>> {
+    get_nba_api()?.get_player_season_averages(player_id, season).await
+}
+
+/// Get the regular season standings for both conferences
+pub async fn get_standings(season: u32) -> Result<Standings> {
+    get_nba_api()?.get_standings(season).await
 }
 
 /// Get all NBA games for today
 pub async fn get_todays_games() -> Result<Vec<Game>> {
-    get_nba_api().get_todays_games().await
+    get_nba_api()?.get_todays_games().await
 }
 
 /// Get all NBA games from yesterday
 pub async fn get_yesterdays_games() -> Result<Vec<Game>> {
-    get_nba_api().get_yesterdays_games().await
+    get_nba_api()?.get_yesterdays_games().await
 }
 
 /// Get games by date
-#[allow(dead_code)]
 pub async fn get_games_by_date(date: NaiveDate) -> Result<Vec<Game>> {
-    get_nba_api().get_games_by_date(date).await
+    get_nba_api()?.get_games_by_date(date).await
 }
 
-/// Get team games
-pub async fn get_team_games(team_id: u32, start_date: Option<String>, end_date: Option<String>) -> Result<Vec<Game>> {
-    get_nba_api().get_team_games(team_id, start_date, end_date).await
+/// Get team games, stopping once `max_games` have been fetched
+pub async fn get_team_games(team_id: impl Into<TeamId>, start_date: Option<String>, end_date: Option<String>, max_games: Option<usize>) -> Result<TeamGames> {
+    get_nba_api()?.get_team_games(team_id.into().0, start_date, end_date, max_games).await
 }
 
 /// Get player stats for a game
-pub async fn get_game_player_stats(game_id: u32) -> Result<Vec<PlayerStats>> {
-    get_nba_api().get_game_player_stats(game_id).await
+pub async fn get_game_player_stats(game_id: impl Into<GameId>) -> Result<Vec<PlayerStats>> {
+    get_nba_api()?.get_game_player_stats(game_id.into().0).await
+}
+
+/// Get current player injuries, optionally limited to a single team
+pub async fn get_player_injuries(team_id: Option<u32>) -> Result<Vec<PlayerInjury>> {
+    get_nba_api()?.get_player_injuries(team_id).await
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::order::Ordered;
     
     fn mock_team() -> Team {
         Team {
@@ -475,6 +1486,7 @@ mod tests {
                 name: "Celtics".to_string(),
             },
             visitor_team_score: 108,
+            quarters: QuarterScores::default(),
         }
     }
     
@@ -498,5 +1510,454 @@ mod tests {
         assert!(display.contains("Celtics: 108"));
         assert!(display.contains("Lakers: 110"));
         assert!(display.contains("Final"));
+
+        let compact = format!("{:#}", game);
+        assert_eq!(compact, "2024-03-28 BOS 108 @ LAL 110 (Final)");
+    }
+    
+    #[test]
+    fn test_game_display_home_first() {
+        let game = mock_game();
+        
+        let away_first = format!("{}", Ordered(&game, false));
+        assert!(away_first.starts_with("BOS @ LAL - 2024-03-28\nCeltics: 108\nLakers: 110\n"));
+        
+        let home_first = format!("{}", Ordered(&game, true));
+        assert!(home_first.starts_with("LAL vs BOS - 2024-03-28\nLakers: 110\nCeltics: 108\n"));
+        assert!(home_first.ends_with("Winner: Los Angeles Lakers"));
+        assert_eq!(format!("{:#}", Ordered(&game, true)), "2024-03-28 LAL 110 vs BOS 108 (Final)");
+    }
+    
+    #[test]
+    fn test_line_score() {
+        let mut game: Game = serde_json::from_value(serde_json::json!({
+            "id": 12345,
+            "date": "2024-03-28",
+            "home_team": {"id": 14, "abbreviation": "LAL", "full_name": "Los Angeles Lakers"},
+            "home_team_score": 118,
+            "period": 5,
+            "status": "Final",
+            "visitor_team": {"id": 2, "abbreviation": "BOS", "full_name": "Boston Celtics"},
+            "visitor_team_score": 112,
+            "home_q1": 28, "home_q2": 25, "home_q3": 26, "home_q4": 24, "home_ot1": 15,
+            "visitor_q1": 22, "visitor_q2": 31, "visitor_q3": 24, "visitor_q4": 26, "visitor_ot1": 9,
+            "home_ot2": null, "visitor_ot2": null
+        })).unwrap();
+        
+        let line_score = game.line_score();
+        assert_eq!(line_score.periods, vec![(22, 28), (31, 25), (24, 26), (26, 24), (9, 15)]);
+        assert_eq!(
+            Ordered(&line_score, false).to_string(),
+            "     Q1  Q2  Q3  Q4  OT |   T\n\
+             ------------------------+----\n\
+             BOS  22  31  24  26   9 | 112\n\
+             LAL  28  25  26  24  15 | 118"
+        );
+        assert!(Ordered(&line_score, true).to_string().lines().nth(2).unwrap().starts_with("LAL"));
+        
+        // A second overtime numbers them
+        game.period = 6;
+        game.quarters.home_ot2 = Some(10);
+        game.quarters.visitor_ot2 = Some(8);
+        let display = Ordered(&game.line_score(), false).to_string();
+        assert!(display.starts_with("     Q1  Q2  Q3  Q4 OT1 OT2 |   T\n"));
+        
+        // Without quarter data only the final is shown
+        let line_score = mock_game().line_score();
+        assert!(line_score.periods.is_empty());
+        assert_eq!(
+            Ordered(&line_score, false).to_string(),
+            "    |   T\n\
+             ----+----\n\
+             BOS | 108\n\
+             LAL | 110"
+        );
+    }
+    
+    #[test]
+    fn test_summary_line() {
+        let mut game = mock_game();
+        assert_eq!(game.summary_line(), "BOS 108 @ LAL 110 (Final)");
+        
+        game.status = "4th Qtr".to_string();
+        game.time = Some(" 2:14 ".to_string());
+        assert_eq!(game.summary_line(), "BOS 108 @ LAL 110 (Live, Q4 2:14)");
+        
+        game.period = 6;
+        game.time = None;
+        assert_eq!(game.summary_line(), "BOS 108 @ LAL 110 (Live, 2OT)");
+        
+        game.status = "2024-03-28T23:30:00Z".to_string();
+        game.period = 0;
+        assert_eq!(game.summary_line(), "BOS @ LAL (Scheduled)");
+    }
+    
+    #[test]
+    fn test_game_winner() {
+        let mut game = mock_game();
+        assert_eq!(game.winner().map(|team| team.abbreviation.as_str()), Some("LAL"));
+        assert!(format!("{}", game).contains("Winner: Los Angeles Lakers"));
+        
+        // Level scores in a final game
+        game.visitor_team_score = 110;
+        assert!(game.winner().is_none());
+        assert!(format!("{}", game).contains("Result: Tie"));
+        
+        // Level or leading scores mid-game produce no result
+        game.status = "3rd Qtr".to_string();
+        game.period = 3;
+        assert!(game.winner().is_none());
+        let display = format!("{}", game);
+        assert!(!display.contains("Winner:"));
+        assert!(!display.contains("Tie"));
+        
+        game.home_team_score = 120;
+        assert!(game.winner().is_none());
+    }
+    
+    #[test]
+    fn test_tolerant_deserialization() {
+        // Missing position, null team fields, and an unknown field
+        let player: Player = serde_json::from_str(r#"{
+            "id": 237,
+            "first_name": "LeBron",
+            "last_name": "James",
+            "height_feet": null,
+            "height_inches": null,
+            "weight_pounds": null,
+            "draft_year": 2003,
+            "team": {"id": 14, "abbreviation": "LAL", "city": null, "conference": "West", "division": "Pacific", "full_name": "Los Angeles Lakers", "name": "Lakers"}
+        }"#).unwrap();
+        assert_eq!(player.position, "");
+        assert_eq!(player.team.city, "");
+        assert_eq!(player.team.abbreviation, "LAL");
+        
+        // Null scores before tip-off and a free agent without a team
+        let response: PaginatedResponse<Game> = serde_json::from_str(r#"{
+            "data": [{
+                "id": 1,
+                "date": "2024-03-28",
+                "home_team": {"id": 14, "abbreviation": "LAL"},
+                "home_team_score": null,
+                "period": 0,
+                "postseason": false,
+                "season": 2023,
+                "status": "7:30 pm ET",
+                "time": null,
+                "visitor_team": {"id": 2, "abbreviation": "BOS"},
+                "visitor_team_score": null,
+                "datetime": "2024-03-28T23:30:00Z"
+            }],
+            "meta": {"next_cursor": null, "per_page": 25}
+        }"#).unwrap();
+        assert_eq!(response.data[0].home_team_score, 0);
+        assert_eq!(response.data[0].visitor_team.abbreviation, "BOS");
+        
+        let free_agent: Player = serde_json::from_str(r#"{"id": 1, "first_name": "Free", "last_name": "Agent", "position": null, "team": null}"#).unwrap();
+        assert_eq!(free_agent.team.id, 0);
+    }
+    
+    #[test]
+    fn test_season_averages_display() {
+        let response: SingleResponse<Vec<SeasonAverages>> = serde_json::from_str(r#"{
+            "data": [{
+                "games_played": 71, "player_id": 237, "season": 2024, "min": "35:18",
+                "pts": 25.7, "reb": 7.25, "ast": 8.3, "stl": 1.3, "blk": 0.56,
+                "fg_pct": 0.54, "fg3_pct": 0.41, "ft_pct": null
+            }]
+        }"#).unwrap();
+        
+        assert_eq!(
+            response.data[0].to_string(),
+            "PPG: 25.7, RPG: 7.2, APG: 8.3, SPG: 1.3, BPG: 0.6, FG%: 54.0, 3P%: 41.0, FT%: 0.0, MIN: 35:18, GP: 71"
+        );
+    }
+    
+    #[test]
+    fn test_group_standings() {
+        let response: SingleResponse<Vec<StandingsRow>> = serde_json::from_str(r#"{
+            "data": [
+                {"team": {"id": 14, "conference": "West", "full_name": "Los Angeles Lakers"}, "wins": 47, "losses": 35, "conference_rank": 8},
+                {"team": {"id": 2, "conference": "East", "full_name": "Boston Celtics"}, "wins": 64, "losses": 18, "conference_rank": 1},
+                {"team": {"id": 20, "conference": "East", "full_name": "New York Knicks"}, "wins": 50, "losses": 32, "conference_rank": 2, "games_behind": 14.0},
+                {"team": {"id": 21, "conference": "West", "full_name": "Oklahoma City Thunder"}, "wins": 57, "losses": 25, "conference_rank": 1},
+                {"team": {"id": 9, "conference": "East", "full_name": "Detroit Pistons"}, "wins": 14, "losses": 67}
+            ]
+        }"#).unwrap();
+        let standings = group_standings(2023, response.data);
+        
+        let east: Vec<(u32, u32, f64)> = standings.east.iter().map(|record| (record.team.id, record.conference_rank, record.games_back)).collect();
+        assert_eq!(east, vec![(2, 1, 0.0), (20, 2, 14.0), (9, 3, 49.5)]);
+        let west: Vec<(u32, u32, f64)> = standings.west.iter().map(|record| (record.team.id, record.conference_rank, record.games_back)).collect();
+        assert_eq!(west, vec![(21, 1, 0.0), (14, 8, 10.0)]);
+        
+        let display = standings.to_string();
+        assert!(display.starts_with("Eastern Conference\n"));
+        assert!(display.contains("   1 Boston Celtics             64-18  .780     -\n"));
+        assert!(display.contains("   3 Detroit Pistons            14-67  .173  49.5\n"));
+        assert!(display.contains("\nWestern Conference\n"));
+    }
+    
+    #[test]
+    fn test_current_season() {
+        assert_eq!(current_season(NaiveDate::from_ymd_opt(2024, 3, 28).unwrap()), 2023);
+        assert_eq!(current_season(NaiveDate::from_ymd_opt(2024, 10, 22).unwrap()), 2024);
+    }
+    
+    #[test]
+    fn test_game_scorebug() {
+        let mut game = mock_game();
+        assert_eq!(game.scorebug().state, ScorebugState::Final);
+        assert!(game.scorebug().to_string().contains("| BOS 108 | F        |"));
+        
+        game.status = "3rd Qtr".to_string();
+        game.period = 3;
+        game.time = Some("5:32".to_string());
+        let live = game.scorebug();
+        assert_eq!(live.state, ScorebugState::Live { period: "Q3".to_string(), detail: Some("5:32".to_string()) });
+        assert!(live.to_string().contains("| LAL 110 | 5:32     |"));
+        
+        game.period = 6;
+        game.time = Some(" ".to_string());
+        assert_eq!(game.scorebug().state, ScorebugState::Live { period: "2OT".to_string(), detail: None });
+        
+        game.status = "7:30 pm ET".to_string();
+        game.period = 0;
+        let scheduled = game.scorebug();
+        assert_eq!(scheduled.state, ScorebugState::Scheduled("7:30 pm ET".to_string()));
+        assert!(scheduled.home_score.is_none());
+    }
+    
+    #[test]
+    fn test_display_game_player_stats_detailed() {
+        let stats: Vec<PlayerStats> = serde_json::from_value(serde_json::json!([
+            {
+                "id": 1, "min": "35", "pts": 25, "reb": 9, "oreb": 2, "dreb": 7, "ast": 8,
+                "stl": 1, "blk": 1, "turnover": 4, "pf": 3,
+                "fgm": 10, "fga": 20, "fg3m": 2, "fg3a": 6, "ftm": 3, "fta": 4,
+                "player": {"id": 237, "first_name": "LeBron", "last_name": "James"},
+                "team": {"id": 14, "full_name": "Los Angeles Lakers", "abbreviation": "LAL"},
+                "game": {"id": 12345, "date": "2024-03-28", "home_team_id": 14, "visitor_team_id": 2}
+            }
+        ])).unwrap();
+        
+        let summary = display_game_player_stats(12345, &stats, false);
+        assert!(summary.contains("LeBron James: 25 pts, 9 reb, 8 ast"));
+        assert!(!summary.contains("oreb"));
+        
+        let detailed = display_game_player_stats(12345, &stats, true);
+        assert!(detailed.contains("LeBron James: 25 pts, 9 reb, 8 ast"));
+        assert!(detailed.contains("\n    2 oreb, 7 dreb, 4 tov, 3 pf\n"));
+    }
+    
+    #[test]
+    fn test_player_stats_plus_minus() {
+        let stats: Vec<PlayerStats> = serde_json::from_value(serde_json::json!([
+            {
+                "id": 1, "min": "32", "pts": 18, "reb": 4, "ast": 3, "plus_minus": -7,
+                "player": {"id": 237, "first_name": "LeBron", "last_name": "James"},
+                "team": {"id": 14, "full_name": "Los Angeles Lakers", "abbreviation": "LAL"},
+                "game": {"id": 12345, "date": "2024-03-28", "home_team_id": 14, "visitor_team_id": 2}
+            },
+            {
+                "id": 2, "min": "30", "pts": 12, "pm": 9,
+                "player": {"id": 140, "first_name": "Anthony", "last_name": "Davis"},
+                "team": {"id": 14, "full_name": "Los Angeles Lakers", "abbreviation": "LAL"},
+                "game": {"id": 12345, "date": "2024-03-28", "home_team_id": 14, "visitor_team_id": 2}
+            },
+            {
+                "id": 3, "min": "12", "pts": 2,
+                "player": {"id": 666786, "first_name": "Jalen", "last_name": "Hood-Schifino"},
+                "team": {"id": 14, "full_name": "Los Angeles Lakers", "abbreviation": "LAL"},
+                "game": {"id": 12345, "date": "2024-03-28", "home_team_id": 14, "visitor_team_id": 2}
+            }
+        ])).unwrap();
+        
+        assert_eq!(stats[0].pm, Some(-7));
+        assert!(stats[0].to_string().ends_with("in 32 min, +/-: -7"));
+        assert!(stats[1].to_string().ends_with("in 30 min, +/-: +9"));
+        assert_eq!(stats[2].pm, None);
+        assert!(stats[2].to_string().ends_with("in 12 min"));
+    }
+    
+    #[test]
+    fn test_game_leaders() {
+        let line = |id: u32, last_name: &str, team_id: u32, pts: u32, reb: u32, ast: u32| serde_json::json!({
+            "id": id, "pts": pts, "reb": reb, "ast": ast,
+            "player": {"id": id, "first_name": "", "last_name": last_name},
+            "team": {"id": team_id},
+            "game": {"id": 12345, "home_team_id": 14, "visitor_team_id": 2}
+        });
+        let stats: Vec<PlayerStats> = serde_json::from_value(serde_json::json!([
+            line(1, "Tatum", 2, 34, 9, 4),
+            line(2, "Davis", 14, 22, 14, 2),
+            line(3, "James", 14, 25, 8, 11),
+            // Tied with Davis on rebounds and with James on assists
+            line(4, "Brown", 2, 18, 14, 11)
+        ])).unwrap();
+        
+        assert_eq!(game_leaders(&stats), "Leaders — PTS: Tatum 34, REB: Brown 14, AST: Brown 11");
+    }
+    
+    #[test]
+    fn test_stat_category_count() {
+        let stats: Vec<PlayerStats> = serde_json::from_value(serde_json::json!([
+            {
+                "id": 1, "min": "38", "pts": 25, "reb": 12, "ast": 11, "stl": 2, "blk": 1,
+                "player": {"id": 237, "first_name": "LeBron", "last_name": "James"},
+                "team": {"id": 14, "full_name": "Los Angeles Lakers", "abbreviation": "LAL"},
+                "game": {"id": 12345, "date": "2024-03-28", "home_team_id": 14, "visitor_team_id": 2}
+            },
+            {
+                "id": 2, "min": "36", "pts": 30, "reb": 3, "ast": 2, "stl": null, "blk": null,
+                "player": {"id": 140, "first_name": "Anthony", "last_name": "Davis"},
+                "team": {"id": 14, "full_name": "Los Angeles Lakers", "abbreviation": "LAL"},
+                "game": {"id": 12345, "date": "2024-03-28", "home_team_id": 14, "visitor_team_id": 2}
+            }
+        ])).unwrap();
+        
+        assert_eq!(stat_category_count(&stats[0]), 3);
+        // Double-digit scoring alone isn't tagged
+        assert_eq!(stat_category_count(&stats[1]), 1);
+        
+        // Still sorted by points, with only the standout line tagged
+        let summary = display_game_player_stats(12345, &stats, false);
+        assert!(summary.contains("Anthony Davis: 30 pts, 3 reb, 2 ast, 0 stl, 0 blk, 0/0 FG, 0/0 3PT, 0/0 FT in 36 min\nLeBron James"));
+        assert!(summary.contains("0/0 FT in 38 min (triple-double)\n"));
+    }
+    
+    #[test]
+    fn test_schedule_to_ics() {
+        let schedule: Vec<Game> = serde_json::from_value(serde_json::json!([
+            {
+                "id": 1001, "date": "2024-03-28", "status": "2024-03-28T23:30:00Z", "period": 0,
+                "home_team": {"id": 14, "abbreviation": "LAL", "city": "Los Angeles"},
+                "visitor_team": {"id": 2, "abbreviation": "BOS", "city": "Boston"}
+            },
+            {
+                "id": 1002, "date": "2024-03-26", "status": "Final", "period": 4,
+                "home_team": {"id": 13, "abbreviation": "LAC", "city": "Los Angeles"},
+                "visitor_team": {"id": 14, "abbreviation": "LAL", "city": "Los Angeles"}
+            }
+        ])).unwrap();
+        
+        let ics = crate::calendar::to_ics(&schedule);
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("\r\nUID:nba-1001@plaintext-sports\r\nDTSTAMP:"));
+        assert!(ics.contains("\r\nDTSTART:20240328T233000Z\r\n"));
+        assert!(ics.contains("\r\nSUMMARY:Boston (BOS) @ Los Angeles (LAL)\r\n"));
+        
+        // A finished game no longer has its start time, so it's an all-day event on its date
+        assert!(ics.contains("\r\nDTSTART;VALUE=DATE:20240326\r\n"));
+        assert!(ics.contains("\r\nSUMMARY:Los Angeles (LAL) @ Los Angeles (LAC)\r\n"));
+    }
+    
+    #[test]
+    fn test_game_state() {
+        let mut game = mock_game();
+        assert_eq!(game.game_state(), GameState::Final);
+        assert!(game.is_final());
+        
+        game.status = "2nd Qtr".to_string();
+        game.period = 2;
+        assert_eq!(game.game_state(), GameState::InProgress);
+        assert!(!game.is_final());
+        
+        game.status = "Halftime".to_string();
+        assert_eq!(game.game_state(), GameState::InProgress);
+        
+        game.status = "2024-03-28T23:30:00Z".to_string();
+        game.period = 0;
+        assert_eq!(game.game_state(), GameState::Scheduled);
+        
+        game.status = "Postponed".to_string();
+        assert_eq!(game.game_state(), GameState::Postponed);
+        assert!(format!("{:#}", game).ends_with("(Postponed)"), "{:#}", game);
+    }
+    
+    #[test]
+    fn test_stats_are_final() {
+        let page = |statuses: &[&str]| serde_json::json!({
+            "data": statuses.iter().map(|status| serde_json::json!({"game": {"status": status}})).collect::<Vec<_>>()
+        });
+        assert!(stats_are_final(&page(&["Final", "Final"])));
+        assert!(!stats_are_final(&page(&["Final", "4th Qtr"])));
+        assert!(!stats_are_final(&page(&[])));
+        assert!(!stats_are_final(&serde_json::json!({"error": "Unauthorized"})));
+    }
+    
+    #[test]
+    fn test_summarize_team_games() {
+        // Lakers (14) win 110-108 at home
+        let win = mock_game();
+        
+        // Lakers lose 99-112 on the road
+        let mut loss = mock_game();
+        loss.home_team = win.visitor_team.clone();
+        loss.visitor_team = mock_team();
+        loss.home_team_score = 112;
+        loss.visitor_team_score = 99;
+        
+        // Lakers win 120-100 at home
+        let mut blowout = mock_game();
+        blowout.home_team_score = 120;
+        blowout.visitor_team_score = 100;
+        
+        // A scheduled game isn't counted
+        let mut scheduled = mock_game();
+        scheduled.status = "7:30 pm ET".to_string();
+        scheduled.home_team_score = 0;
+        scheduled.visitor_team_score = 0;
+        
+        let summary = summarize_team_games(14, &[win, loss, blowout, scheduled]);
+        assert_eq!(summary, TeamGamesSummary { wins: 2, losses: 1, point_differential: 9 });
+        assert_eq!(summary.games(), 3);
+        assert_eq!(summary.to_string(), "Record in these games: 2-1 (avg. point differential: +3.0)");
+        
+        // From the other side of the same games
+        let summary = summarize_team_games(2, &[mock_game()]);
+        assert_eq!(summary.to_string(), "Record in these games: 0-1 (avg. point differential: -2.0)");
+    }
+    
+    #[test]
+    fn test_summarize_team_games_none_completed() {
+        let mut scheduled = mock_game();
+        scheduled.status = "7:30 pm ET".to_string();
+        
+        let summary = summarize_team_games(14, &[scheduled]);
+        assert_eq!(summary.games(), 0);
+        assert!(summary.average_differential().is_none());
+        assert_eq!(summary.to_string(), "Record in these games: no completed games");
+    }
+    
+    #[test]
+    fn test_injury_status() {
+        let fixture = r#"{
+            "data": [
+                {
+                    "player": {"id": 132, "first_name": "Luka", "last_name": "Doncic", "position": "G", "team_id": 7, "jersey_number": "77"},
+                    "return_date": "Nov 17",
+                    "description": "Nov 12: Doncic (ankle) is out for Tuesday's game.",
+                    "status": "Out"
+                },
+                {
+                    "player": {"id": 145, "first_name": "Kyrie", "last_name": "Irving", "position": "G", "team_id": 7},
+                    "return_date": null,
+                    "description": null,
+                    "status": "Day-To-Day"
+                }
+            ],
+            "meta": {"next_cursor": null, "per_page": 100}
+        }"#;
+        let response: PaginatedResponse<PlayerInjury> = serde_json::from_str(fixture).unwrap();
+        let injuries = response.data;
+        
+        assert_eq!(injuries[0].injury(), Some("ankle"));
+        assert_eq!(injury_status(&injuries, 132), "Out (ankle), expected return Nov 17");
+        assert_eq!(injury_status(&injuries, 145), "Day-To-Day");
+        
+        // Players missing from the report are healthy
+        assert_eq!(injury_status(&injuries, 237), "Healthy");
+        assert_eq!(injury_status(&[], 132), "Healthy");
     }
 } 
\ No newline at end of file
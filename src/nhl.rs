@@ -0,0 +1,1073 @@
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tracing::debug;
+use crate::cache::DiskCache;
+use crate::clock::{Clock, SystemClock};
+use crate::color::{paint, Style};
+use crate::config::get_config;
+use crate::error::SportError;
+use crate::league::{self, DetailOptions, DetailSection};
+use crate::mlb::GameState;
+use crate::order::{in_order, separator, TeamOrdered};
+use crate::output::render;
+use crate::scorebug;
+use crate::types::{GameId, Record, Score, TeamId};
+use crate::utils::{determine_winner, schedule_range};
+
+fn get_nhl_api_base_url() -> Result<&'static str> {
+    Ok(crate::config::get_config().nhl_api_base_url.as_str())
+}
+
+/// NHL API client for making requests to the NHL Stats API
+#[derive(Clone)]
+pub struct NhlApi {
+    client: Client,
+    clock: Arc<dyn Clock>,
+    // The team list only changes between seasons, so it's fetched once per client
+    teams_cache: OnceLock<Vec<Team>>,
+    cache: Option<DiskCache>,
+}
+
+/// Player information
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Player {
+    pub id: u32,
+    #[serde(rename = "fullName")]
+    pub full_name: String,
+    #[serde(rename = "primaryNumber")]
+    pub primary_number: Option<String>,
+    #[serde(rename = "currentTeam")]
+    pub current_team: Option<Team>,
+    #[serde(rename = "primaryPosition")]
+    pub position: Option<Position>,
+    #[serde(rename = "birthDate")]
+    pub birth_date: Option<String>,
+    #[serde(rename = "birthCity")]
+    pub birth_city: Option<String>,
+    #[serde(rename = "birthCountry")]
+    pub birth_country: Option<String>,
+    pub height: Option<String>,
+    pub weight: Option<u32>,
+    #[serde(rename = "currentAge")]
+    pub current_age: Option<u32>,
+    /// "L" or "R"; which hand a skater shoots with or a goalie catches with
+    #[serde(rename = "shootsCatches")]
+    pub shoots_catches: Option<String>,
+    pub captain: Option<bool>,
+    #[serde(rename = "alternateCaptain")]
+    pub alternate_captain: Option<bool>,
+    pub active: Option<bool>,
+}
+
+/// Player position
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Position {
+    pub code: String,
+    pub name: String,
+    pub abbreviation: String,
+}
+
+/// Represents a National Hockey League team
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Team {
+    /// Unique identifier for the team
+    pub id: u32,
+    /// Full name of the team (e.g., "Nashville Predators")
+    pub name: String,
+    /// Short abbreviation (e.g., "NSH")
+    pub abbreviation: Option<String>,
+    /// Team name without the location (e.g., "Predators")
+    #[serde(rename = "teamName")]
+    pub team_name: Option<String>,
+    /// Location of the team (e.g., "Nashville")
+    #[serde(rename = "locationName")]
+    pub location_name: Option<String>,
+    #[serde(rename = "firstYearOfPlay")]
+    pub first_year_of_play: Option<String>,
+    pub active: Option<bool>,
+    pub venue: Option<Venue>,
+    pub conference: Option<Conference>,
+    pub division: Option<Division>,
+}
+
+impl Team {
+    /// Creates a new team with only its ID and name
+    pub fn new(id: u32, name: String) -> Self {
+        Self {
+            id,
+            name,
+            abbreviation: None,
+            team_name: None,
+            location_name: None,
+            first_year_of_play: None,
+            active: None,
+            venue: None,
+            conference: None,
+            division: None,
+        }
+    }
+
+    /// Abbreviation if known, otherwise the team name
+    fn short_label(&self) -> &str {
+        self.abbreviation.as_deref().unwrap_or(&self.name)
+    }
+}
+
+/// Venue information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Venue {
+    pub name: String,
+}
+
+/// Conference information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Conference {
+    pub name: String,
+}
+
+/// Division information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Division {
+    pub name: String,
+}
+
+/// Represents a single NHL game with its associated data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Game {
+    /// Unique identifier for the game
+    pub game_pk: u32,
+    /// ISO 8601 formatted date and time of the game
+    pub game_date: String,
+    /// Current status of the game
+    pub status: GameState,
+    /// Teams participating in the game
+    pub teams: GameTeams,
+    /// Name of the arena the game is played in
+    pub venue: Option<String>,
+    /// Period being played (e.g. "2nd"), or the last one played once the game is over
+    pub current_period: Option<String>,
+    /// Time left in the current period (e.g. "12:34")
+    pub period_time_remaining: Option<String>,
+}
+
+/// Game teams
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameTeams {
+    pub away: GameTeam,
+    pub home: GameTeam,
+}
+
+/// Game team
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameTeam {
+    pub team: Team,
+    pub score: Option<Score>,
+    /// Wins and regulation losses going into the game
+    pub league_record: Option<Record>,
+    /// Overtime and shootout losses, which hockey records keep apart from regulation losses
+    pub overtime_losses: Option<u32>,
+}
+
+impl GameTeam {
+    /// The team's record as wins-losses-overtime losses (e.g. "10-5-2")
+    pub fn record_label(&self) -> Option<String> {
+        self.league_record.map(|record| format!("{}-{}", record, self.overtime_losses.unwrap_or(0)))
+    }
+}
+
+/// Goals scored by each team in one period
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PeriodScore {
+    /// "1st", "2nd", "3rd", "OT", "SO"
+    pub label: String,
+    pub away: u32,
+    pub home: u32,
+}
+
+/// Game with period-by-period breakdown
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GameLinescore {
+    pub game_pk: u32,
+    pub away_team: Team,
+    pub home_team: Team,
+    pub periods: Vec<PeriodScore>,
+    pub away_goals: Option<u32>,
+    pub home_goals: Option<u32>,
+    pub away_shots: Option<u32>,
+    pub home_shots: Option<u32>,
+}
+
+impl Game {
+    /// Returns true if the game has started
+    pub fn has_started(&self) -> bool {
+        self.status.has_started()
+    }
+
+    /// Returns true if the game is finished
+    pub fn is_finished(&self) -> bool {
+        matches!(self.status, GameState::Final)
+    }
+
+    /// Returns the winning team, if the game is finished
+    pub fn winner(&self) -> Option<&Team> {
+        if !self.is_finished() {
+            return None;
+        }
+        let score = |team: &GameTeam| team.score.map(|score| score.0);
+        match determine_winner(score(&self.teams.away), score(&self.teams.home)) {
+            Some(0) => Some(&self.teams.away.team),
+            Some(_) => Some(&self.teams.home.team),
+            None => None,
+        }
+    }
+
+    /// Status with how the game stands: "Live, 2nd 12:34" while it's being played, and
+    /// "Final/OT" or "Final/SO" for a game decided after regulation
+    pub fn status_label(&self) -> String {
+        match (&self.status, self.current_period.as_deref()) {
+            (GameState::Live, Some(period)) => match self.period_time_remaining.as_deref() {
+                Some(time) => format!("Live, {} {}", period, time),
+                None => format!("Live, {}", period),
+            },
+            (GameState::Final, Some(period)) if !is_regulation(period) => format!("Final/{}", period),
+            (status, _) => status.to_string(),
+        }
+    }
+
+    /// One-line result such as "NSH 3 @ SEA 4 (Final/OT)", with the period for live games
+    pub fn summary_line(&self) -> String {
+        let team = |team: &GameTeam| match team.score.filter(|_| self.has_started()) {
+            Some(score) => format!("{} {}", team.team.short_label(), score),
+            None => team.team.short_label().to_string(),
+        };
+        format!("{} @ {} ({})", team(&self.teams.away), team(&self.teams.home), self.status_label())
+    }
+}
+
+impl NhlApi {
+    /// Create a new NHL API client
+    pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+
+    /// Create a new NHL API client that uses the given clock for "today"
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            client: Client::builder()
+                .timeout(Duration::from_secs(get_config().request_timeout_secs))
+                .build()
+                .expect("Failed to create HTTP client"),
+            clock,
+            teams_cache: OnceLock::new(),
+            cache: DiskCache::from_config().filter(|_| get_config().cache_enabled),
+        }
+    }
+
+    // Send a GET request, or answer it from a recorded fixture when replaying
+    async fn fetch(&self, url: &str) -> Result<reqwest::Response> {
+        match get_config().replay_dir {
+            Some(ref dir) => crate::replay::response(dir, "nhl", get_nhl_api_base_url()?, url),
+            None => {
+                let response = crate::retry::send_with_retry(get_config().http_retries, || self.client.get(url).send()).await?;
+                crate::replay::dump(get_config().dump_raw_dir.as_deref(), "nhl", get_nhl_api_base_url()?, url, response).await
+            }
+        }
+    }
+
+    /// Get player information by ID
+    pub async fn get_player(&self, player_id: u32) -> Result<Player> {
+        let url = format!("{}/people/{}", get_nhl_api_base_url()?, player_id);
+        let response = self.fetch(&url).await?;
+        let not_found = || SportError::NotFound(format!("Player {}", player_id));
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(not_found().into());
+        }
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch player data: HTTP {}", response.status()));
+        }
+
+        let data = response.json::<Value>().await?;
+        let player = data["people"].get(0).ok_or_else(not_found)?;
+
+        let player: Player = serde_json::from_value(player.to_owned())?;
+        Ok(player)
+    }
+
+    /// Get team information by ID
+    pub async fn get_team(&self, team_id: u32) -> Result<Team> {
+        let url = format!("{}/teams/{}", get_nhl_api_base_url()?, team_id);
+        let response = self.fetch(&url).await?;
+        let not_found = || SportError::NotFound(format!("Team {}", team_id));
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(not_found().into());
+        }
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch team data: HTTP {}", response.status()));
+        }
+
+        let data = response.json::<Value>().await?;
+        let team = data["teams"].get(0).ok_or_else(not_found)?;
+
+        let team: Team = serde_json::from_value(team.to_owned())?;
+        Ok(team)
+    }
+
+    /// Get all NHL teams, sorted by division then name
+    pub async fn get_all_teams(&self) -> Result<Vec<Team>> {
+        if let Some(teams) = self.teams_cache.get() {
+            return Ok(teams.clone());
+        }
+
+        let url = format!("{}/teams", get_nhl_api_base_url()?);
+        let response = self.fetch(&url).await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch teams: HTTP {}", response.status()));
+        }
+
+        let data = response.json::<Value>().await?;
+        let teams = data.get("teams").cloned().unwrap_or(Value::Array(Vec::new()));
+        let mut teams: Vec<Team> = serde_json::from_value(teams)?;
+
+        teams.sort_by(|a, b| {
+            let division = |team: &Team| team.division.as_ref().map(|d| d.name.clone()).unwrap_or_default();
+            division(a).cmp(&division(b)).then_with(|| a.name.cmp(&b.name))
+        });
+
+        Ok(self.teams_cache.get_or_init(|| teams).clone())
+    }
+
+    /// Get schedule for a team, for the current month unless dates are given
+    pub async fn get_team_schedule(&self, team_id: u32, start_date: Option<String>, end_date: Option<String>) -> Result<Vec<Game>> {
+        let (start, end) = schedule_range(self.clock.today(), start_date, end_date)?;
+        let url = format!(
+            "{}/schedule?teamId={}&startDate={}&endDate={}&expand=schedule.teams,schedule.linescore",
+            get_nhl_api_base_url()?, team_id, start, end
+        );
+        let response = self.fetch(&url).await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch schedule data: HTTP {}", response.status()));
+        }
+
+        let data = response.json::<Value>().await?;
+        Ok(parse_schedule(&data))
+    }
+
+    /// Get game information by ID
+    pub async fn get_game(&self, game_id: u32) -> Result<Game> {
+        let url = format!("{}/game/{}/feed/live", get_nhl_api_base_url()?, game_id);
+        let response = self.fetch(&url).await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(SportError::NotFound(format!("Game {}", game_id)).into());
+        }
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch game data: HTTP {}", response.status()));
+        }
+
+        let data = response.json::<Value>().await?;
+        parse_game_feed(&data)
+    }
+
+    /// Get goals and shots for each period of a game
+    pub async fn get_game_linescore(&self, game_id: u32) -> Result<GameLinescore> {
+        let url = format!("{}/game/{}/linescore", get_nhl_api_base_url()?, game_id);
+        let response = self.fetch(&url).await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch linescore for game {}: HTTP {}", game_id, response.status()));
+        }
+
+        let data = response.json::<Value>().await?;
+        Ok(parse_linescore(game_id, &data))
+    }
+
+    /// Get the period-by-period breakdown of a finished game, reading it through the response
+    /// cache and storing it once the linescore itself says the game is final
+    pub async fn get_final_game_linescore(&self, game_id: u32) -> Result<GameLinescore> {
+        let url = format!("{}/game/{}/linescore", get_nhl_api_base_url()?, game_id);
+        let response = crate::cache::fetch_final(self.cache.as_ref(), &url, linescore_is_final, self.fetch(&url)).await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch linescore for game {}: HTTP {}", game_id, response.status()));
+        }
+
+        let data = response.json::<Value>().await?;
+        Ok(parse_linescore(game_id, &data))
+    }
+
+    /// Get all games scheduled for today
+    pub async fn get_todays_games(&self) -> Result<Vec<Game>> {
+        let today = self.clock.today();
+        self.get_games_by_date(today).await
+    }
+
+    /// Get all games scheduled for yesterday
+    pub async fn get_yesterdays_games(&self) -> Result<Vec<Game>> {
+        let yesterday = self.clock.today().pred_opt().ok_or_else(|| anyhow!("Failed to calculate yesterday's date"))?;
+        self.get_games_by_date(yesterday).await
+    }
+
+    /// Get all games scheduled for a specific date
+    pub async fn get_games_by_date(&self, date: NaiveDate) -> Result<Vec<Game>> {
+        let date_str = date.format("%Y-%m-%d").to_string();
+        let url = format!(
+            "{}/schedule?date={}&expand=schedule.teams,schedule.linescore",
+            get_nhl_api_base_url()?, date_str
+        );
+
+        let response = self.fetch(&url).await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch games for {}: HTTP {}", date_str, response.status()));
+        }
+
+        let data = response.json::<Value>().await?;
+        Ok(parse_schedule(&data))
+    }
+}
+
+impl Default for NhlApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Regulation periods; anything past them is overtime or a shootout
+fn is_regulation(period: &str) -> bool {
+    matches!(period, "1st" | "2nd" | "3rd")
+}
+
+// Map a status object to our GameState. A postponed game is still a "Preview" in
+// abstractGameState, so the detailed state is checked first.
+fn game_state(status: &Value) -> GameState {
+    let abstract_state = status["abstractGameState"].as_str().unwrap_or("Unknown");
+    let detailed_state = status["detailedState"].as_str().unwrap_or("Unknown");
+    match (abstract_state, detailed_state) {
+        (_, "Postponed") => GameState::Postponed,
+        ("Final", _) => GameState::Final,
+        ("Live", _) => GameState::Live,
+        ("Preview", _) => GameState::Scheduled,
+        _ => GameState::Unknown,
+    }
+}
+
+fn count(value: &Value) -> Option<u32> {
+    value.as_u64().map(|n| n as u32)
+}
+
+fn text(value: &Value) -> Option<String> {
+    value.as_str().map(String::from)
+}
+
+// A team object, keeping just the ID and name when the rest is missing or malformed
+fn parse_team(value: &Value) -> Team {
+    serde_json::from_value(value.clone()).unwrap_or_else(|_| {
+        Team::new(count(&value["id"]).unwrap_or(0), value["name"].as_str().unwrap_or("").to_string())
+    })
+}
+
+// The period and clock of a linescore, if the game has started
+fn period_info(linescore: &Value) -> (Option<String>, Option<String>) {
+    (
+        text(&linescore["currentPeriodOrdinal"]),
+        text(&linescore["currentPeriodTimeRemaining"]).filter(|time| time != "Final"),
+    )
+}
+
+/// Build the games from every date of a schedule response
+fn parse_schedule(data: &Value) -> Vec<Game> {
+    data["dates"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|date| date["games"].as_array())
+        .flatten()
+        .map(parse_schedule_game)
+        .collect()
+}
+
+/// Build a game from an entry in a schedule response
+fn parse_schedule_game(game_data: &Value) -> Game {
+    let status = game_state(&game_data["status"]);
+    debug!("Game {} status: {}", game_data["gamePk"].as_u64().unwrap_or(0), status);
+
+    let team = |side: &str| {
+        let entry = &game_data["teams"][side];
+        let record = &entry["leagueRecord"];
+        GameTeam {
+            team: parse_team(&entry["team"]),
+            score: count(&entry["score"]).map(Score),
+            league_record: count(&record["wins"]).zip(count(&record["losses"])).map(|(wins, losses)| Record::new(wins, losses)),
+            overtime_losses: count(&record["ot"]),
+        }
+    };
+
+    let (current_period, period_time_remaining) = period_info(&game_data["linescore"]);
+    Game {
+        game_pk: count(&game_data["gamePk"]).unwrap_or(0),
+        game_date: game_data["gameDate"].as_str().unwrap_or("").to_string(),
+        status,
+        teams: GameTeams { away: team("away"), home: team("home") },
+        venue: text(&game_data["venue"]["name"]),
+        current_period,
+        period_time_remaining,
+    }
+}
+
+/// Parse a game from a feed/live response
+fn parse_game_feed(data: &Value) -> Result<Game> {
+    let game_data = data.get("gameData").ok_or_else(|| anyhow!("Missing game data"))?;
+    let linescore = &data["liveData"]["linescore"];
+
+    let team = |side: &str| GameTeam {
+        team: parse_team(&game_data["teams"][side]),
+        score: count(&linescore["teams"][side]["goals"]).map(Score),
+        league_record: None,
+        overtime_losses: None,
+    };
+
+    let (current_period, period_time_remaining) = period_info(linescore);
+    Ok(Game {
+        game_pk: count(&game_data["game"]["pk"]).ok_or_else(|| anyhow!("Missing game ID"))?,
+        game_date: game_data["datetime"]["dateTime"].as_str().unwrap_or("").to_string(),
+        status: game_state(&game_data["status"]),
+        teams: GameTeams { away: team("away"), home: team("home") },
+        venue: text(&game_data["venue"]["name"]),
+        current_period,
+        period_time_remaining,
+    })
+}
+
+// Whether a linescore response describes a finished game; the clock reads "Final" once it is
+fn linescore_is_final(linescore: &Value) -> bool {
+    linescore["currentPeriodTimeRemaining"] == "Final"
+}
+
+/// Parse a game's linescore response into per-period goals
+fn parse_linescore(game_pk: u32, data: &Value) -> GameLinescore {
+    let teams = &data["teams"];
+    let mut periods: Vec<PeriodScore> = data["periods"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|period| PeriodScore {
+            label: period["ordinalNum"].as_str().unwrap_or("").to_string(),
+            away: count(&period["away"]["goals"]).unwrap_or(0),
+            home: count(&period["home"]["goals"]).unwrap_or(0),
+        })
+        .collect();
+
+    // A shootout isn't listed among the periods; the winner is credited with one goal for it
+    if data["hasShootout"].as_bool().unwrap_or(false) {
+        let info = &data["shootoutInfo"];
+        let away = count(&info["away"]["scores"]).unwrap_or(0);
+        let home = count(&info["home"]["scores"]).unwrap_or(0);
+        periods.push(PeriodScore {
+            label: "SO".to_string(),
+            away: u32::from(away > home),
+            home: u32::from(home > away),
+        });
+    }
+
+    GameLinescore {
+        game_pk,
+        away_team: parse_team(&teams["away"]["team"]),
+        home_team: parse_team(&teams["home"]["team"]),
+        periods,
+        away_goals: count(&teams["away"]["goals"]),
+        home_goals: count(&teams["home"]["goals"]),
+        away_shots: count(&teams["away"]["shotsOnGoal"]),
+        home_shots: count(&teams["home"]["shotsOnGoal"]),
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Name: {}", self.full_name)?;
+        if let Some(ref num) = self.primary_number {
+            writeln!(f, "Number: {}", num)?;
+        }
+        if let Some(ref position) = self.position {
+            writeln!(f, "Position: {} ({})", position.name, position.abbreviation)?;
+        }
+        if let Some(ref team) = self.current_team {
+            writeln!(f, "Team: {}", team.name)?;
+        }
+        if let Some(ref age) = self.current_age {
+            writeln!(f, "Age: {}", age)?;
+        }
+        if let Some(ref birth_date) = self.birth_date {
+            writeln!(f, "Birth Date: {}", birth_date)?;
+        }
+        if let Some(ref birth_city) = self.birth_city {
+            if let Some(ref birth_country) = self.birth_country {
+                writeln!(f, "Birthplace: {}, {}", birth_city, birth_country)?;
+            }
+        }
+        if let Some(ref height) = self.height {
+            if let Some(weight) = self.weight {
+                writeln!(f, "Height/Weight: {} / {} lbs", height, weight)?;
+            }
+        }
+        if let Some(ref hand) = self.shoots_catches {
+            let is_goalie = self.position.as_ref().is_some_and(|position| position.code == "G");
+            writeln!(f, "{}: {}", if is_goalie { "Catches" } else { "Shoots" }, hand)?;
+        }
+        if self.captain == Some(true) {
+            writeln!(f, "Captain: Yes")?;
+        } else if self.alternate_captain == Some(true) {
+            writeln!(f, "Alternate Captain: Yes")?;
+        }
+        if let Some(active) = self.active {
+            writeln!(f, "Active: {}", if active { "Yes" } else { "No" })?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Team {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Team: {}", self.name)?;
+
+        if let Some(ref abbr) = self.abbreviation {
+            writeln!(f, "Abbreviation: {}", abbr)?;
+        }
+
+        if let Some(ref venue) = self.venue {
+            writeln!(f, "Venue: {}", venue.name)?;
+        }
+
+        if let Some(ref conference) = self.conference {
+            if let Some(ref division) = self.division {
+                writeln!(f, "Conference/Division: {} / {}", conference.name, division.name)?;
+            }
+        }
+
+        if let Some(ref first_year) = self.first_year_of_play {
+            writeln!(f, "First Year of Play: {}", first_year)?;
+        }
+
+        if let Some(active) = self.active {
+            writeln!(f, "Active: {}", if active { "Yes" } else { "No" })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_ordered(f, get_config().home_first)
+    }
+}
+
+impl TeamOrdered for Game {
+    fn fmt_ordered(&self, f: &mut fmt::Formatter<'_>, home_first: bool) -> fmt::Result {
+        // With color on, the winner's score is green, the loser is dimmed and a live status is yellow
+        let winner_id = self.winner().map(|team| team.id);
+        let score = |team: &GameTeam| {
+            let score = team.score.unwrap_or(Score(0));
+            if winner_id == Some(team.team.id) {
+                paint(score, Style::Winner)
+            } else {
+                score.to_string()
+            }
+        };
+        let status = if self.status.is_live() {
+            paint(self.status_label(), Style::Live)
+        } else {
+            self.status_label()
+        };
+
+        // Compact single-line form: {:#}
+        if f.alternate() {
+            let (first, second) = in_order(&self.teams.away, &self.teams.home, home_first);
+            return write!(
+                f,
+                "{} {} {} {} {} {} ({})",
+                self.game_date.split('T').next().unwrap_or(&self.game_date),
+                first.team.short_label(),
+                score(first),
+                separator(home_first),
+                second.team.short_label(),
+                score(second),
+                status
+            );
+        }
+
+        writeln!(f, "Game ID: {}", self.game_pk)?;
+        writeln!(f, "Date: {}", self.game_date.split('T').next().unwrap_or(&self.game_date))?;
+        writeln!(f, "Status: {}", status)?;
+        if self.status == GameState::Scheduled {
+            writeln!(f, "Start Time: {}", scorebug::start_time(&self.game_date))?;
+        }
+        writeln!(f, "Teams:")?;
+
+        let line = |label: &str, team: &GameTeam| {
+            let mut line = format!("  {}: {} ({})", label, team.team.name, score(team));
+            if let Some(record) = team.record_label() {
+                line.push_str(&format!(", {}", record));
+            }
+            if winner_id.is_some_and(|id| id != team.team.id) {
+                paint(line, Style::Dimmed)
+            } else {
+                line
+            }
+        };
+        let (first, second) = in_order(line("Away", &self.teams.away), line("Home", &self.teams.home), home_first);
+        writeln!(f, "{}", first)?;
+        writeln!(f, "{}", second)?;
+
+        if let Some(winner) = self.winner() {
+            writeln!(f, "Winner: {}", winner.name)?;
+        }
+        if let Some(ref venue) = self.venue {
+            writeln!(f, "Venue: {}", venue)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for GameLinescore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_ordered(f, get_config().home_first)
+    }
+}
+
+impl TeamOrdered for GameLinescore {
+    fn fmt_ordered(&self, f: &mut fmt::Formatter<'_>, home_first: bool) -> fmt::Result {
+        // Compact single-line form: {:#}
+        if f.alternate() {
+            let (first, second) = in_order(
+                (&self.away_team, self.away_goals, self.away_shots),
+                (&self.home_team, self.home_goals, self.home_shots),
+                home_first,
+            );
+            return write!(
+                f,
+                "{} {} {} {} {} (SOG {}-{})",
+                first.0.short_label(),
+                first.1.unwrap_or(0),
+                separator(home_first),
+                second.0.short_label(),
+                second.1.unwrap_or(0),
+                first.2.unwrap_or(0),
+                second.2.unwrap_or(0)
+            );
+        }
+
+        // A header row with one column per period, then a row per team in display order
+        write!(f, "     ")?;
+        for period in &self.periods {
+            write!(f, " {:>3}", period.label)?;
+        }
+        writeln!(f, "  | {:>2} {:>3}", "G", "SOG")?;
+        writeln!(f, "-----{}--+-------", "-".repeat(self.periods.len() * 4))?;
+
+        let away = (
+            self.away_team.short_label(),
+            self.periods.iter().map(|period| period.away).collect::<Vec<_>>(),
+            self.away_goals,
+            self.away_shots,
+        );
+        let home = (
+            self.home_team.short_label(),
+            self.periods.iter().map(|period| period.home).collect::<Vec<_>>(),
+            self.home_goals,
+            self.home_shots,
+        );
+        let (first, second) = in_order(away, home, home_first);
+        for (label, goals, total, shots) in [first, second] {
+            write!(f, "{:<4} ", label)?;
+            for goals in goals {
+                write!(f, " {:>3}", goals)?;
+            }
+            writeln!(f, "  | {:>2} {:>3}", total.unwrap_or(0), shots.unwrap_or(0))?;
+        }
+        Ok(())
+    }
+}
+
+impl league::League for NhlApi {
+    type Game = Game;
+
+    const NAME: &'static str = "NHL";
+    const PENDING_MESSAGE: &'static str = "Detailed information not available for games that haven't been completed.";
+
+    fn game_id(game: &Game) -> u32 {
+        game.game_pk
+    }
+
+    fn game_state(game: &Game) -> GameState {
+        game.status.clone()
+    }
+
+    fn summary_line(game: &Game) -> String {
+        game.summary_line()
+    }
+
+    fn team_ids(game: &Game) -> (u32, u32) {
+        (game.teams.away.team.id, game.teams.home.team.id)
+    }
+
+    fn favorite_teams(config: &crate::config::Config) -> &[u32] {
+        &config.favorite_nhl_teams
+    }
+
+    async fn games_by_date(&self, date: NaiveDate) -> Result<Vec<Game>> {
+        self.get_games_by_date(date).await
+    }
+
+    async fn todays_games(&self) -> Result<Vec<Game>> {
+        self.get_todays_games().await
+    }
+
+    async fn yesterdays_games(&self) -> Result<Vec<Game>> {
+        self.get_yesterdays_games().await
+    }
+
+    async fn team_schedule(&self, team_id: u32, start_date: Option<String>, end_date: Option<String>) -> Result<Vec<Game>> {
+        self.get_team_schedule(team_id, start_date, end_date).await
+    }
+
+    async fn team_abbreviations(&self) -> Result<Vec<(u32, String)>> {
+        Ok(self.get_all_teams().await?
+            .into_iter()
+            .filter_map(|team| team.abbreviation.map(|abbreviation| (team.id, abbreviation)))
+            .collect())
+    }
+
+    /// The period-by-period breakdown; there's no box score for NHL games yet
+    async fn game_detail(&self, game: &Game, options: DetailOptions) -> Vec<DetailSection> {
+        vec![DetailSection {
+            heading: "Period-by-Period Breakdown",
+            what: "period data",
+            body: self.get_final_game_linescore(game.game_pk).await.map(|linescore| render(&linescore, options.compact)),
+        }]
+    }
+}
+
+// Singleton instance for the API
+static NHL_API_INSTANCE: OnceLock<NhlApi> = OnceLock::new();
+
+/// The shared NHL API client behind the free functions, initialized on first use
+pub fn get_nhl_api() -> &'static NhlApi {
+    NHL_API_INSTANCE.get_or_init(NhlApi::new)
+}
+
+/// Get player information by ID
+pub async fn get_player(player_id: u32) -> Result<Player> {
+    get_nhl_api().get_player(player_id).await
+}
+
+/// Get team information by ID
+pub async fn get_team(team_id: impl Into<TeamId>) -> Result<Team> {
+    get_nhl_api().get_team(team_id.into().0).await
+}
+
+/// Get all NHL teams, sorted by division then name
+pub async fn get_all_teams() -> Result<Vec<Team>> {
+    get_nhl_api().get_all_teams().await
+}
+
+/// Get team schedule by ID
+pub async fn get_team_schedule(team_id: impl Into<TeamId>, start_date: Option<String>, end_date: Option<String>) -> Result<Vec<Game>> {
+    get_nhl_api().get_team_schedule(team_id.into().0, start_date, end_date).await
+}
+
+/// Get game information by ID
+pub async fn get_game(game_id: impl Into<GameId>) -> Result<Game> {
+    get_nhl_api().get_game(game_id.into().0).await
+}
+
+/// Get goals and shots for each period of a game
+pub async fn get_game_linescore(game_id: impl Into<GameId>) -> Result<GameLinescore> {
+    get_nhl_api().get_game_linescore(game_id.into().0).await
+}
+
+/// Get all games scheduled for today
+pub async fn get_todays_games() -> Result<Vec<Game>> {
+    get_nhl_api().get_todays_games().await
+}
+
+/// Get all games scheduled for yesterday
+pub async fn get_yesterdays_games() -> Result<Vec<Game>> {
+    get_nhl_api().get_yesterdays_games().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order::Ordered;
+
+    fn schedule_fixture() -> Value {
+        serde_json::json!({"dates": [{"date": "2023-10-10", "games": [
+            {
+                "gamePk": 2023020001,
+                "gameDate": "2023-10-10T21:30:00Z",
+                "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                "teams": {
+                    "away": {
+                        "score": 3,
+                        "leagueRecord": {"wins": 0, "losses": 0, "ot": 1},
+                        "team": {"id": 18, "name": "Nashville Predators", "abbreviation": "NSH"}
+                    },
+                    "home": {
+                        "score": 4,
+                        "leagueRecord": {"wins": 1, "losses": 0, "ot": 0},
+                        "team": {"id": 14, "name": "Tampa Bay Lightning", "abbreviation": "TBL"}
+                    }
+                },
+                "linescore": {"currentPeriodOrdinal": "OT", "currentPeriodTimeRemaining": "Final"},
+                "venue": {"name": "Amalie Arena"}
+            },
+            {
+                "gamePk": 2023020002,
+                "gameDate": "2023-10-11T00:00:00Z",
+                "status": {"abstractGameState": "Live", "detailedState": "In Progress"},
+                "teams": {
+                    "away": {"score": 1, "team": {"id": 16, "name": "Chicago Blackhawks", "abbreviation": "CHI"}},
+                    "home": {"score": 2, "team": {"id": 5, "name": "Pittsburgh Penguins", "abbreviation": "PIT"}}
+                },
+                "linescore": {"currentPeriodOrdinal": "2nd", "currentPeriodTimeRemaining": "12:34"}
+            },
+            {
+                "gamePk": 2023020003,
+                "gameDate": "2023-10-11T02:30:00Z",
+                "status": {"abstractGameState": "Preview", "detailedState": "Postponed"},
+                "teams": {
+                    "away": {"score": 0, "team": {"id": 52, "name": "Winnipeg Jets"}},
+                    "home": {"score": 0, "team": {"id": 6, "name": "Boston Bruins", "abbreviation": "BOS"}}
+                }
+            }
+        ]}]})
+    }
+
+    #[test]
+    fn test_parse_schedule() {
+        let games = parse_schedule(&schedule_fixture());
+        assert_eq!(games.len(), 3);
+
+        let opener = &games[0];
+        assert_eq!(opener.game_pk, 2023020001);
+        assert_eq!(opener.status, GameState::Final);
+        assert_eq!(opener.teams.away.score, Some(Score(3)));
+        assert_eq!(opener.teams.away.record_label().as_deref(), Some("0-0-1"));
+        assert_eq!(opener.winner().map(|team| team.id), Some(14));
+        assert_eq!(opener.venue.as_deref(), Some("Amalie Arena"));
+        assert_eq!(opener.period_time_remaining, None);
+
+        assert_eq!(games[1].status, GameState::Live);
+        assert_eq!(games[1].winner().map(|team| team.id), None);
+        // The detailed state wins over "Preview"
+        assert_eq!(games[2].status, GameState::Postponed);
+        assert!(parse_schedule(&serde_json::json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_summary_line() {
+        let games = parse_schedule(&schedule_fixture());
+        assert_eq!(games[0].summary_line(), "NSH 3 @ TBL 4 (Final/OT)");
+        assert_eq!(games[1].summary_line(), "CHI 1 @ PIT 2 (Live, 2nd 12:34)");
+        // Scores are left out before the game starts, and a team without an abbreviation uses its name
+        assert_eq!(games[2].summary_line(), "Winnipeg Jets @ BOS (Postponed)");
+    }
+
+    #[test]
+    fn test_game_display() {
+        let game = &parse_schedule(&schedule_fixture())[0];
+        assert_eq!(format!("{:#}", Ordered(game, false)), "2023-10-10 NSH 3 @ TBL 4 (Final/OT)");
+        assert_eq!(format!("{:#}", Ordered(game, true)), "2023-10-10 TBL 4 vs NSH 3 (Final/OT)");
+
+        let text = format!("{}", Ordered(game, false));
+        assert!(text.contains("  Away: Nashville Predators (3), 0-0-1\n  Home: Tampa Bay Lightning (4), 1-0-0\n"), "{}", text);
+        assert!(text.contains("Winner: Tampa Bay Lightning\n"), "{}", text);
+    }
+
+    #[test]
+    fn test_parse_game_feed() {
+        let feed = serde_json::json!({
+            "gameData": {
+                "game": {"pk": 2023020002},
+                "datetime": {"dateTime": "2023-10-11T00:00:00Z"},
+                "status": {"abstractGameState": "Live", "detailedState": "In Progress"},
+                "teams": {
+                    "away": {"id": 16, "name": "Chicago Blackhawks", "abbreviation": "CHI"},
+                    "home": {"id": 5, "name": "Pittsburgh Penguins", "abbreviation": "PIT"}
+                },
+                "venue": {"name": "PPG Paints Arena"}
+            },
+            "liveData": {"linescore": {
+                "currentPeriodOrdinal": "3rd",
+                "currentPeriodTimeRemaining": "04:10",
+                "teams": {"away": {"goals": 2}, "home": {"goals": 2}}
+            }}
+        });
+
+        let game = parse_game_feed(&feed).unwrap();
+        assert_eq!(game.game_pk, 2023020002);
+        assert_eq!(game.teams.home.score, Some(Score(2)));
+        assert_eq!(game.summary_line(), "CHI 2 @ PIT 2 (Live, 3rd 04:10)");
+        assert!(parse_game_feed(&serde_json::json!({})).is_err());
+    }
+
+    #[test]
+    fn test_parse_linescore() {
+        let data = serde_json::json!({
+            "periods": [
+                {"ordinalNum": "1st", "away": {"goals": 1}, "home": {"goals": 0}},
+                {"ordinalNum": "2nd", "away": {"goals": 0}, "home": {"goals": 1}},
+                {"ordinalNum": "3rd", "away": {"goals": 1}, "home": {"goals": 1}},
+                {"ordinalNum": "OT", "away": {"goals": 0}, "home": {"goals": 0}}
+            ],
+            "hasShootout": true,
+            "shootoutInfo": {"away": {"scores": 1}, "home": {"scores": 2}},
+            "teams": {
+                "away": {"team": {"id": 18, "name": "Nashville Predators", "abbreviation": "NSH"}, "goals": 2, "shotsOnGoal": 31},
+                "home": {"team": {"id": 14, "name": "Tampa Bay Lightning", "abbreviation": "TBL"}, "goals": 3, "shotsOnGoal": 28}
+            }
+        });
+
+        let linescore = parse_linescore(2023020001, &data);
+        assert_eq!(linescore.periods.len(), 5);
+        assert_eq!(format!("{:#}", Ordered(&linescore, false)), "NSH 2 @ TBL 3 (SOG 31-28)");
+
+        let table = format!("{}", Ordered(&linescore, false));
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "      1st 2nd 3rd  OT  SO  |  G SOG");
+        assert_eq!(lines[2], "NSH     1   0   1   0   0  |  2  31");
+        assert_eq!(lines[3], "TBL     0   1   1   0   1  |  3  28");
+
+        // Only a linescore whose clock reads "Final" is cached
+        assert!(!linescore_is_final(&data));
+        assert!(!linescore_is_final(&serde_json::json!({"currentPeriodTimeRemaining": "05:12"})));
+        assert!(linescore_is_final(&serde_json::json!({"currentPeriodTimeRemaining": "Final"})));
+    }
+
+    #[test]
+    fn test_player_display() {
+        let player: Player = serde_json::from_value(serde_json::json!({
+            "id": 8476883,
+            "fullName": "Andrei Vasilevskiy",
+            "primaryNumber": "88",
+            "primaryPosition": {"code": "G", "name": "Goalie", "type": "Goalie", "abbreviation": "G"},
+            "currentTeam": {"id": 14, "name": "Tampa Bay Lightning"},
+            "shootsCatches": "L",
+            "active": true
+        })).unwrap();
+
+        let text = player.to_string();
+        assert!(text.contains("Position: Goalie (G)\nTeam: Tampa Bay Lightning\n"), "{}", text);
+        assert!(text.contains("Catches: L\n"), "{}", text);
+    }
+}
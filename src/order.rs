@@ -0,0 +1,47 @@
+use std::fmt;
+
+/// A value whose display lists an away and a home team, and can put either one first
+pub trait TeamOrdered {
+    /// Format the value with the home team first if `home_first` is set, otherwise the away team first
+    fn fmt_ordered(&self, f: &mut fmt::Formatter<'_>, home_first: bool) -> fmt::Result;
+}
+
+/// Display adapter that renders a value in an explicit team order instead of the configured one
+pub struct Ordered<'a, T>(pub &'a T, pub bool);
+
+impl<T: TeamOrdered> fmt::Display for Ordered<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_ordered(f, self.1)
+    }
+}
+
+/// Put an (away, home) pair into display order
+pub fn in_order<T>(away: T, home: T, home_first: bool) -> (T, T) {
+    if home_first {
+        (home, away)
+    } else {
+        (away, home)
+    }
+}
+
+/// Word between the two teams on a one-line matchup: "@" when away is first, "vs" when home is first
+pub fn separator(home_first: bool) -> &'static str {
+    if home_first {
+        "vs"
+    } else {
+        "@"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_order() {
+        assert_eq!(in_order("away", "home", false), ("away", "home"));
+        assert_eq!(in_order("away", "home", true), ("home", "away"));
+        assert_eq!(separator(false), "@");
+        assert_eq!(separator(true), "vs");
+    }
+}
@@ -0,0 +1,262 @@
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::mlb::Game as MlbGame;
+use crate::nba::Game as NbaGame;
+use crate::nhl::Game as NhlGame;
+
+/// Formats results can be rendered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    /// Only box scores have a CSV form; see `GameStats::to_csv`
+    Csv,
+    /// GitHub-flavored markdown tables, also only for box scores; see `GameStats::to_markdown`
+    Markdown,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            "markdown" | "md" => Ok(OutputFormat::Markdown),
+            other => Err(format!("unknown output format '{}' (expected text, json, csv or markdown)", other)),
+        }
+    }
+}
+
+impl OutputFormat {
+    /// Render a value in this format
+    pub fn render<T: Serialize + fmt::Display>(&self, value: &T) -> anyhow::Result<String> {
+        match self {
+            OutputFormat::Text => Ok(value.to_string()),
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(value)?),
+            OutputFormat::Csv => Err(anyhow::anyhow!("CSV output is only available for box scores")),
+            OutputFormat::Markdown => Err(anyhow::anyhow!("Markdown output is only available for box scores")),
+        }
+    }
+
+    /// The format's name as given on the command line
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Markdown => "markdown",
+        }
+    }
+
+    /// Whether this format only covers box scores, printed as tables
+    pub fn is_table(&self) -> bool {
+        matches!(self, OutputFormat::Csv | OutputFormat::Markdown)
+    }
+}
+
+/// An extra output destination, given on the command line as `format:path` (e.g. `json:games.json`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputSpec {
+    pub format: OutputFormat,
+    pub path: PathBuf,
+}
+
+impl FromStr for OutputSpec {
+    type Err = String;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let (format, path) = spec
+            .split_once(':')
+            .ok_or_else(|| format!("expected FORMAT:PATH (e.g. json:games.json), got '{}'", spec))?;
+        let format: OutputFormat = format.parse()?;
+        if format.is_table() {
+            return Err(format!("{} is only available for box scores, with --format {}", format.name(), format.name()));
+        }
+
+        if path.is_empty() {
+            return Err(format!("missing output path in '{}'", spec));
+        }
+
+        let path = PathBuf::from(path);
+        if path.is_dir() {
+            return Err(format!("'{}' is a directory", path.display()));
+        }
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() && !parent.is_dir() {
+                return Err(format!("directory '{}' does not exist", parent.display()));
+            }
+        }
+
+        Ok(OutputSpec { format, path })
+    }
+}
+
+/// Format a value, using its single-line `{:#}` form when compact output is requested
+pub fn render<T: fmt::Display>(value: &T, compact: bool) -> String {
+    if compact {
+        format!("{:#}", value)
+    } else {
+        format!("{}", value)
+    }
+}
+
+/// Quote a CSV field if it contains a comma, quote or line break, doubling any quotes inside it
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Escape a markdown table cell, so a pipe in a player's name doesn't start a new column
+pub fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+/// A GitHub-flavored markdown table with a header row and `|---|` separator
+pub fn markdown_table<S: AsRef<str>>(headers: &[&str], rows: &[Vec<S>]) -> String {
+    let line = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+    let mut table = line(headers.iter().map(|header| markdown_cell(header)).collect());
+    table.push_str(&format!("|{}\n", "---|".repeat(headers.len())));
+    for row in rows {
+        table.push_str(&line(row.iter().map(|cell| markdown_cell(cell.as_ref())).collect()));
+    }
+    table
+}
+
+/// Games fetched for each day of a listing run
+#[derive(Debug, Default, Serialize)]
+pub struct GamesReport {
+    pub days: Vec<DayReport>,
+}
+
+/// Games fetched for a single day; a league is omitted if it wasn't fetched or failed
+#[derive(Debug, Serialize)]
+pub struct DayReport {
+    pub date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mlb: Option<Vec<MlbGame>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nba: Option<Vec<NbaGame>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nhl: Option<Vec<NhlGame>>,
+}
+
+impl DayReport {
+    /// Create an empty report for a date
+    pub fn new(date: String) -> Self {
+        Self { date, mlb: None, nba: None, nhl: None }
+    }
+}
+
+impl fmt::Display for GamesReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for day in &self.days {
+            writeln!(f, "{}", day.date)?;
+            if let Some(ref games) = day.mlb {
+                writeln!(f, "  MLB:")?;
+                for game in games {
+                    writeln!(f, "    {:#}", game)?;
+                }
+            }
+            if let Some(ref games) = day.nba {
+                writeln!(f, "  NBA:")?;
+                for game in games {
+                    writeln!(f, "    {:#}", game)?;
+                }
+            }
+            if let Some(ref games) = day.nhl {
+                writeln!(f, "  NHL:")?;
+                for game in games {
+                    writeln!(f, "    {:#}", game)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_spec_parsing() {
+        let spec: OutputSpec = "json:games.json".parse().unwrap();
+        assert_eq!(spec.format, OutputFormat::Json);
+        assert_eq!(spec.path, PathBuf::from("games.json"));
+
+        let spec: OutputSpec = "TEXT:games.txt".parse().unwrap();
+        assert_eq!(spec.format, OutputFormat::Text);
+
+        assert!("games.json".parse::<OutputSpec>().unwrap_err().contains("FORMAT:PATH"));
+        assert!("xml:games.xml".parse::<OutputSpec>().unwrap_err().contains("unknown output format 'xml'"));
+        assert!("csv:games.csv".parse::<OutputSpec>().unwrap_err().contains("only available for box scores"));
+        assert!("md:box.md".parse::<OutputSpec>().unwrap_err().contains("markdown is only available for box scores"));
+        assert!("json:".parse::<OutputSpec>().unwrap_err().contains("missing output path"));
+        assert!("json:/no/such/dir/games.json".parse::<OutputSpec>().unwrap_err().contains("does not exist"));
+
+        let dir = std::env::temp_dir();
+        let spec = format!("json:{}", dir.display());
+        assert!(spec.parse::<OutputSpec>().unwrap_err().contains("is a directory"));
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("Mike Trout"), "Mike Trout");
+        assert_eq!(csv_field("Griffey, Jr."), "\"Griffey, Jr.\"");
+        assert_eq!(csv_field("Nick \"The Stick\""), "\"Nick \"\"The Stick\"\"\"");
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        assert_eq!("markdown".parse::<OutputFormat>(), Ok(OutputFormat::Markdown));
+        assert_eq!("md".parse::<OutputFormat>(), Ok(OutputFormat::Markdown));
+        
+        let table = markdown_table(&["NAME", "AB"], &[vec!["Mike Trout", "4"], vec!["Pipe | Name", "3"]]);
+        assert_eq!(table, "| NAME | AB |\n|---|---|\n| Mike Trout | 4 |\n| Pipe \\| Name | 3 |\n");
+        
+        // No rows still makes a well-formed table
+        assert_eq!(markdown_table::<&str>(&["R", "H", "E"], &[]), "| R | H | E |\n|---|---|---|\n");
+    }
+
+    #[test]
+    fn test_render_compact() {
+        struct Sample;
+
+        impl fmt::Display for Sample {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if f.alternate() {
+                    write!(f, "one line")
+                } else {
+                    write!(f, "first line\nsecond line")
+                }
+            }
+        }
+
+        assert_eq!(render(&Sample, false), "first line\nsecond line");
+        assert_eq!(render(&Sample, true), "one line");
+    }
+
+    #[test]
+    fn test_render_report() {
+        let report = GamesReport {
+            days: vec![DayReport { date: "2024-03-28".to_string(), mlb: Some(Vec::new()), nba: None, nhl: None }],
+        };
+
+        let json = OutputFormat::Json.render(&report).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["days"][0]["date"], "2024-03-28");
+        assert!(value["days"][0]["mlb"].as_array().unwrap().is_empty());
+        assert!(value["days"][0].get("nba").is_none());
+
+        let text = OutputFormat::Text.render(&report).unwrap();
+        assert_eq!(text, "2024-03-28\n  MLB:\n");
+    }
+}
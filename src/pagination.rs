@@ -0,0 +1,154 @@
+use anyhow::{anyhow, Result};
+use std::future::Future;
+
+// Upper bound on pages fetched in one call, in case an API keeps handing back cursors
+const MAX_PAGES: usize = 50;
+
+/// One page of results, with the cursor for the next page if there is one
+#[derive(Debug)]
+pub struct Page<T, C> {
+    pub items: Vec<T>,
+    pub next: Option<C>,
+}
+
+/// Results gathered from a paged endpoint, possibly cut short by an item limit
+#[derive(Debug)]
+pub struct Paged<T, C> {
+    pub items: Vec<T>,
+    /// Cursor for the page after the last one fetched, if the endpoint has more
+    pub next: Option<C>,
+    /// Whether the limit was reached before the endpoint ran out of results
+    pub truncated: bool,
+}
+
+/// Fetch every page of a paged endpoint and accumulate the results.
+///
+/// `next_url` builds the URL for a page from the previous page's cursor (`None` for the first
+/// page), and `fetch` requests that URL and returns its items and next cursor.
+pub async fn paginate<T, C, U, F, Fut>(next_url: U, fetch: F) -> Result<Vec<T>>
+where
+    U: FnMut(Option<&C>) -> String,
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<Page<T, C>>>,
+{
+    Ok(paginate_up_to(next_url, fetch, None).await?.items)
+}
+
+/// Like [`paginate`], but stop requesting pages once `limit` items have been gathered, keeping
+/// only the first `limit` of them.
+pub async fn paginate_up_to<T, C, U, F, Fut>(mut next_url: U, mut fetch: F, limit: Option<usize>) -> Result<Paged<T, C>>
+where
+    U: FnMut(Option<&C>) -> String,
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<Page<T, C>>>,
+{
+    let mut items = Vec::new();
+    let mut cursor = None;
+
+    for _ in 0..MAX_PAGES {
+        let page = fetch(next_url(cursor.as_ref())).await?;
+        items.extend(page.items);
+
+        if let Some(limit) = limit.filter(|&limit| items.len() >= limit) {
+            let truncated = items.len() > limit || page.next.is_some();
+            items.truncate(limit);
+            return Ok(Paged { items, next: page.next, truncated });
+        }
+
+        match page.next {
+            Some(next) => cursor = Some(next),
+            None => return Ok(Paged { items, next: None, truncated: false }),
+        }
+    }
+
+    Err(anyhow!("Stopped after {} pages without reaching the end of the results", MAX_PAGES))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[tokio::test]
+    async fn test_paginate_two_pages() {
+        let requested = RefCell::new(Vec::new());
+
+        let items = paginate(
+            |cursor: Option<&u32>| match cursor {
+                Some(cursor) => format!("/stats?per_page=2&cursor={}", cursor),
+                None => "/stats?per_page=2".to_string(),
+            },
+            |url: String| {
+                requested.borrow_mut().push(url.clone());
+                async move {
+                    if url.ends_with("cursor=2") {
+                        Ok(Page { items: vec!["c"], next: None })
+                    } else {
+                        Ok(Page { items: vec!["a", "b"], next: Some(2) })
+                    }
+                }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec!["a", "b", "c"]);
+        assert_eq!(*requested.borrow(), vec!["/stats?per_page=2", "/stats?per_page=2&cursor=2"]);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_errors() {
+        // A failed page fails the whole fetch
+        let result = paginate(
+            |_: Option<&u32>| "/stats".to_string(),
+            |_| async { Err::<Page<u32, u32>, _>(anyhow!("HTTP 500")) },
+        )
+        .await;
+        assert_eq!(result.unwrap_err().to_string(), "HTTP 500");
+
+        // A cursor that never runs out stops at the page limit
+        let result = paginate(
+            |_: Option<&u32>| "/stats".to_string(),
+            |_| async { Ok(Page { items: vec![1], next: Some(1) }) },
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_paginate_up_to_limit() {
+        let requested = RefCell::new(0);
+        let fetch = |_: String| {
+            *requested.borrow_mut() += 1;
+            async { Ok(Page { items: (1..=8).collect::<Vec<u32>>(), next: Some(2) }) }
+        };
+
+        // A limit smaller than the first page stops after that page
+        let paged = paginate_up_to(|_: Option<&u32>| "/games".to_string(), fetch, Some(5)).await.unwrap();
+        assert_eq!(paged.items, vec![1, 2, 3, 4, 5]);
+        assert_eq!(paged.next, Some(2));
+        assert!(paged.truncated);
+        assert_eq!(*requested.borrow(), 1);
+
+        // A limit the results never reach isn't a truncation
+        let paged = paginate_up_to(
+            |_: Option<&u32>| "/games".to_string(),
+            |_| async { Ok(Page { items: vec![1, 2, 3], next: None }) },
+            Some(5),
+        )
+        .await
+        .unwrap();
+        assert_eq!(paged.items, vec![1, 2, 3]);
+        assert!(!paged.truncated);
+
+        // Neither is a limit the last page lands on exactly
+        let paged = paginate_up_to(
+            |_: Option<&u32>| "/games".to_string(),
+            |_| async { Ok(Page { items: vec![1, 2, 3], next: None }) },
+            Some(3),
+        )
+        .await
+        .unwrap();
+        assert!(!paged.truncated);
+    }
+}
@@ -0,0 +1,122 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Reports "Fetched N/M games..." progress lines while per-game details are fetched
+pub struct Progress<W: Write> {
+    total: usize,
+    completed: AtomicUsize,
+    label: &'static str,
+    output: Option<Mutex<W>>,
+}
+
+/// When progress is reported on stderr
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressMode {
+    /// Only when stderr is a terminal
+    Auto,
+    /// Even when stderr is redirected, e.g. to a log file
+    Always,
+    /// Never, as with --quiet or machine-readable output
+    Never,
+}
+
+impl Progress<io::Stderr> {
+    /// Create a progress reporter that writes to stderr when `mode` allows it
+    pub fn stderr(total: usize, label: &'static str, mode: ProgressMode) -> Self {
+        let stderr = io::stderr();
+        let show = match mode {
+            ProgressMode::Auto => stderr.is_terminal(),
+            ProgressMode::Always => true,
+            ProgressMode::Never => false,
+        };
+        Self::new(total, label, show.then_some(stderr))
+    }
+}
+
+impl<W: Write> Progress<W> {
+    /// Create a progress reporter writing to the given output, or a silent one if None
+    pub fn new(total: usize, label: &'static str, output: Option<W>) -> Self {
+        Self {
+            total,
+            completed: AtomicUsize::new(0),
+            label,
+            output: output.map(Mutex::new),
+        }
+    }
+
+    /// Mark one task as complete and report the updated count
+    pub fn tick(&self) {
+        let completed = self.completed.fetch_add(1, Ordering::SeqCst) + 1;
+
+        // Progress is only worth showing when there's more than one task
+        if self.total < 2 {
+            return;
+        }
+
+        if let Some(ref output) = self.output {
+            if let Ok(mut output) = output.lock() {
+                let _ = writeln!(output, "Fetched {}/{} {}...", completed, self.total, self.label);
+            }
+        }
+    }
+
+    /// Number of tasks completed so far
+    pub fn completed(&self) -> usize {
+        self.completed.load(Ordering::SeqCst)
+    }
+
+    /// Consume the reporter and return its output, if any
+    pub fn into_output(self) -> Option<W> {
+        self.output.and_then(|output| output.into_inner().ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_lines() {
+        let progress = Progress::new(3, "games", Some(Vec::new()));
+        progress.tick();
+        progress.tick();
+        progress.tick();
+
+        let output = String::from_utf8(progress.into_output().unwrap()).unwrap();
+        assert_eq!(output, "Fetched 1/3 games...\nFetched 2/3 games...\nFetched 3/3 games...\n");
+    }
+
+    #[test]
+    fn test_progress_concurrent_ticks() {
+        let progress = Progress::new(15, "games", Some(Vec::new()));
+        std::thread::scope(|scope| {
+            for _ in 0..15 {
+                scope.spawn(|| progress.tick());
+            }
+        });
+        assert_eq!(progress.completed(), 15);
+
+        // Every count is reported exactly once, regardless of completion order
+        let output = String::from_utf8(progress.into_output().unwrap()).unwrap();
+        for n in 1..=15 {
+            assert!(output.contains(&format!("Fetched {}/15 games...\n", n)));
+        }
+        assert_eq!(output.lines().count(), 15);
+    }
+
+    #[test]
+    fn test_progress_suppressed() {
+        // A silent reporter still counts but writes nothing
+        let progress: Progress<Vec<u8>> = Progress::new(2, "games", None);
+        progress.tick();
+        progress.tick();
+        assert_eq!(progress.completed(), 2);
+        assert!(progress.into_output().is_none());
+
+        // A single game doesn't get progress output
+        let progress = Progress::new(1, "games", Some(Vec::new()));
+        progress.tick();
+        assert!(progress.into_output().unwrap().is_empty());
+    }
+}
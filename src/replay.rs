@@ -0,0 +1,169 @@
+use anyhow::{anyhow, Result};
+use reqwest::Response;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// File under `dir` holding the recorded response for `url`.
+///
+/// Fixtures are keyed by league and endpoint, e.g. `{base}/teams?sportId=1` for MLB is read from
+/// `mlb/teams@sportId=1.json`. Characters that aren't safe in file names are replaced with `_`.
+pub fn fixture_path(dir: &Path, league: &str, base_url: &str, url: &str) -> PathBuf {
+    let endpoint = url.strip_prefix(base_url).unwrap_or(url).trim_start_matches('/');
+    let (path, query) = match endpoint.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (endpoint, None),
+    };
+
+    let mut file = dir.join(league);
+    let segments: Vec<String> = path.split('/').filter(|s| !s.is_empty()).map(sanitize).collect();
+    let Some((last, parents)) = segments.split_last() else {
+        return file.join("index.json");
+    };
+    for segment in parents {
+        file.push(segment);
+    }
+    match query {
+        Some(query) => file.push(format!("{}@{}.json", last, sanitize(query))),
+        None => file.push(format!("{}.json", last)),
+    }
+    file
+}
+
+/// Answer a request from its recorded fixture instead of the network
+pub fn response(dir: &Path, league: &str, base_url: &str, url: &str) -> Result<Response> {
+    let path = fixture_path(dir, league, base_url, url);
+    let body = fs::read_to_string(&path)
+        .map_err(|e| anyhow!("No replay fixture for {}: expected {} ({})", url, path.display(), e))?;
+    Ok(Response::from(http::Response::new(body)))
+}
+
+/// File under `dir` that a raw response for `url` is dumped to, named `{league}-{endpoint}-{id}.json`.
+///
+/// The endpoint is the URL's non-numeric path segments and the ID its numeric ones, e.g.
+/// `{base}/game/745444/feed/live` for MLB is written to `mlb-game_feed_live-745444.json`. An
+/// endpoint without an ID in its path uses its query string instead, and one with neither
+/// leaves the ID out.
+pub fn dump_path(dir: &Path, league: &str, base_url: &str, url: &str) -> PathBuf {
+    let endpoint = url.strip_prefix(base_url).unwrap_or(url).trim_start_matches('/');
+    let (path, query) = match endpoint.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (endpoint, None),
+    };
+
+    let (ids, names): (Vec<&str>, Vec<&str>) = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .partition(|s| s.chars().all(|c| c.is_ascii_digit()));
+    let name = if names.is_empty() { "index".to_string() } else { sanitize(&names.join("_")) };
+    let id = if ids.is_empty() { query.map(sanitize) } else { Some(ids.join("_")) };
+    match id {
+        Some(id) => dir.join(format!("{}-{}-{}.json", league, name, id)),
+        None => dir.join(format!("{}-{}.json", league, name)),
+    }
+}
+
+/// Write a response's untouched body to its file under `dir`, then hand back an equivalent
+/// response for the caller to read. Without a directory the response is returned as is.
+pub async fn dump(dir: Option<&Path>, league: &str, base_url: &str, url: &str, response: Response) -> Result<Response> {
+    let Some(dir) = dir else {
+        return Ok(response);
+    };
+
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = response.bytes().await?;
+
+    // A dump that can't be written shouldn't stop the command it was meant to help debug
+    let path = dump_path(dir, league, base_url, url);
+    if let Err(e) = fs::create_dir_all(dir).and_then(|_| fs::write(&path, &body)) {
+        warn!("Couldn't dump the response for {} to {}: {}", url, path.display(), e);
+    }
+
+    let mut rebuilt = http::Response::new(body);
+    *rebuilt.status_mut() = status;
+    *rebuilt.headers_mut() = headers;
+    Ok(Response::from(rebuilt))
+}
+
+// Keep characters that are safe in file names on every platform
+fn sanitize(part: &str) -> String {
+    part.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || "-_.,=&()".contains(c) { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixture_path() {
+        let dir = Path::new("fixtures");
+        let base = "https://statsapi.mlb.com/api/v1";
+        assert_eq!(
+            fixture_path(dir, "mlb", base, "https://statsapi.mlb.com/api/v1/teams?sportId=1"),
+            Path::new("fixtures/mlb/teams@sportId=1.json")
+        );
+        assert_eq!(
+            fixture_path(dir, "mlb", base, "https://statsapi.mlb.com/api/v1/game/745444/feed/live"),
+            Path::new("fixtures/mlb/game/745444/feed/live.json")
+        );
+        assert_eq!(
+            fixture_path(dir, "nba", "http://localhost/v1", "http://localhost/v1/games?team_ids[]=14&cursor=5"),
+            Path::new("fixtures/nba/games@team_ids__=14&cursor=5.json")
+        );
+    }
+
+    #[test]
+    fn test_dump_path() {
+        let dir = Path::new("dumps");
+        let base = "https://statsapi.mlb.com/api/v1";
+        assert_eq!(
+            dump_path(dir, "mlb", base, "https://statsapi.mlb.com/api/v1/game/745444/feed/live"),
+            Path::new("dumps/mlb-game_feed_live-745444.json")
+        );
+        assert_eq!(
+            dump_path(dir, "mlb", base, "https://statsapi.mlb.com/api/v1/teams?sportId=1"),
+            Path::new("dumps/mlb-teams-sportId=1.json")
+        );
+        assert_eq!(
+            dump_path(dir, "nba", "http://localhost/v1", "http://localhost/v1/teams"),
+            Path::new("dumps/nba-teams.json")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dump() {
+        let dir = std::env::temp_dir().join(format!("plaintext-sports-dump-{}", std::process::id()));
+        let mut original = http::Response::new(r#"{"teams": []}"#);
+        *original.status_mut() = http::StatusCode::NOT_FOUND;
+
+        let response = dump(Some(&dir), "nhl", "http://api", "http://api/teams/99", Response::from(original)).await.unwrap();
+        assert_eq!(response.status(), http::StatusCode::NOT_FOUND);
+        assert_eq!(response.text().await.unwrap(), r#"{"teams": []}"#);
+        assert_eq!(fs::read_to_string(dir.join("nhl-teams-99.json")).unwrap(), r#"{"teams": []}"#);
+
+        // Without a directory nothing is written
+        let response = dump(None, "nhl", "http://api", "http://api/teams", Response::from(http::Response::new("{}"))).await.unwrap();
+        assert_eq!(response.text().await.unwrap(), "{}");
+        assert!(!dir.join("nhl-teams.json").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_response_from_fixture() {
+        let dir = std::env::temp_dir().join(format!("plaintext-sports-replay-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nba")).unwrap();
+        fs::write(dir.join("nba/teams.json"), r#"{"data": []}"#).unwrap();
+
+        let response = response(&dir, "nba", "http://api", "http://api/teams").unwrap();
+        assert!(response.status().is_success());
+        assert_eq!(response.text().await.unwrap(), r#"{"data": []}"#);
+
+        let err = super::response(&dir, "nba", "http://api", "http://api/players/1").unwrap_err();
+        assert!(err.to_string().contains("No replay fixture for http://api/players/1"));
+        assert!(err.to_string().contains("players"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,192 @@
+use crate::error::SportError;
+use anyhow::Result;
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Response, StatusCode};
+use std::future::Future;
+use std::time::Duration;
+
+// Wait before the first retry; each later retry waits twice as long as the one before
+const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+
+// Longest Retry-After worth waiting for; a server asking for more gets a RateLimited error instead
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(5);
+
+/// Send a request, retrying server errors, rate limiting and network failures.
+///
+/// `send` is called once plus up to `retries` more times, waiting 250ms, 500ms, 1s, ... between
+/// attempts, or as long as a 429 response's `Retry-After` asks (up to 5 seconds; a longer wait
+/// fails straight away with `SportError::RateLimited`). The last response is returned even if
+/// it's still an error status, so callers report it the same way as any other, except that a
+/// request still rate limited fails with `SportError::RateLimited` and one still timing out
+/// fails with `SportError::Timeout`.
+pub async fn send_with_retry<F, Fut>(retries: u32, mut send: F) -> Result<Response>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = reqwest::Result<Response>>,
+{
+    let mut attempt = 0;
+    loop {
+        let result = send().await;
+        if attempt >= retries {
+            return match result {
+                Ok(response) if response.status() == StatusCode::TOO_MANY_REQUESTS => {
+                    let retry_after = retry_after(&response).map(|delay| delay.as_secs());
+                    Err(SportError::RateLimited { retry_after }.into())
+                }
+                Ok(response) => Ok(response),
+                Err(err) if err.is_timeout() => Err(SportError::Timeout.into()),
+                Err(err) => Err(err.into()),
+            };
+        }
+
+        let delay = match result {
+            Ok(ref response) if response.status() == StatusCode::TOO_MANY_REQUESTS => match retry_after(response) {
+                Some(delay) if delay > MAX_RETRY_AFTER => {
+                    return Err(SportError::RateLimited { retry_after: Some(delay.as_secs()) }.into());
+                }
+                Some(delay) => delay,
+                None => backoff(attempt),
+            },
+            Ok(ref response) if response.status().is_server_error() => backoff(attempt),
+            Ok(response) => return Ok(response),
+            Err(_) => backoff(attempt),
+        };
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+// Delay before retry number `attempt` (counting from 0)
+fn backoff(attempt: u32) -> Duration {
+    INITIAL_BACKOFF * 2u32.saturating_pow(attempt)
+}
+
+// A Retry-After header given in seconds; the HTTP-date form falls back to the usual backoff
+fn retry_after(response: &Response) -> Option<Duration> {
+    let seconds = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim().parse().ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use tokio::time::Instant;
+
+    fn response(status: u16, retry_after: Option<&str>) -> reqwest::Result<Response> {
+        let mut builder = http::Response::builder().status(status);
+        if let Some(seconds) = retry_after {
+            builder = builder.header("Retry-After", seconds);
+        }
+        Ok(Response::from(builder.body("").unwrap()))
+    }
+
+    #[test]
+    fn test_backoff() {
+        assert_eq!(backoff(0), Duration::from_millis(250));
+        assert_eq!(backoff(1), Duration::from_millis(500));
+        assert_eq!(backoff(2), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retries_server_errors_with_backoff() {
+        let attempts = Cell::new(0);
+        let start = Instant::now();
+
+        let result = send_with_retry(3, || {
+            attempts.set(attempts.get() + 1);
+            let status = if attempts.get() < 3 { 503 } else { 200 };
+            async move { response(status, None) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(result.status(), StatusCode::OK);
+        assert_eq!(attempts.get(), 3);
+        assert_eq!(start.elapsed(), Duration::from_millis(750));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_gives_up_after_retries() {
+        let attempts = Cell::new(0);
+
+        let result = send_with_retry(3, || {
+            attempts.set(attempts.get() + 1);
+            async { response(500, None) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(attempts.get(), 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_honors_retry_after() {
+        let attempts = Cell::new(0);
+        let start = Instant::now();
+
+        let result = send_with_retry(3, || {
+            attempts.set(attempts.get() + 1);
+            let status = if attempts.get() == 1 { 429 } else { 200 };
+            async move { response(status, Some("2")) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(result.status(), StatusCode::OK);
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_fails_fast_on_long_retry_after() {
+        let attempts = Cell::new(0);
+        let start = Instant::now();
+
+        // A day is far past the cap, so there's no waiting and no further attempts
+        let result = send_with_retry(3, || {
+            attempts.set(attempts.get() + 1);
+            async { response(429, Some("86400")) }
+        })
+        .await;
+
+        let err = result.unwrap_err().downcast::<SportError>().unwrap();
+        assert_eq!(err, SportError::RateLimited { retry_after: Some(86400) });
+        assert_eq!(attempts.get(), 1);
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_still_rate_limited_after_retries() {
+        let result = send_with_retry(1, || async { response(429, Some("3")) }).await;
+        let err = result.unwrap_err().downcast::<SportError>().unwrap();
+        assert_eq!(err, SportError::RateLimited { retry_after: Some(3) });
+        assert_eq!(err.to_string(), "Rate limited, retry in 3 seconds");
+        
+        let result = send_with_retry(0, || async { response(429, None) }).await;
+        assert_eq!(result.unwrap_err().to_string(), "Rate limited, try again later");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_does_not_retry_client_errors_or_when_disabled() {
+        let attempts = Cell::new(0);
+        let result = send_with_retry(3, || {
+            attempts.set(attempts.get() + 1);
+            async { response(404, None) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(result.status(), StatusCode::NOT_FOUND);
+        assert_eq!(attempts.get(), 1);
+
+        attempts.set(0);
+        let result = send_with_retry(0, || {
+            attempts.set(attempts.get() + 1);
+            async { response(503, None) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(result.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(attempts.get(), 1);
+    }
+}
@@ -0,0 +1,129 @@
+use chrono::Local;
+use serde::Serialize;
+use std::fmt;
+
+use crate::config::get_config;
+use crate::utils::{format_score, parse_iso8601_date};
+
+// Width of the team abbreviation and state columns inside the box
+const TEAM_WIDTH: usize = 4;
+const STATE_WIDTH: usize = 8;
+
+/// Where a game stands, shown in the right-hand column of a score bug
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum ScorebugState {
+    /// Not started yet, with the start time (e.g. "7:05 PM")
+    Scheduled(String),
+    /// In progress, with the period (e.g. "T7", "Q3") and an optional detail (e.g. "2 out", "5:32")
+    Live { period: String, detail: Option<String> },
+    /// Finished
+    Final,
+}
+
+/// A compact broadcast-style score bug for a single game
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Scorebug {
+    pub away: String,
+    pub away_score: Option<u32>,
+    pub home: String,
+    pub home_score: Option<u32>,
+    pub state: ScorebugState,
+}
+
+impl fmt::Display for Scorebug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (top, bottom) = match self.state {
+            ScorebugState::Scheduled(ref start) => (start.as_str(), ""),
+            ScorebugState::Live { ref period, ref detail } => (period.as_str(), detail.as_deref().unwrap_or("")),
+            ScorebugState::Final => ("F", ""),
+        };
+
+        // Scores are left blank until the game starts
+        let score = |score: Option<u32>| match self.state {
+            ScorebugState::Scheduled(_) => String::new(),
+            _ => format_score(score),
+        };
+
+        let border = format!("+{}+", "-".repeat(TEAM_WIDTH + STATE_WIDTH + 8));
+        writeln!(f, "{}", border)?;
+        writeln!(f, "{}", row(&self.away, &score(self.away_score), top))?;
+        writeln!(f, "{}", row(&self.home, &score(self.home_score), bottom))?;
+        write!(f, "{}", border)
+    }
+}
+
+// Build one line of the box, clipping values so the box never changes width
+fn row(team: &str, score: &str, state: &str) -> String {
+    let team: String = team.chars().take(TEAM_WIDTH).collect();
+    let state: String = state.chars().take(STATE_WIDTH).collect();
+    format!("| {:<tw$}{:>3} | {:<sw$} |", team, score, state, tw = TEAM_WIDTH, sw = STATE_WIDTH)
+}
+
+/// Format an ISO8601 start time as a short time (e.g. "7:05 PM") in the configured timezone, or
+/// the local one, passing through anything unparseable
+pub fn start_time(date_str: &str) -> String {
+    match (parse_iso8601_date(date_str), get_config().timezone) {
+        (Ok(dt), Some(tz)) => dt.with_timezone(&tz).format("%-I:%M %p").to_string(),
+        (Ok(dt), None) => dt.with_timezone(&Local).format("%-I:%M %p").to_string(),
+        (Err(_), _) => date_str.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bug(state: ScorebugState) -> Scorebug {
+        Scorebug {
+            away: "CWS".to_string(),
+            away_score: Some(3),
+            home: "CHC".to_string(),
+            home_score: Some(5),
+            state,
+        }
+    }
+
+    #[test]
+    fn test_scorebug_live() {
+        let live = bug(ScorebugState::Live { period: "T7".to_string(), detail: Some("2 out".to_string()) });
+        assert_eq!(
+            live.to_string(),
+            "+--------------------+\n\
+             | CWS   3 | T7       |\n\
+             | CHC   5 | 2 out    |\n\
+             +--------------------+"
+        );
+    }
+
+    #[test]
+    fn test_scorebug_final() {
+        let final_bug = bug(ScorebugState::Final);
+        let display = final_bug.to_string();
+        let lines: Vec<&str> = display.lines().collect();
+        assert_eq!(lines[1], "| CWS   3 | F        |");
+        assert_eq!(lines[2], "| CHC   5 |          |");
+    }
+
+    #[test]
+    fn test_scorebug_scheduled() {
+        let mut scheduled = bug(ScorebugState::Scheduled("7:05 PM".to_string()));
+        scheduled.away_score = None;
+        scheduled.home_score = None;
+        let display = scheduled.to_string();
+        let lines: Vec<&str> = display.lines().collect();
+        assert_eq!(lines[1], "| CWS     | 7:05 PM  |");
+        assert_eq!(lines[2], "| CHC     |          |");
+
+        // Every line has the same width, even with oversized values
+        let mut wide = bug(ScorebugState::Scheduled("7:30 pm ET".to_string()));
+        wide.away = "Chicago White Sox".to_string();
+        let widths: Vec<usize> = wide.to_string().lines().map(|line| line.chars().count()).collect();
+        assert!(widths.iter().all(|&width| width == widths[0]));
+    }
+
+    #[test]
+    fn test_start_time_passthrough() {
+        assert_eq!(start_time("7:30 pm ET"), "7:30 pm ET");
+        assert!(start_time("2024-03-28T23:05:00Z").ends_with('M'));
+    }
+}
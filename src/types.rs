@@ -1,6 +1,7 @@
 use std::fmt;
 use std::str::FromStr;
 use crate::error::{Result, SportError};
+use serde::{Deserialize, Serialize};
 
 /// Represents a team identifier
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -16,6 +17,12 @@ impl FromStr for TeamId {
     }
 }
 
+impl From<u32> for TeamId {
+    fn from(id: u32) -> Self {
+        TeamId(id)
+    }
+}
+
 /// Represents a game identifier
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct GameId(pub u32);
@@ -30,8 +37,14 @@ impl FromStr for GameId {
     }
 }
 
+impl From<u32> for GameId {
+    fn from(id: u32) -> Self {
+        GameId(id)
+    }
+}
+
 /// Represents a game score
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Score(pub u32);
 
 impl fmt::Display for Score {
@@ -57,9 +70,9 @@ impl Average {
         }
     }
 
-    /// Formats the average as a three-digit string (e.g., ".333")
+    /// Formats the average as a three-digit string (e.g., ".333", or "1.000" for a perfect average)
     pub fn format(&self) -> String {
-        format!(".{:03}", (self.0 * 1000.0).round() as u32)
+        format_rate(self.0)
     }
 }
 
@@ -69,6 +82,79 @@ impl fmt::Display for Average {
     }
 }
 
+impl FromStr for Average {
+    type Err = SportError;
+
+    /// Parses the conventional ".333" form, as well as "0.333" and "1.000"
+    fn from_str(s: &str) -> Result<Self> {
+        Average::new(parse_decimal(s, "average")?)
+    }
+}
+
+/// Represents a rate statistic like slugging percentage or OPS, which can exceed 1.000
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateStat(pub f32);
+
+impl RateStat {
+    /// Largest accepted value; a perfect OPS is 5.000 (1.000 OBP plus 4.000 SLG)
+    pub const MAX: f32 = 5.0;
+
+    /// Creates a new RateStat from a float, ensuring it's between 0 and `RateStat::MAX`
+    pub fn new(value: f32) -> Result<Self> {
+        if !(0.0..=Self::MAX).contains(&value) {
+            Err(SportError::FetchError(format!(
+                "Invalid rate stat value: {}. Must be between 0 and {}",
+                value,
+                Self::MAX
+            )))
+        } else {
+            Ok(RateStat(value))
+        }
+    }
+
+    /// Formats the value to three decimal places, without a leading zero (e.g., ".750" or "1.500")
+    pub fn format(&self) -> String {
+        format_rate(self.0)
+    }
+}
+
+impl fmt::Display for RateStat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format())
+    }
+}
+
+impl FromStr for RateStat {
+    type Err = SportError;
+
+    /// Parses ".750", "0.750" and "1.500"
+    fn from_str(s: &str) -> Result<Self> {
+        RateStat::new(parse_decimal(s, "rate stat")?)
+    }
+}
+
+// Three decimal places, dropping the leading zero below 1 the way averages are written
+fn format_rate(value: f32) -> String {
+    let thousandths = (value * 1000.0).round() as u32;
+    if thousandths >= 1000 {
+        format!("{}.{:03}", thousandths / 1000, thousandths % 1000)
+    } else {
+        format!(".{:03}", thousandths)
+    }
+}
+
+// Parse a plain decimal; f32 parsing would also accept forms like "inf" or "1e-1"
+fn parse_decimal(s: &str, what: &str) -> Result<f32> {
+    let invalid = || SportError::FetchError(format!("Invalid {}: {}", what, s));
+    if !s.contains(|c: char| c.is_ascii_digit())
+        || s.matches('.').count() > 1
+        || !s.chars().all(|c| c.is_ascii_digit() || c == '.')
+    {
+        return Err(invalid());
+    }
+    s.parse::<f32>().map_err(|_| invalid())
+}
+
 /// Represents innings pitched
 #[derive(Debug, Clone)]
 pub struct InningsPitched {
@@ -113,6 +199,20 @@ impl InningsPitched {
     pub fn as_float(&self) -> f32 {
         self.complete as f32 + (self.partial as f32 / 3.0)
     }
+
+    /// Totals several innings pitched strings (e.g., across a pitching staff) by counting outs,
+    /// so "6.2" and "2.1" sum to "9.0" rather than "8.3". Unparseable values count as zero.
+    pub fn sum(values: &[&str]) -> InningsPitched {
+        let outs: u32 = values
+            .iter()
+            .filter_map(|value| InningsPitched::new(value).ok())
+            .map(|ip| ip.complete * 3 + ip.partial)
+            .sum();
+        InningsPitched {
+            complete: outs / 3,
+            partial: outs % 3,
+        }
+    }
 }
 
 impl fmt::Display for InningsPitched {
@@ -158,6 +258,17 @@ impl Position {
             Position::Unknown => "??",
         }
     }
+
+    /// Returns the roster group the position belongs to
+    pub fn group(&self) -> &'static str {
+        match self {
+            Position::Pitcher => "Pitchers",
+            Position::Catcher => "Catchers",
+            Position::FirstBase | Position::SecondBase | Position::ThirdBase | Position::Shortstop => "Infielders",
+            Position::LeftField | Position::CenterField | Position::RightField => "Outfielders",
+            Position::DesignatedHitter | Position::Unknown => "Other",
+        }
+    }
 }
 
 impl FromStr for Position {
@@ -223,7 +334,7 @@ impl FromStr for ThrowingSide {
 }
 
 /// Represents a win-loss record
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Record {
     pub wins: u32,
     pub losses: u32,
@@ -274,6 +385,67 @@ impl FromStr for Record {
     }
 }
 
+/// Whether a streak is of wins or losses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreakKind {
+    Win,
+    Loss,
+}
+
+/// Represents a run of consecutive wins or losses, written like "W3" or "L2"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Streak {
+    pub kind: StreakKind,
+    pub length: u32,
+}
+
+impl fmt::Display for Streak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            StreakKind::Win => 'W',
+            StreakKind::Loss => 'L',
+        };
+        write!(f, "{}{}", kind, self.length)
+    }
+}
+
+impl FromStr for Streak {
+    type Err = SportError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || SportError::FetchError(format!("Invalid streak: {}", s));
+        let kind = match s.chars().next() {
+            Some('W') => StreakKind::Win,
+            Some('L') => StreakKind::Loss,
+            _ => return Err(invalid()),
+        };
+        let length = &s[1..];
+        if length.is_empty() || !length.chars().all(|c| c.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        match length.parse() {
+            Ok(length) if length > 0 => Ok(Streak { kind, length }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+// Streaks are serialized in their "W3" form
+impl From<Streak> for String {
+    fn from(streak: Streak) -> Self {
+        streak.to_string()
+    }
+}
+
+impl TryFrom<String> for Streak {
+    type Error = SportError;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
 /// Represents a player's uniform number
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UniformNumber(pub String);
@@ -325,6 +497,43 @@ mod tests {
         assert!(Average::new(0.333).is_ok());
         assert!(Average::new(1.5).is_err());
         assert_eq!(Average::new(0.333).unwrap().format(), ".333");
+        assert_eq!(Average::new(1.0).unwrap().format(), "1.000");
+    }
+
+    #[test]
+    fn test_rate_stat() {
+        assert_eq!(RateStat::new(1.5).unwrap().format(), "1.500");
+        assert_eq!(RateStat::new(0.75).unwrap().format(), ".750");
+        assert_eq!(RateStat::new(0.9996).unwrap().format(), "1.000");
+        assert!(RateStat::new(5.0).is_ok());
+        assert!(RateStat::new(5.001).is_err());
+        assert!(RateStat::new(-0.1).is_err());
+        
+        assert_eq!("1.500".parse::<RateStat>().unwrap(), RateStat(1.5));
+        assert_eq!(".882".parse::<RateStat>().unwrap().to_string(), ".882");
+        assert!(".---".parse::<RateStat>().is_err());
+        assert!("inf".parse::<RateStat>().is_err());
+    }
+
+    #[test]
+    fn test_average_from_str() {
+        assert_eq!(".333".parse::<Average>().unwrap(), Average(0.333));
+        assert_eq!("0.333".parse::<Average>().unwrap(), Average(0.333));
+        assert_eq!("1.000".parse::<Average>().unwrap(), Average(1.0));
+        assert_eq!(".000".parse::<Average>().unwrap(), Average(0.0));
+        assert_eq!(".333".parse::<Average>().unwrap().to_string(), ".333");
+        
+        // Out of range
+        assert!("1.500".parse::<Average>().is_err());
+        assert!("2".parse::<Average>().is_err());
+        assert!("-.250".parse::<Average>().is_err());
+        
+        // Not an average at all; the API uses ".---" when there are no at-bats
+        assert!(".---".parse::<Average>().is_err());
+        assert!("".parse::<Average>().is_err());
+        assert!(".".parse::<Average>().is_err());
+        assert!("NaN".parse::<Average>().is_err());
+        assert!("1.0.0".parse::<Average>().is_err());
     }
 
     #[test]
@@ -339,6 +548,20 @@ mod tests {
         assert!(InningsPitched::new("invalid").is_err());
     }
 
+    #[test]
+    fn test_innings_pitched_sum() {
+        // Partials carry into full innings every three outs
+        assert_eq!(InningsPitched::sum(&["6.2", "2.1"]).to_string(), "9.0");
+        assert_eq!(InningsPitched::sum(&["6.2", "2.2"]).to_string(), "9.1");
+        assert_eq!(InningsPitched::sum(&["0.1", "0.1", "0.1", "0.1"]).to_string(), "1.1");
+        assert_eq!(InningsPitched::sum(&["5.1", "1.2", "1.1", "0.2"]).to_string(), "9.0");
+        assert_eq!(InningsPitched::sum(&["7", "2.0"]).to_string(), "9.0");
+
+        // Nothing to sum, or nothing parseable, is zero innings
+        assert_eq!(InningsPitched::sum(&[]).to_string(), "0.0");
+        assert_eq!(InningsPitched::sum(&["6.2", "invalid"]).to_string(), "6.2");
+    }
+
     #[test]
     fn test_position_from_str() {
         assert_eq!(Position::from_str("P").unwrap(), Position::Pitcher);
@@ -353,6 +576,15 @@ mod tests {
         assert_eq!(Position::Unknown.abbreviation(), "??");
     }
 
+    #[test]
+    fn test_position_group() {
+        assert_eq!(Position::Pitcher.group(), "Pitchers");
+        assert_eq!(Position::Catcher.group(), "Catchers");
+        assert_eq!(Position::Shortstop.group(), "Infielders");
+        assert_eq!(Position::CenterField.group(), "Outfielders");
+        assert_eq!(Position::Unknown.group(), "Other");
+    }
+
     #[test]
     fn test_batting_side() {
         assert_eq!(BattingSide::from_str("L").unwrap(), BattingSide::Left);
@@ -378,6 +610,22 @@ mod tests {
         assert!(Record::from_str("invalid").is_err());
     }
 
+    #[test]
+    fn test_streak() {
+        assert_eq!("W10".parse::<Streak>().unwrap(), Streak { kind: StreakKind::Win, length: 10 });
+        assert_eq!("L1".parse::<Streak>().unwrap(), Streak { kind: StreakKind::Loss, length: 1 });
+        assert_eq!("W10".parse::<Streak>().unwrap().to_string(), "W10");
+        assert_eq!("L1".parse::<Streak>().unwrap().to_string(), "L1");
+
+        for malformed in ["X3", "W", "", "W0", "W-2", "w3", "3W", "L2.5"] {
+            assert!(malformed.parse::<Streak>().is_err(), "{}", malformed);
+        }
+
+        let streak: Streak = serde_json::from_str("\"W3\"").unwrap();
+        assert_eq!(serde_json::to_string(&streak).unwrap(), "\"W3\"");
+        assert!(serde_json::from_str::<Streak>("\"X3\"").is_err());
+    }
+
     #[test]
     fn test_uniform_number() {
         assert!(UniformNumber::new("42").is_ok());
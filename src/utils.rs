@@ -1,7 +1,9 @@
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Utc};
+use chrono_tz::Tz;
 use crate::error::{Result, SportError};
-use crate::types::Average;
+use crate::types::{Average, RateStat};
 use std::cmp::Ordering;
+use std::fmt;
 
 /// Parses an ISO8601 date string into a DateTime<Utc>
 pub fn parse_iso8601_date(date_str: &str) -> Result<DateTime<Utc>> {
@@ -22,12 +24,29 @@ pub fn format_decimal_stat(value: Option<f32>) -> String {
         .unwrap_or_else(|| "---".to_string())
 }
 
-/// Truncates a string to a maximum length, adding "..." if truncated
+/// Parses a decimal statistic as it's displayed (".333", "0.333", "1.000" or "2.95"). The
+/// placeholders shown for a missing value ("---", ".---", "-.--", "--" and "-") give None, as
+/// does anything else that isn't a plain decimal.
+pub fn parse_decimal_stat(s: &str) -> Option<f32> {
+    let s = s.trim();
+    let plain = s.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && s.chars().filter(|&c| c == '.').count() <= 1
+        && s.chars().any(|c| c.is_ascii_digit());
+    if !plain {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Truncates a string to a maximum number of characters, adding "..." if truncated
 pub fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
+    if s.chars().count() <= max_len {
         s.to_string()
+    } else if max_len < 3 {
+        // No room for the ellipsis
+        s.chars().take(max_len).collect()
     } else {
-        format!("{}...", &s[0..max_len.saturating_sub(3)])
+        format!("{}...", s.chars().take(max_len - 3).collect::<String>())
     }
 }
 
@@ -56,6 +75,16 @@ pub fn calculate_era(earned_runs: u32, innings_pitched: &str) -> Option<f32> {
     }
 }
 
+/// Calculates walks plus hits per inning pitched (WHIP)
+pub fn calculate_whip(walks: u32, hits: u32, innings_pitched: &str) -> Option<f32> {
+    let ip = parse_innings_pitched(innings_pitched);
+    if ip > 0.0 {
+        Some((walks + hits) as f32 / ip)
+    } else {
+        None
+    }
+}
+
 /// Calculates batting average from hits and at-bats
 pub fn calculate_average(hits: u32, at_bats: u32) -> Option<Average> {
     if at_bats > 0 {
@@ -65,6 +94,76 @@ pub fn calculate_average(hits: u32, at_bats: u32) -> Option<Average> {
     }
 }
 
+/// Calculates on-base percentage, (H + BB + HBP) / (AB + BB + HBP + SF), formatted like ".379"
+pub fn calculate_obp(hits: u32, walks: u32, hbp: u32, at_bats: u32, sac_flies: u32) -> Option<String> {
+    let plate_appearances = at_bats + walks + hbp + sac_flies;
+    if plate_appearances > 0 {
+        format_rate_stat((hits + walks + hbp) as f32 / plate_appearances as f32)
+    } else {
+        None
+    }
+}
+
+/// Calculates slugging percentage from total bases and at-bats, formatted like ".523" or "1.500"
+pub fn calculate_slg(total_bases: u32, at_bats: u32) -> Option<String> {
+    if at_bats > 0 {
+        format_rate_stat(total_bases as f32 / at_bats as f32)
+    } else {
+        None
+    }
+}
+
+/// Calculates OPS from formatted on-base and slugging percentages (e.g. ".359" and ".523" give ".882")
+pub fn calculate_ops(obp: &str, slg: &str) -> Option<String> {
+    format_rate_stat(parse_decimal_stat(obp)? + parse_decimal_stat(slg)?)
+}
+
+// Three decimal places, or None for a value no rate stat can have
+fn format_rate_stat(value: f32) -> Option<String> {
+    RateStat::new(value).ok().map(|rate| rate.format())
+}
+
+/// Calculates Pythagorean expected winning percentage from runs scored and allowed
+pub fn calculate_pythagorean_pct(runs_scored: u32, runs_allowed: u32) -> Option<f32> {
+    // 1.83 is the exponent Baseball-Reference uses; it fits MLB run environments better than 2
+    const EXPONENT: f32 = 1.83;
+    if runs_scored + runs_allowed > 0 {
+        let scored = (runs_scored as f32).powf(EXPONENT);
+        let allowed = (runs_allowed as f32).powf(EXPONENT);
+        Some(scored / (scored + allowed))
+    } else {
+        None
+    }
+}
+
+/// Returns the last day of the month the date falls in
+pub fn last_day_of_month(date: NaiveDate) -> NaiveDate {
+    // The day before the first of next month, so February and 31-day months come out right
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|first| first.pred_opt())
+        .expect("the first of a month always has a previous day")
+}
+
+/// Fills in the missing ends of a schedule's date range ("YYYY-MM-DD"). The start defaults to the
+/// first of `today`'s month and the end to the last day of the start's month.
+pub fn schedule_range(today: NaiveDate, start_date: Option<String>, end_date: Option<String>) -> Result<(String, String)> {
+    let start = start_date.unwrap_or_else(|| format!("{}-{:02}-01", today.year(), today.month()));
+    let end = match end_date {
+        Some(end) => end,
+        None => {
+            let start = NaiveDate::parse_from_str(&start, "%Y-%m-%d")
+                .map_err(|e| SportError::DateError(format!("Invalid start date '{}': {}", start, e)))?;
+            last_day_of_month(start).format("%Y-%m-%d").to_string()
+        }
+    };
+    Ok((start, end))
+}
+
 /// Formats a win-loss record (e.g., "42-34")
 pub fn format_record(wins: u32, losses: u32) -> String {
     format!("{}-{}", wins, losses)
@@ -107,12 +206,155 @@ pub fn format_player_name(first: &str, last: &str) -> String {
     format!("{}, {}", last.to_uppercase(), first)
 }
 
-/// Formats a game time in local timezone
+/// Number of single-character insertions, deletions and substitutions that turn `a` into `b`
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Formats a game time in UTC
 pub fn format_game_time(date_str: &str) -> Result<String> {
     let dt = parse_iso8601_date(date_str)?;
     Ok(dt.format("%I:%M %p").to_string())
 }
 
+/// Formats a game time in an IANA timezone such as "America/New_York"
+pub fn format_game_time_in_tz(date_str: &str, tz: &str) -> Result<String> {
+    let tz: Tz = tz.parse().map_err(|_| SportError::DateError(format!("Unknown timezone: {}", tz)))?;
+    let dt = parse_iso8601_date(date_str)?;
+    Ok(dt.with_timezone(&tz).format("%I:%M %p").to_string())
+}
+
+/// How a [`Table`] column's values line up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+// A line of a table: cells, or a dashed rule
+#[derive(Debug)]
+enum TableRow {
+    Cells(Vec<String>),
+    Rule,
+}
+
+/// A plain-text table whose columns widen to fit their longest value, so a stat that outgrows
+/// its usual width (e.g. a three-digit pitch count) doesn't push the rest of its row out of line.
+///
+/// Columns are separated by a space and left-aligned unless set otherwise. A rule crosses a
+/// column of `|` dividers with a `+`.
+#[derive(Debug, Default)]
+pub struct Table {
+    rows: Vec<TableRow>,
+    min_widths: Vec<usize>,
+    align: Vec<Align>,
+}
+
+impl Table {
+    /// A table with a header row, underlined by a rule
+    pub fn new<S: ToString>(headers: &[S]) -> Self {
+        let mut table = Table::default();
+        table.row(headers.iter().map(ToString::to_string)).rule();
+        table
+    }
+
+    /// A table without a header row
+    pub fn headless() -> Self {
+        Table::default()
+    }
+
+    /// Keep columns at least these widths, so a table's usual layout doesn't shift with its values
+    pub fn min_widths(&mut self, widths: &[usize]) -> &mut Self {
+        self.min_widths = widths.to_vec();
+        self
+    }
+
+    /// Line a column's values up on the right, as for numbers
+    pub fn align_right(&mut self, column: usize) -> &mut Self {
+        if self.align.len() <= column {
+            self.align.resize(column + 1, Align::Left);
+        }
+        self.align[column] = Align::Right;
+        self
+    }
+
+    /// Add a row of cells
+    pub fn row<S: ToString>(&mut self, cells: impl IntoIterator<Item = S>) -> &mut Self {
+        self.rows.push(TableRow::Cells(cells.into_iter().map(|cell| cell.to_string()).collect()));
+        self
+    }
+
+    /// Add a dashed rule, such as above a totals row
+    pub fn rule(&mut self) -> &mut Self {
+        self.rows.push(TableRow::Rule);
+        self
+    }
+
+    /// The table's lines, without line breaks
+    pub fn lines(&self) -> Vec<String> {
+        let cell_rows = || self.rows.iter().filter_map(|row| match row {
+            TableRow::Cells(cells) => Some(cells),
+            TableRow::Rule => None,
+        });
+        let columns = cell_rows().map(Vec::len).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|column| {
+                let min = self.min_widths.get(column).copied().unwrap_or(0);
+                cell_rows()
+                    .filter_map(|cells| cells.get(column))
+                    .map(|cell| cell.chars().count())
+                    .fold(min, usize::max)
+            })
+            .collect();
+        let dividers: Vec<bool> = (0..columns)
+            .map(|column| cell_rows().all(|cells| cells.get(column).is_some_and(|cell| cell == "|")))
+            .collect();
+
+        self.rows
+            .iter()
+            .map(|row| match row {
+                TableRow::Cells(cells) => widths
+                    .iter()
+                    .enumerate()
+                    .map(|(column, &width)| {
+                        let cell = cells.get(column).map_or("", String::as_str);
+                        match self.align.get(column) {
+                            Some(Align::Right) => format!("{:>w$}", cell, w = width),
+                            _ => format!("{:<w$}", cell, w = width),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                TableRow::Rule => widths
+                    .iter()
+                    .zip(&dividers)
+                    .map(|(&width, &divider)| if divider { "+".to_string() } else { "-".repeat(width) })
+                    .collect::<Vec<_>>()
+                    .join("-"),
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.lines() {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,10 +380,37 @@ mod tests {
         assert_eq!(format_decimal_stat(None), "---");
     }
 
+    #[test]
+    fn test_parse_decimal_stat() {
+        assert_eq!(parse_decimal_stat(".333"), Some(0.333));
+        assert_eq!(parse_decimal_stat("0.333"), Some(0.333));
+        assert_eq!(parse_decimal_stat("1.000"), Some(1.0));
+        assert_eq!(parse_decimal_stat("1.500"), Some(1.5));
+        assert_eq!(parse_decimal_stat("2.95"), Some(2.95));
+        assert_eq!(parse_decimal_stat(" .287 "), Some(0.287));
+        assert_eq!(parse_decimal_stat("0"), Some(0.0));
+
+        // Every placeholder the box scores, season lines and standings print for a missing value
+        for placeholder in ["---", ".---", "-.--", "--", "-", ""] {
+            assert_eq!(parse_decimal_stat(placeholder), None, "{:?}", placeholder);
+        }
+        assert_eq!(parse_decimal_stat(&format_decimal_stat(None)), None);
+
+        // Only plain decimals, not the other forms f32 parsing accepts
+        for other in ["inf", "NaN", "1e-1", "-.250", "1.2.3", "."] {
+            assert_eq!(parse_decimal_stat(other), None, "{:?}", other);
+        }
+    }
+
     #[test]
     fn test_truncate_string() {
         assert_eq!(truncate_string("short", 10), "short");
         assert_eq!(truncate_string("very long string", 10), "very lo...");
+        assert_eq!(truncate_string("José Ramírez", 12), "José Ramírez");
+        assert_eq!(truncate_string("Ronald Acuña Jr.", 12), "Ronald Ac...");
+        assert_eq!(truncate_string("Acuña", 4), "A...");
+        assert_eq!(truncate_string("ñññ", 2), "ññ");
+        assert_eq!(truncate_string("abc", 0), "");
     }
 
     #[test]
@@ -159,6 +428,31 @@ mod tests {
         assert_eq!(calculate_era(1, "0.0"), None);
     }
 
+    #[test]
+    fn test_calculate_whip() {
+        assert_eq!(calculate_whip(2, 4, "6.0"), Some(1.0));
+        assert_eq!(calculate_whip(1, 1, "1.1").map(|whip| format!("{:.2}", whip)), Some("1.50".to_string()));
+        assert_eq!(calculate_whip(1, 1, "0.0"), None);
+    }
+
+    #[test]
+    fn test_slash_line() {
+        // Aaron Judge, 2022: 177 H, 111 BB, 6 HBP, 5 SF and 391 total bases in 570 AB
+        let obp = calculate_obp(177, 111, 6, 570, 5).unwrap();
+        let slg = calculate_slg(391, 570).unwrap();
+        assert_eq!(obp, ".425");
+        assert_eq!(slg, ".686");
+        assert_eq!(calculate_ops(&obp, &slg), Some("1.111".to_string()));
+        
+        // Two hits, a double and a homer, in two at-bats
+        assert_eq!(calculate_slg(6, 2), Some("3.000".to_string()));
+        assert_eq!(calculate_ops(".359", ".523"), Some(".882".to_string()));
+        
+        assert_eq!(calculate_obp(0, 0, 0, 0, 0), None);
+        assert_eq!(calculate_slg(0, 0), None);
+        assert_eq!(calculate_ops(".---", ".523"), None);
+    }
+
     #[test]
     fn test_calculate_average() {
         assert_eq!(
@@ -172,6 +466,45 @@ mod tests {
         assert_eq!(calculate_average(5, 0), None);
     }
 
+    #[test]
+    fn test_calculate_pythagorean_pct() {
+        // 2023 Braves: 947 RS, 716 RA
+        let pct = calculate_pythagorean_pct(947, 716).unwrap();
+        assert!((pct - 0.625).abs() < 0.001);
+        assert_eq!(calculate_pythagorean_pct(500, 500), Some(0.5));
+        assert_eq!(calculate_pythagorean_pct(10, 0), Some(1.0));
+        assert_eq!(calculate_pythagorean_pct(0, 10), Some(0.0));
+        assert_eq!(calculate_pythagorean_pct(0, 0), None);
+    }
+
+    #[test]
+    fn test_last_day_of_month() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(last_day_of_month(date(2024, 2, 10)), date(2024, 2, 29));
+        assert_eq!(last_day_of_month(date(2023, 2, 1)), date(2023, 2, 28));
+        assert_eq!(last_day_of_month(date(2024, 3, 31)), date(2024, 3, 31));
+        assert_eq!(last_day_of_month(date(2024, 4, 15)), date(2024, 4, 30));
+        assert_eq!(last_day_of_month(date(2024, 12, 5)), date(2024, 12, 31));
+    }
+
+    #[test]
+    fn test_schedule_range() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 28).unwrap();
+        let range = |start: Option<&str>, end: Option<&str>| {
+            schedule_range(today, start.map(String::from), end.map(String::from)).unwrap()
+        };
+        assert_eq!(range(None, None), ("2024-03-01".to_string(), "2024-03-31".to_string()));
+        
+        // Without an end, the range runs to the end of the start's month, whatever today is
+        assert_eq!(range(Some("2024-04-01"), None).1, "2024-04-30");
+        assert_eq!(range(Some("2024-07-04"), None).1, "2024-07-31");
+        assert_eq!(range(Some("2023-12-15"), None).1, "2023-12-31");
+        assert_eq!(range(Some("2024-02-10"), None).1, "2024-02-29");
+        assert_eq!(range(Some("2024-04-01"), Some("2024-04-07")).1, "2024-04-07");
+        
+        assert!(schedule_range(today, Some("April 1".to_string()), None).is_err());
+    }
+
     #[test]
     fn test_format_record() {
         assert_eq!(format_record(42, 34), "42-34");
@@ -200,9 +533,57 @@ mod tests {
         assert_eq!(format_player_name("", ""), ", ");
     }
 
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("NYY", "NYY"), 0);
+        assert_eq!(edit_distance("NYX", "NYY"), 1);
+        assert_eq!(edit_distance("LA", "LAL"), 1);
+        assert_eq!(edit_distance("", "BOS"), 3);
+        assert_eq!(edit_distance("CWS", "SEA"), 3);
+    }
+
     #[test]
     fn test_format_game_time() {
         assert!(format_game_time("2024-03-14T19:05:00Z").is_ok());
         assert!(format_game_time("invalid").is_err());
+        
+        // A 7:05pm ET start, shown on the west coast
+        assert_eq!(format_game_time("2024-07-04T23:05:00Z").unwrap(), "11:05 PM");
+        assert_eq!(format_game_time_in_tz("2024-07-04T23:05:00Z", "America/New_York").unwrap(), "07:05 PM");
+        assert_eq!(format_game_time_in_tz("2024-07-04T23:05:00Z", "America/Los_Angeles").unwrap(), "04:05 PM");
+        // And in winter, once both coasts have fallen back an hour
+        assert_eq!(format_game_time_in_tz("2024-12-04T00:05:00Z", "America/Los_Angeles").unwrap(), "04:05 PM");
+        
+        assert_eq!(
+            format_game_time_in_tz("2024-07-04T23:05:00Z", "Mars/Olympus_Mons").unwrap_err(),
+            SportError::DateError("Unknown timezone: Mars/Olympus_Mons".to_string())
+        );
+        assert!(format_game_time_in_tz("invalid", "America/Los_Angeles").is_err());
+    }
+
+    #[test]
+    fn test_table_alignment() {
+        let mut table = Table::new(&["NAME", "H", "AVG"]);
+        table.row(["Judge", "2", ".311"]).row(["Soto", "1", ".288"]);
+        assert_eq!(table.to_string(), "NAME  H AVG \n------------\nJudge 2 .311\nSoto  1 .288\n");
+
+        // A long value widens its column for every row, not just its own
+        table.rule().row(["TEAM", "103", ".300"]);
+        assert_eq!(
+            table.lines(),
+            vec!["NAME  H   AVG ", "--------------", "Judge 2   .311", "Soto  1   .288", "--------------", "TEAM  103 .300"]
+        );
+
+        // Minimum widths hold a column's usual size, and are outgrown the same way
+        let mut table = Table::headless();
+        table.min_widths(&[8, 3]).align_right(1).row(["Ohtani", "4"]).row(["Stanton", "12"]);
+        assert_eq!(table.lines(), vec!["Ohtani     4", "Stanton   12"]);
+        table.row(["Guerrero Jr.", "1000"]);
+        assert_eq!(table.lines(), vec!["Ohtani          4", "Stanton        12", "Guerrero Jr. 1000"]);
+
+        // Rules cross divider columns
+        let mut table = Table::new(&["", "|", "1", "|", "R"]);
+        table.align_right(2).row(["Awa", "|", "0", "|", "10"]);
+        assert_eq!(table.lines(), vec!["    | 1 | R ", "----+---+---", "Awa | 0 | 10"]);
     }
-} 
\ No newline at end of file
+}
@@ -5,12 +5,16 @@ use std::time::Duration;
 
 #[test]
 fn test_mlb_today_workflow() -> Result<()> {
+    // A cache of its own, so finished games are fetched rather than read from the developer's cache
+    let cache_dir = std::env::temp_dir().join(format!("plaintext-sports-workflow-cache-{}", std::process::id()));
     let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("PLAINTEXT_SPORTS_CACHE_DIR", &cache_dir);
     cmd.arg("--todays-games").arg("--leagues").arg("MLB");
     cmd.timeout(Duration::from_secs(10));
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("Today's MLB Games"));
+    let _ = std::fs::remove_dir_all(&cache_dir);
     Ok(())
 }
 
@@ -23,6 +27,7 @@ fn test_nba_today_workflow() -> Result<()> {
     }
     
     let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("PLAINTEXT_SPORTS_CACHE_DIR", std::env::temp_dir().join(format!("plaintext-sports-nba-workflow-cache-{}", std::process::id())));
     cmd.arg("--todays-games").arg("--leagues").arg("NBA");
     cmd.timeout(Duration::from_secs(10));
     cmd.assert()
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use plaintext_sports::league::{DetailOptions, League};
+use plaintext_sports::mlb::{self, GameState};
+use plaintext_sports::nba;
+
+// Exercise a backend only through the trait, the way the CLI drives it
+async fn todays_ids<L: League>(league: &L) -> Result<Vec<(u32, GameState)>> {
+    let games = league.todays_games().await?;
+    Ok(games.iter().map(|game| (L::game_id(game), L::game_state(game))).collect())
+}
+
+#[tokio::test]
+async fn test_leagues_through_trait() -> Result<()> {
+    let mut mlb_server = mockito::Server::new_async().await;
+    let mlb_mock = mlb_server
+        .mock("GET", "/schedule")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "dates": [{
+                "games": [{
+                    "gamePk": 745444,
+                    "gameDate": "2024-03-28T20:10:00Z",
+                    "status": {"abstractGameState": "Preview", "detailedState": "Scheduled"},
+                    "teams": {
+                        "away": {"team": {"id": 145, "name": "Chicago White Sox", "abbreviation": "CWS"}},
+                        "home": {"team": {"id": 112, "name": "Chicago Cubs", "abbreviation": "CHC"}}
+                    },
+                    "venue": {"name": "Wrigley Field"}
+                }]
+            }]
+        }"#)
+        .create_async()
+        .await;
+
+    let mut nba_server = mockito::Server::new_async().await;
+    let nba_games_mock = nba_server
+        .mock("GET", "/games")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "data": [{
+                "id": 1001,
+                "date": "2024-03-28",
+                "home_team": {"id": 14, "abbreviation": "LAL", "full_name": "Los Angeles Lakers"},
+                "home_team_score": 110,
+                "period": 4,
+                "status": "Final",
+                "visitor_team": {"id": 2, "abbreviation": "BOS", "full_name": "Boston Celtics"},
+                "visitor_team_score": 104,
+                "home_q1": 28, "home_q2": 25, "home_q3": 30, "home_q4": 27,
+                "visitor_q1": 22, "visitor_q2": 31, "visitor_q3": 26, "visitor_q4": 25
+            }],
+            "meta": {"per_page": 100}
+        }"#)
+        .create_async()
+        .await;
+    let nba_stats_mock = nba_server
+        .mock("GET", "/stats")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [], "meta": {"per_page": 100}}"#)
+        .create_async()
+        .await;
+
+    // The shared clients read their configuration on first use
+    std::env::set_var("MLB_API_BASE_URL", mlb_server.url());
+    std::env::set_var("NBA_API_BASE_URL", nba_server.url());
+    std::env::set_var("NBA_API_KEY", "test-key");
+
+    assert_eq!(mlb::MlbApi::NAME, "MLB");
+    assert_eq!(todays_ids(mlb::get_mlb_api()).await?, vec![(745444, GameState::Scheduled)]);
+    mlb_mock.assert_async().await;
+
+    let nba_api = nba::get_nba_api()?;
+    assert_eq!(nba::NbaApi::NAME, "NBA");
+    assert_eq!(todays_ids(nba_api).await?, vec![(1001, GameState::Final)]);
+
+    let games = nba_api.todays_games().await?;
+    let sections = nba_api.game_detail(&games[0], DetailOptions::default()).await;
+    assert_eq!(sections.len(), 2);
+    assert_eq!(sections[0].heading, "Line Score");
+    assert!(sections[0].body.as_deref().unwrap().contains("LAL  28  25  30  27 | 110"));
+    assert_eq!(sections[1].heading, "Player Statistics");
+    assert_eq!(
+        sections[1].body.as_deref().unwrap(),
+        "No player statistics available for this game."
+    );
+    nba_games_mock.expect(2).assert_async().await;
+    nba_stats_mock.assert_async().await;
+    Ok(())
+}
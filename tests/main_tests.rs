@@ -1,6 +1,15 @@
 use anyhow::Result;
 use assert_cmd::Command;
 use predicates::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// A fresh directory for each run's response cache, so tests that list finished games are never
+// served (and never write into) the developer's own cached responses
+fn temp_cache_dir() -> std::path::PathBuf {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+    let run = RUNS.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir().join(format!("plaintext-sports-test-cache-{}-{}", std::process::id(), run))
+}
 
 #[test]
 fn test_cli_help() -> Result<()> {
@@ -10,7 +19,8 @@ fn test_cli_help() -> Result<()> {
         .success()
         .stdout(predicate::str::contains("Usage: plaintext-sports [OPTIONS] [COMMAND]"))
         .stdout(predicate::str::contains("MLB related commands"))
-        .stdout(predicate::str::contains("NBA related commands"));
+        .stdout(predicate::str::contains("NBA related commands"))
+        .stdout(predicate::str::contains("NHL related commands"));
     Ok(())
 }
 
@@ -29,10 +39,11 @@ fn test_cli_no_args() -> Result<()> {
         .success()
         .stdout(predicate::str::contains("Welcome to Plaintext Sports!"))
         .stdout(predicate::str::contains("Usage Examples:"))
-        .stdout(predicate::str::contains("Get all of today's games (MLB and NBA):"))
-        .stdout(predicate::str::contains("Get all of yesterday's games (MLB and NBA):"))
+        .stdout(predicate::str::contains("Get all of today's MLB and NBA games:"))
+        .stdout(predicate::str::contains("Get all of yesterday's MLB and NBA games:"))
         .stdout(predicate::str::contains("MLB Commands:"))
-        .stdout(predicate::str::contains("NBA Commands:"));
+        .stdout(predicate::str::contains("NBA Commands:"))
+        .stdout(predicate::str::contains("NHL Commands:"));
     Ok(())
 }
 
@@ -46,6 +57,24 @@ fn test_cli_invalid_command() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_cli_completions() -> Result<()> {
+    let output = Command::cargo_bin("plaintext-sports")?
+        .args(["completions", "bash"])
+        .output()?;
+    assert!(output.status.success());
+    let script = String::from_utf8(output.stdout)?;
+    assert!(script.contains("plaintext__sports__subcmd__mlb"), "{}", script);
+    assert!(script.contains("plaintext__sports__subcmd__nba"), "{}", script);
+    // Nothing but the script is printed, so it can be sourced directly
+    assert!(!script.contains("Welcome"));
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.args(["completions", "tcsh"]);
+    cmd.assert().failure();
+    Ok(())
+}
+
 #[test]
 fn test_cli_name_arg() -> Result<()> {
     let mut cmd = Command::cargo_bin("plaintext-sports")?;
@@ -55,4 +84,1274 @@ fn test_cli_name_arg() -> Result<()> {
         .stdout(predicate::str::contains("Hello, John!"))
         .stdout(predicate::str::contains("Usage Examples:"));
     Ok(())
-} 
\ No newline at end of file
+} 
+#[test]
+fn test_cli_mlb_teams() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/teams")
+        .match_query(mockito::Matcher::UrlEncoded("sportId".into(), "1".into()))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "teams": [
+                {"id": 147, "name": "New York Yankees", "abbreviation": "NYY", "division": {"id": 201, "name": "American League East"}},
+                {"id": 145, "name": "Chicago White Sox", "abbreviation": "CWS", "division": {"id": 202, "name": "American League Central"}}
+            ]
+        }"#)
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", server.url()).args(["mlb", "teams"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("MLB Teams:"))
+        .stdout(predicate::str::contains("American League Central:\n   145  CWS  Chicago White Sox"))
+        .stdout(predicate::str::contains("American League East:\n   147  NYY  New York Yankees"));
+    mock.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_mlb_team_by_abbreviation() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let teams = server
+        .mock("GET", "/teams")
+        .match_query(mockito::Matcher::UrlEncoded("sportId".into(), "1".into()))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "teams": [
+                {"id": 121, "name": "New York Mets", "abbreviation": "NYM"},
+                {"id": 147, "name": "New York Yankees", "abbreviation": "NYY"}
+            ]
+        }"#)
+        .expect(2)
+        .create();
+    let team = server
+        .mock("GET", "/teams/147")
+        .match_query(mockito::Matcher::UrlEncoded("hydrate".into(), "venue(location,timezone)".into()))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"teams": [{"id": 147, "name": "New York Yankees", "abbreviation": "NYY", "league": {"id": 103, "name": "American League"}}]}"#)
+        .create();
+    let standings = server
+        .mock("GET", "/standings")
+        .match_query(mockito::Matcher::UrlEncoded("leagueId".into(), "103".into()))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"records": [{"teamRecords": [{"team": {"id": 147, "name": "New York Yankees"}, "wins": 94, "losses": 68}]}]}"#)
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", server.url()).args(["mlb", "team", "--abbr", "nyy"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("New York Yankees"))
+        .stdout(predicate::str::contains("Record: 94-68 (.580)"));
+    team.assert();
+    standings.assert();
+
+    // An unknown abbreviation suggests the ones it's close to
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", server.url()).args(["mlb", "team", "--id", "NYX"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown MLB team 'NYX'. Did you mean: NYM, NYY?"));
+    teams.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_nba_teams() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/teams")
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "data": [
+                {"id": 14, "abbreviation": "LAL", "city": "Los Angeles", "conference": "West", "division": "Pacific", "full_name": "Los Angeles Lakers", "name": "Lakers"},
+                {"id": 37, "abbreviation": "CHS", "city": "Chicago", "conference": "", "division": "", "full_name": "Chicago Stags", "name": "Stags"}
+            ]
+        }"#)
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("NBA_API_BASE_URL", server.url())
+        .env("NBA_API_KEY", "test-key")
+        .args(["nba", "teams"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Pacific:\n    14  LAL  Los Angeles Lakers"))
+        .stdout(predicate::str::contains("Chicago Stags").not());
+    mock.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_nhl_game() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let feed = server
+        .mock("GET", "/game/2023020001/feed/live")
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "gameData": {
+                "game": {"pk": 2023020001},
+                "datetime": {"dateTime": "2023-10-10T21:30:00Z"},
+                "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                "teams": {
+                    "away": {"id": 18, "name": "Nashville Predators", "abbreviation": "NSH"},
+                    "home": {"id": 14, "name": "Tampa Bay Lightning", "abbreviation": "TBL"}
+                }
+            },
+            "liveData": {"linescore": {"currentPeriodOrdinal": "3rd", "teams": {"away": {"goals": 3}, "home": {"goals": 5}}}}
+        }"#)
+        .create();
+    let linescore = server
+        .mock("GET", "/game/2023020001/linescore")
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "periods": [
+                {"ordinalNum": "1st", "away": {"goals": 1}, "home": {"goals": 2}},
+                {"ordinalNum": "2nd", "away": {"goals": 2}, "home": {"goals": 1}},
+                {"ordinalNum": "3rd", "away": {"goals": 0}, "home": {"goals": 2}}
+            ],
+            "teams": {
+                "away": {"team": {"id": 18, "name": "Nashville Predators", "abbreviation": "NSH"}, "goals": 3, "shotsOnGoal": 24},
+                "home": {"team": {"id": 14, "name": "Tampa Bay Lightning", "abbreviation": "TBL"}, "goals": 5, "shotsOnGoal": 33}
+            }
+        }"#)
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("NHL_API_BASE_URL", server.url())
+        .env("NO_COLOR", "1")
+        .args(["nhl", "game", "--id", "2023020001"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Winner: Tampa Bay Lightning"))
+        .stdout(predicate::str::contains("Period-by-Period Breakdown"))
+        .stdout(predicate::str::contains("TBL     2   1   2  |  5  33"));
+    feed.assert();
+    linescore.assert();
+
+    // With --summary only the result line is printed
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("NHL_API_BASE_URL", server.url())
+        .args(["nhl", "game", "--id", "2023020001", "--summary"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("NSH 3 @ TBL 5 (Final)"))
+        .stdout(predicate::str::contains("Period-by-Period").not());
+    Ok(())
+}
+
+#[test]
+fn test_cli_mlb_teams_json() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/teams")
+        .match_query(mockito::Matcher::UrlEncoded("sportId".into(), "1".into()))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"teams": [{"id": 145, "name": "Chicago White Sox", "abbreviation": "CWS"}]}"#)
+        .create();
+
+    // Logs and the greeting stay off stdout, leaving only the JSON document
+    let output = Command::cargo_bin("plaintext-sports")?
+        .env("MLB_API_BASE_URL", server.url())
+        .args(["--format", "json", "--name", "John", "mlb", "teams"])
+        .output()?;
+    assert!(output.status.success());
+    let document: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(document["teams"][0]["id"], 145);
+    assert_eq!(document["teams"][0]["name"], "Chicago White Sox");
+    mock.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_retries_server_errors() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let unavailable = server
+        .mock("GET", "/teams")
+        .match_query(mockito::Matcher::UrlEncoded("sportId".into(), "1".into()))
+        .with_status(503)
+        .expect(1)
+        .create();
+    let teams = server
+        .mock("GET", "/teams")
+        .match_query(mockito::Matcher::UrlEncoded("sportId".into(), "1".into()))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"teams": [{"id": 145, "name": "Chicago White Sox", "abbreviation": "CWS"}]}"#)
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", server.url()).args(["mlb", "teams"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Chicago White Sox"));
+    unavailable.assert();
+    teams.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_retries_can_be_disabled() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let unavailable = server
+        .mock("GET", "/teams")
+        .match_query(mockito::Matcher::UrlEncoded("sportId".into(), "1".into()))
+        .with_status(503)
+        .expect(1)
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", server.url())
+        .env("PLAINTEXT_SPORTS_HTTP_RETRIES", "0")
+        .args(["mlb", "teams"]);
+    cmd.assert()
+        .stdout(predicate::str::contains("HTTP 503"));
+    unavailable.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_rate_limited_and_not_found() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let limited = server
+        .mock("GET", "/teams")
+        .match_query(mockito::Matcher::UrlEncoded("sportId".into(), "1".into()))
+        .with_status(429)
+        .with_header("retry-after", "30")
+        .expect(1)
+        .create();
+    let missing = server
+        .mock("GET", "/teams/999999")
+        .match_query(mockito::Matcher::UrlEncoded("hydrate".into(), "venue(location,timezone)".into()))
+        .with_status(404)
+        .expect(1)
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", server.url())
+        .env("PLAINTEXT_SPORTS_HTTP_RETRIES", "0")
+        .args(["mlb", "teams"]);
+    cmd.assert()
+        .stdout(predicate::str::contains("Rate limited, retry in 30 seconds"));
+    limited.assert();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", server.url()).args(["mlb", "team", "--id", "999999"]);
+    cmd.assert()
+        .stdout(predicate::str::contains("Team 999999 not found"));
+    missing.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_request_timeout() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let slow = server
+        .mock("GET", "/teams")
+        .match_query(mockito::Matcher::UrlEncoded("sportId".into(), "1".into()))
+        .with_body_from_request(|_| {
+            std::thread::sleep(std::time::Duration::from_secs(2));
+            br#"{"teams": []}"#.to_vec()
+        })
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", server.url())
+        .env("PLAINTEXT_SPORTS_REQUEST_TIMEOUT_SECS", "1")
+        .env("PLAINTEXT_SPORTS_HTTP_RETRIES", "0")
+        .args(["mlb", "teams"]);
+    cmd.assert()
+        .stdout(predicate::str::contains("Request timed out"));
+    slow.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_games_on_date() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/schedule")
+        .match_query(mockito::Matcher::UrlEncoded("date".into(), "2024-07-04".into()))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"dates": []}"#)
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", server.url()).args(["--date", "2024-07-04", "--leagues", "MLB"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("MLB Games for 2024-07-04:"))
+        .stdout(predicate::str::contains("No MLB games played on 2024-07-04."))
+        .stdout(predicate::str::contains("Usage Examples:").not());
+    mock.assert();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.args(["--date", "July 4"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid date 'July 4'"));
+    Ok(())
+}
+
+#[test]
+fn test_cli_nba_api_key_file() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/teams")
+        .match_header("authorization", "key-from-file")
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [{"id": 14, "abbreviation": "LAL", "city": "Los Angeles", "conference": "West", "division": "Pacific", "full_name": "Los Angeles Lakers", "name": "Lakers"}]}"#)
+        .create();
+
+    let key_file = std::env::temp_dir().join(format!("plaintext-sports-cli-key-{}", std::process::id()));
+    std::fs::write(&key_file, "key-from-file\n")?;
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("NBA_API_BASE_URL", server.url())
+        .env_remove("NBA_API_KEY")
+        .env("NBA_API_KEY_FILE", &key_file)
+        .args(["nba", "teams"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Los Angeles Lakers"));
+    std::fs::remove_file(&key_file)?;
+    mock.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_nba_without_api_key() -> Result<()> {
+    // An empty config file keeps a key in the user's own config from being picked up
+    let config_file = std::env::temp_dir().join(format!("plaintext-sports-no-key-{}.toml", std::process::id()));
+    std::fs::write(&config_file, "")?;
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("PLAINTEXT_SPORTS_CONFIG", &config_file)
+        .env("RUST_BACKTRACE", "1")
+        .env_remove("NBA_API_KEY")
+        .env_remove("NBA_API_KEY_FILE")
+        .args(["nba", "teams"]);
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("NBA features require NBA_API_KEY; set it in the environment or config."))
+        .stderr(predicate::str::contains("panicked").not())
+        .stderr(predicate::str::contains("stack backtrace").not());
+    std::fs::remove_file(&config_file)?;
+    Ok(())
+}
+
+#[test]
+fn test_cli_replay_dir() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("plaintext-sports-replay-cli-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("mlb"))?;
+    std::fs::write(
+        dir.join("mlb/teams@sportId=1.json"),
+        r#"{"teams": [{"id": 147, "name": "New York Yankees", "abbreviation": "NYY", "division": {"id": 201, "name": "American League East"}}]}"#,
+    )?;
+
+    // Nothing listens on the discard port, so any request that reaches the network fails
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", "http://127.0.0.1:9/api/v1")
+        .args(["mlb", "teams", "--replay-dir"])
+        .arg(&dir);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("American League East:\n   147  NYY  New York Yankees"));
+
+    // A request without a recorded response names the fixture it was looking for
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", "http://127.0.0.1:9/api/v1")
+        .env("PLAINTEXT_SPORTS_REPLAY_DIR", &dir)
+        .args(["mlb", "player", "--id", "547989"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No replay fixture for http://127.0.0.1:9/api/v1/people/547989"))
+        .stdout(predicate::str::contains("people").and(predicate::str::contains("547989.json")));
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_cli_dump_raw() -> Result<()> {
+    let body = r#"{"teams": [{"id": 147, "name": "New York Yankees", "abbreviation": "NYY", "division": {"id": 201, "name": "American League East"}}]}"#;
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/teams?sportId=1")
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .create();
+
+    let dir = std::env::temp_dir().join(format!("plaintext-sports-dump-cli-{}", std::process::id()));
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", server.url())
+        .args(["mlb", "teams", "--dump-raw"])
+        .arg(&dir);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("147  NYY  New York Yankees"));
+    mock.assert();
+
+    assert_eq!(std::fs::read_to_string(dir.join("mlb-teams-sportId=1.json"))?, body);
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_cli_also_writes_json() -> Result<()> {
+    let mut mlb_server = mockito::Server::new();
+    let mlb_mock = mlb_server
+        .mock("GET", "/schedule")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "dates": [{
+                "games": [{
+                    "gamePk": 745444,
+                    "gameDate": "2024-03-28T20:10:00Z",
+                    "status": {"detailedState": "Scheduled"},
+                    "teams": {
+                        "away": {"team": {"id": 145, "name": "Chicago White Sox", "abbreviation": "CWS"}},
+                        "home": {"team": {"id": 112, "name": "Chicago Cubs", "abbreviation": "CHC"}}
+                    },
+                    "venue": {"name": "Wrigley Field"}
+                }]
+            }]
+        }"#)
+        .create();
+
+    let mut nba_server = mockito::Server::new();
+    let nba_mock = nba_server
+        .mock("GET", "/games")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [], "meta": {"per_page": 100}}"#)
+        .create();
+
+    let path = std::env::temp_dir().join(format!("plaintext-sports-also-{}.json", std::process::id()));
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", mlb_server.url())
+        .env("NBA_API_BASE_URL", nba_server.url())
+        .env("NBA_API_KEY", "test-key")
+        .env("PLAINTEXT_SPORTS_CACHE_DIR", temp_cache_dir())
+        .args(["--todays-games", "--leagues", "MLB,NBA", "--also", &format!("json:{}", path.display())]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Chicago White Sox"));
+    mlb_mock.assert();
+    nba_mock.assert();
+
+    let written: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+    std::fs::remove_file(&path)?;
+    let mlb_games = written["days"][0]["mlb"].as_array().unwrap();
+    assert_eq!(mlb_games.len(), 1);
+    assert!(written["days"][0]["nba"].as_array().unwrap().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_cli_quiet_hides_info_logs() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let _mock = server
+        .mock("GET", "/schedule")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"dates": []}"#)
+        .create();
+
+    let output = Command::cargo_bin("plaintext-sports")?
+        .env("MLB_API_BASE_URL", server.url())
+        .args(["--quiet", "--todays-games", "--leagues", "MLB"])
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stdout.contains("No MLB games scheduled for today."), "{}", stdout);
+    assert!(!stderr.contains("Fetching"), "{}", stderr);
+    assert!(!stdout.contains("Fetching") && !stdout.contains("Welcome"), "{}", stdout);
+    Ok(())
+}
+
+#[test]
+fn test_cli_one_league_failing_still_shows_the_other() -> Result<()> {
+    let mut mlb_server = mockito::Server::new();
+    let mlb_mock = mlb_server
+        .mock("GET", "/schedule")
+        .match_query(mockito::Matcher::Any)
+        .with_status(404)
+        .create();
+
+    let mut nba_server = mockito::Server::new();
+    let nba_mock = nba_server
+        .mock("GET", "/games")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [], "meta": {"per_page": 100}}"#)
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", mlb_server.url())
+        .env("NBA_API_BASE_URL", nba_server.url())
+        .env("NBA_API_KEY", "test-key")
+        .args(["--todays-games", "--leagues", "MLB,NBA"]);
+    cmd.assert()
+        .stdout(predicate::str::contains("Error fetching today's MLB games"))
+        .stdout(predicate::str::contains("No NBA games scheduled for today."));
+    mlb_mock.assert();
+    nba_mock.assert();
+    Ok(())
+}
+
+// Mock an MLB schedule of two finished games, 717465 and 717466
+fn mock_two_final_games(server: &mut mockito::Server) -> mockito::Mock {
+    let game = |game_pk: u32, away: &str, home: &str| format!(
+        r#"{{"gamePk": {game_pk}, "gameDate": "2024-03-28T20:10:00Z",
+            "status": {{"abstractGameState": "Final", "detailedState": "Final"}},
+            "teams": {{"away": {{"score": 3, "team": {{"name": "{away}"}}}}, "home": {{"score": 5, "team": {{"name": "{home}"}}}}}}}}"#
+    );
+    server
+        .mock("GET", "/schedule")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"dates": [{{"games": [{}, {}]}}]}}"#,
+            game(717465, "Chicago White Sox", "Chicago Cubs"),
+            game(717466, "New York Yankees", "Boston Red Sox"),
+        ))
+        .create()
+}
+
+#[test]
+fn test_cli_error_summary_names_only_the_failing_game() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let _schedule = mock_two_final_games(&mut server);
+    // Detailed stats come from the same live feed as the innings, which is cached once it's fetched
+    // for the innings; 717466's feed is missing its boxscore
+    let feed = |boxscore: &str| format!(
+        r#"{{"gameData": {{"status": {{"abstractGameState": "Final"}}}},
+            "liveData": {{"linescore": {{"teams": {{"away": {{"runs": 3}}, "home": {{"runs": 5}}}}}}{}}}}}"#,
+        boxscore
+    );
+    let feed_with_boxscore = server
+        .mock("GET", "/game/717465/feed/live")
+        .with_header("content-type", "application/json")
+        .with_body(feed(r#", "boxscore": {"teams": {"away": {"team": {"name": "Chicago White Sox"}}, "home": {"team": {"name": "Chicago Cubs"}}}}"#))
+        .expect(1)
+        .create();
+    let feed_without_boxscore = server
+        .mock("GET", "/game/717466/feed/live")
+        .with_header("content-type", "application/json")
+        .with_body(feed(""))
+        .expect(1)
+        .create();
+
+    let cache_dir = temp_cache_dir();
+    let output = Command::cargo_bin("plaintext-sports")?
+        .env("MLB_API_BASE_URL", server.url())
+        .env("PLAINTEXT_SPORTS_HTTP_RETRIES", "0")
+        .env("PLAINTEXT_SPORTS_CACHE_DIR", &cache_dir)
+        .args(["--todays-games", "--leagues", "MLB", "--detailed-stats"])
+        .output()?;
+    let _ = std::fs::remove_dir_all(&cache_dir);
+    feed_with_boxscore.assert();
+    feed_without_boxscore.assert();
+    let stdout = String::from_utf8(output.stdout)?;
+    let summary = &stdout[stdout.find("Completed with").expect(&stdout)..];
+    assert_eq!(
+        summary.trim_end(),
+        "Completed with 1 error (affected game IDs: 717466):\n  \
+         MLB game 717466 (detailed game stats): Missing teams data"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_cli_caches_only_final_responses() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let _schedule = mock_two_final_games(&mut server);
+    // The listing calls both games final, but 717466's feed says it's still being played
+    let feed = |state: &str| format!(
+        r#"{{"gameData": {{"status": {{"abstractGameState": "{state}"}}}},
+            "liveData": {{"linescore": {{"teams": {{"away": {{"runs": 3}}, "home": {{"runs": 5}}}}}}}}}}"#
+    );
+    let final_feed = server
+        .mock("GET", "/game/717465/feed/live")
+        .with_header("content-type", "application/json")
+        .with_body(feed("Final"))
+        .expect(1)
+        .create();
+    let live_feed = server
+        .mock("GET", "/game/717466/feed/live")
+        .with_header("content-type", "application/json")
+        .with_body(feed("Live"))
+        .expect(2)
+        .create();
+
+    let cache_dir = temp_cache_dir();
+    for _ in 0..2 {
+        Command::cargo_bin("plaintext-sports")?
+            .env("MLB_API_BASE_URL", server.url())
+            .env("PLAINTEXT_SPORTS_CACHE_DIR", &cache_dir)
+            .args(["--todays-games", "--leagues", "MLB"])
+            .assert()
+            .success();
+    }
+    final_feed.assert();
+    live_feed.assert();
+
+    // The final game's raw response is stored under the SHA-256 of its URL
+    let entry = |game_pk: u32| cache_dir.join(plaintext_sports::cache::entry_name(&format!("{}/game/{}/feed/live", server.url(), game_pk)));
+    assert_eq!(std::fs::read_to_string(entry(717465))?, feed("Final"));
+    assert!(!entry(717466).exists());
+    std::fs::remove_dir_all(&cache_dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_cli_progress() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let _schedule = mock_two_final_games(&mut server);
+    let _details = server
+        .mock("GET", mockito::Matcher::Regex("^/game/71746[56]/".to_string()))
+        .with_status(404)
+        .create();
+
+    let run = |args: &[&str]| -> Result<String> {
+        let output = Command::cargo_bin("plaintext-sports")?
+            .env("MLB_API_BASE_URL", server.url())
+            .env("PLAINTEXT_SPORTS_HTTP_RETRIES", "0")
+            .env("PLAINTEXT_SPORTS_CACHE_DIR", temp_cache_dir())
+            .args(["--todays-games", "--leagues", "MLB"])
+            .args(args)
+            .output()?;
+        Ok(String::from_utf8(output.stderr)?)
+    };
+
+    // Each finished game's details are counted off on stderr, which isn't a terminal here
+    let stderr = run(&["--progress"])?;
+    assert!(stderr.contains("Fetched 1/2 games...\nFetched 2/2 games...\n"), "{}", stderr);
+    assert!(!run(&[])?.contains("Fetched"));
+
+    // --quiet and JSON output never show it
+    for args in [["--progress", "--quiet"], ["--progress", "--format=json"]] {
+        let stderr = run(&args)?;
+        assert!(!stderr.contains("Fetched"), "{}", stderr);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_cli_clear_cache() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("plaintext-sports-clear-cache-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let boxscore = dir.join(plaintext_sports::cache::entry_name("https://statsapi.mlb.com/api/v1/game/745444/boxscore"));
+    let feed = dir.join(plaintext_sports::cache::entry_name("https://statsapi.mlb.com/api/v1/game/745444/feed/live"));
+    std::fs::write(&boxscore, "{}")?;
+    std::fs::write(&feed, "{}")?;
+    std::fs::write(dir.join("notes.txt"), "not ours")?;
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.args(["--clear-cache", "--cache-dir", dir.to_str().unwrap()]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Removed 2 cached responses"))
+        .stdout(predicate::str::contains("Usage Examples:").not());
+    // Only the cache's own entries are removed
+    assert!(!boxscore.exists());
+    assert!(!feed.exists());
+    assert_eq!(std::fs::read_to_string(dir.join("notes.txt"))?, "not ours");
+
+    // Caching is opt-in, so without a directory there's nothing to clear
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env_remove("PLAINTEXT_SPORTS_CACHE_DIR")
+        .env("PLAINTEXT_SPORTS_CONFIG", dir.join("missing.toml"))
+        .args(["--clear-cache"]);
+    cmd.assert()
+        .stdout(predicate::str::contains("No cache to clear"));
+    assert_eq!(std::fs::read_to_string(dir.join("notes.txt"))?, "not ours");
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_cli_watch_stops_once_every_game_is_final() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let schedule = server
+        .mock("GET", "/schedule")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "dates": [{
+                "games": [{
+                    "gamePk": 745444,
+                    "gameDate": "2024-03-28T20:10:00Z",
+                    "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                    "teams": {
+                        "away": {"score": 3, "team": {"id": 145, "name": "Chicago White Sox"}},
+                        "home": {"score": 5, "team": {"id": 112, "name": "Chicago Cubs"}}
+                    }
+                }]
+            }]
+        }"#)
+        .expect(1)
+        .create();
+    let _details = server
+        .mock("GET", mockito::Matcher::Regex("^/game/745444/".to_string()))
+        .with_status(404)
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", server.url())
+        .env("PLAINTEXT_SPORTS_CACHE_DIR", temp_cache_dir())
+        .args(["--todays-games", "--leagues", "MLB", "--watch", "1", "--no-cache"]);
+    cmd.timeout(std::time::Duration::from_secs(10));
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Chicago Cubs"))
+        .stdout(predicate::str::contains("All games are final."))
+        .stdout(predicate::str::contains("refreshing every").not());
+    schedule.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_also_rejects_bad_spec() -> Result<()> {
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.args(["--todays-games", "--also", "xml:games.xml"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown output format 'xml'"));
+    Ok(())
+}
+
+#[test]
+fn test_cli_rejects_unknown_league() -> Result<()> {
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.args(["--todays-games", "--leagues", "foo"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown league 'foo' for --leagues, valid leagues are MLB, NBA"));
+    Ok(())
+}
+
+#[test]
+fn test_cli_mlb_game_has_no_debug_output() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/game/745444/feed/live")
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "gameData": {
+                "gamePk": 745444,
+                "gameDate": "2024-03-28T20:10:00Z",
+                "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                "venue": {"id": 17, "name": "Wrigley Field"}
+            },
+            "away": {"id": 145, "name": "Chicago White Sox", "runs": 3},
+            "home": {"id": 112, "name": "Chicago Cubs", "runs": 5}
+        }"#)
+        .expect_at_least(1)
+        .create();
+
+    let output = Command::cargo_bin("plaintext-sports")?
+        .env("MLB_API_BASE_URL", server.url())
+        .args(["mlb", "game", "--id", "745444", "--detailed-stats"])
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.contains("Game ID: 745444"));
+    assert!(!stdout.lines().any(|line| line.starts_with("DEBUG:")), "{}", stdout);
+    mock.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_mlb_game_from_file() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let mock = server.mock("GET", mockito::Matcher::Any).expect(0).create();
+    let path = std::env::temp_dir().join(format!("plaintext-sports-game-{}.json", std::process::id()));
+    std::fs::write(&path, r#"{
+        "gameData": {
+            "gamePk": 745444,
+            "gameDate": "2024-03-28T20:10:00Z",
+            "status": {"abstractGameState": "Final", "detailedState": "Final"},
+            "teams": {
+                "away": {"id": 145, "name": "Chicago White Sox"},
+                "home": {"id": 112, "name": "Chicago Cubs"}
+            },
+            "venue": {"id": 17, "name": "Wrigley Field"}
+        },
+        "liveData": {"linescore": {"teams": {"away": {"runs": 3}, "home": {"runs": 5}}}}
+    }"#)?;
+
+    let output = Command::cargo_bin("plaintext-sports")?
+        .env("MLB_API_BASE_URL", server.url())
+        .args(["mlb", "game", "--from-file", &path.display().to_string()])
+        .output()?;
+    std::fs::remove_file(&path)?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.contains("Game ID: 745444"), "{}", stdout);
+    assert!(stdout.contains("Winner: Chicago Cubs"), "{}", stdout);
+    // Nothing is fetched for a game read from disk
+    mock.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_nba_follows_every_page() -> Result<()> {
+    let mut server = mockito::Server::new();
+    let lakers = r#"{"id": 14, "abbreviation": "LAL", "city": "Los Angeles", "conference": "West", "division": "Pacific", "full_name": "Los Angeles Lakers", "name": "Lakers"}"#;
+    let celtics = r#"{"id": 2, "abbreviation": "BOS", "city": "Boston", "conference": "East", "division": "Atlantic", "full_name": "Boston Celtics", "name": "Celtics"}"#;
+    let game = |id: u32, date: &str| format!(
+        r#"{{"id": {id}, "date": "{date}", "home_team": {lakers}, "home_team_score": 110, "period": 4, "status": "Final", "visitor_team": {celtics}, "visitor_team_score": 104}}"#
+    );
+    let player = |id: u32, first: &str, last: &str, team: &str| format!(
+        r#"{{"id": {id}, "pts": 20, "player": {{"id": {id}, "first_name": "{first}", "last_name": "{last}"}}, "team": {team}, "game": {{"id": 1001, "home_team_id": 14, "visitor_team_id": 2}}}}"#
+    );
+    // Each endpoint hands back two pages; the first page's request has only the one filter
+    let first_page = mockito::Matcher::Regex("^[^&]*$".to_string());
+    let mocks = vec![
+        server
+            .mock("GET", "/teams/14")
+            .with_header("content-type", "application/json")
+            .with_body(format!(r#"{{"data": {lakers}}}"#))
+            .create(),
+        server
+            .mock("GET", "/games")
+            .match_query(first_page.clone())
+            .with_header("content-type", "application/json")
+            .with_body(format!(r#"{{"data": [{}], "meta": {{"next_cursor": 5, "per_page": 1}}}}"#, game(1001, "2024-03-28")))
+            .create(),
+        server
+            .mock("GET", "/games")
+            .match_query(mockito::Matcher::Regex("cursor=5".to_string()))
+            .with_header("content-type", "application/json")
+            .with_body(format!(r#"{{"data": [{}], "meta": {{"per_page": 1}}}}"#, game(1002, "2024-03-30")))
+            .create(),
+        server
+            .mock("GET", "/stats")
+            .match_query(first_page)
+            .with_header("content-type", "application/json")
+            .with_body(format!(r#"{{"data": [{}], "meta": {{"next_cursor": 9, "per_page": 1}}}}"#, player(237, "LeBron", "James", lakers)))
+            .expect(2)
+            .create(),
+        server
+            .mock("GET", "/stats")
+            .match_query(mockito::Matcher::Regex("cursor=9".to_string()))
+            .with_header("content-type", "application/json")
+            .with_body(format!(r#"{{"data": [{}], "meta": {{"per_page": 1}}}}"#, player(434, "Jayson", "Tatum", celtics)))
+            .expect(2)
+            .create(),
+    ];
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("NBA_API_BASE_URL", server.url())
+        .env("NBA_API_KEY", "test-key")
+        .env("PLAINTEXT_SPORTS_CACHE_DIR", temp_cache_dir())
+        .args(["nba", "team", "--id", "14", "--schedule"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Game 1: ID 1001"))
+        .stdout(predicate::str::contains("Game 2: ID 1002"))
+        .stdout(predicate::str::contains("LeBron James: 20 pts"))
+        .stdout(predicate::str::contains("Jayson Tatum: 20 pts"));
+    for mock in mocks {
+        mock.assert();
+    }
+    Ok(())
+}
+
+// Mock a slate with a finished and an upcoming game in each league. Finished MLB games get no
+// inning data, so the error path and the end-of-run summary are covered too.
+fn mock_slate(mlb_server: &mut mockito::Server, nba_server: &mut mockito::Server) -> Vec<mockito::Mock> {
+    let team = |id: u32, abbreviation: &str, city: &str, name: &str| format!(
+        r#"{{"id": {id}, "abbreviation": "{abbreviation}", "city": "{city}", "conference": "West", "division": "Pacific", "full_name": "{city} {name}", "name": "{name}"}}"#
+    );
+    let lakers = team(14, "LAL", "Los Angeles", "Lakers");
+    let celtics = team(2, "BOS", "Boston", "Celtics");
+    let nba_games = format!(
+        r#"{{
+            "data": [
+                {{"id": 1001, "date": "2024-03-28", "home_team": {lakers}, "home_team_score": 110, "period": 4, "status": "Final", "visitor_team": {celtics}, "visitor_team_score": 104}},
+                {{"id": 1002, "date": "2024-03-28", "home_team": {celtics}, "home_team_score": 0, "period": 0, "status": "2024-03-29T23:30:00Z", "visitor_team": {lakers}, "visitor_team_score": 0}}
+            ],
+            "meta": {{"per_page": 100}}
+        }}"#
+    );
+
+    vec![
+        mlb_server
+            .mock("GET", "/schedule")
+            .match_query(mockito::Matcher::Any)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{
+                "dates": [{
+                    "games": [
+                        {
+                            "gamePk": 745444,
+                            "gameDate": "2024-03-28T20:10:00Z",
+                            "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                            "teams": {
+                                "away": {"score": 3, "isWinner": false, "team": {"id": 145, "name": "Chicago White Sox", "abbreviation": "CWS"}},
+                                "home": {"score": 5, "isWinner": true, "team": {"id": 112, "name": "Chicago Cubs", "abbreviation": "CHC"}}
+                            },
+                            "venue": {"id": 17, "name": "Wrigley Field"}
+                        },
+                        {
+                            "gamePk": 745445,
+                            "gameDate": "2024-03-28T23:10:00Z",
+                            "status": {"abstractGameState": "Preview", "detailedState": "Scheduled"},
+                            "teams": {
+                                "away": {"team": {"id": 147, "name": "New York Yankees", "abbreviation": "NYY"}},
+                                "home": {"team": {"id": 111, "name": "Boston Red Sox", "abbreviation": "BOS"}}
+                            },
+                            "venue": {"id": 3, "name": "Fenway Park"}
+                        }
+                    ]
+                }]
+            }"#)
+            .create(),
+        mlb_server
+            .mock("GET", mockito::Matcher::Regex("^/game/745444/".to_string()))
+            .with_status(404)
+            .create(),
+        nba_server
+            .mock("GET", "/games")
+            .match_query(mockito::Matcher::Any)
+            .with_header("content-type", "application/json")
+            .with_body(nba_games)
+            .create(),
+        nba_server
+            .mock("GET", "/teams/14")
+            .with_header("content-type", "application/json")
+            .with_body(format!(r#"{{"data": {lakers}}}"#))
+            .create(),
+        nba_server
+            .mock("GET", "/stats")
+            .match_query(mockito::Matcher::Any)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": [], "meta": {"per_page": 100}}"#)
+            .create(),
+    ]
+}
+
+// Run the CLI against the mocked slate, keeping only what it prints for the user: log lines
+// aren't part of the listing
+fn slate_output(args: &[&str]) -> Result<String> {
+    let mut mlb_server = mockito::Server::new();
+    let mut nba_server = mockito::Server::new();
+    let mut nhl_server = mockito::Server::new();
+    let _mocks = mock_slate(&mut mlb_server, &mut nba_server);
+    // No NHL games on the slate's date
+    let _nhl_mock = nhl_server
+        .mock("GET", "/schedule")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"dates": []}"#)
+        .create();
+
+    let output = Command::cargo_bin("plaintext-sports")?
+        .env("MLB_API_BASE_URL", mlb_server.url())
+        .env("NBA_API_BASE_URL", nba_server.url())
+        .env("NHL_API_BASE_URL", nhl_server.url())
+        .env("NBA_API_KEY", "test-key")
+        .env("PLAINTEXT_SPORTS_CACHE_DIR", temp_cache_dir())
+        .env("NO_COLOR", "1")
+        .args(["--timezone", "UTC"])
+        .args(args)
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    let lines: Vec<&str> = stdout
+        .lines()
+        .filter(|line| !line.contains(" INFO "))
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+// What the listings printed for the mocked slate before they shared a pipeline; the output
+// shouldn't change as the fetch-and-display code is reorganized
+const TODAYS_SLATE: &str = r#"
+Today's MLB Games:
+
+==================================================
+Game 1: ID 745444
+==================================================
+Game ID: 745444
+Date: 2024-03-28
+Status: Final
+Teams:
+  Away:  Chicago White Sox (3)
+  Home:  Chicago Cubs (5)
+Winner: Chicago Cubs
+Venue: Wrigley Field
+
+Error fetching innings data: Could not retrieve inning data from any endpoint. Errors: feed/live: HTTP error: 404 Not Found, linescore: HTTP error: 404 Not Found, playByPlay: HTTP error: 404 Not Found
+
+==================================================
+Game 2: ID 745445
+==================================================
+Game ID: 745445
+Date: 2024-03-28
+Status: Scheduled
+Start Time: 11:10 PM
+Teams:
+  Away:  New York Yankees (0)
+  Home:  Boston Red Sox (0)
+Venue: Fenway Park
+
+
+Detailed information not available for games that haven't been completed.
+
+Today's NBA Games:
+
+==================================================
+Game 1: ID 1001
+==================================================
+BOS @ LAL - 2024-03-28
+Celtics: 104
+Lakers: 110
+Season: 0, Final
+Winner: Los Angeles Lakers
+
+Line Score:
+    |   T
+----+----
+BOS | 104
+LAL | 110
+
+Player Statistics:
+No player statistics available for this game.
+
+==================================================
+Game 2: ID 1002
+==================================================
+LAL @ BOS - 2024-03-28
+Lakers: 0
+Celtics: 0
+Season: 0, Scheduled - 11:30 PM
+
+Detailed player statistics not available for games that haven't been completed.
+
+Completed with 1 error (affected game IDs: 745444):
+  MLB game 745444 (innings data): Could not retrieve inning data from any endpoint. Errors: feed/live: HTTP error: 404 Not Found, linescore: HTTP error: 404 Not Found, playByPlay: HTTP error: 404 Not Found"#;
+
+const NBA_TEAM_SCHEDULE: &str = r#"
+NBA Team Information:
+Los Angeles Lakers (LAL)
+Conference: West, Division: Pacific
+
+NBA Schedule:
+
+==================================================
+Game 1: ID 1001
+==================================================
+BOS @ LAL - 2024-03-28
+Celtics: 104
+Lakers: 110
+Season: 0, Final
+Winner: Los Angeles Lakers
+
+Line Score:
+    |   T
+----+----
+BOS | 104
+LAL | 110
+
+Player Statistics:
+No player statistics available for this game.
+
+==================================================
+Game 2: ID 1002
+==================================================
+LAL @ BOS - 2024-03-28
+Lakers: 0
+Celtics: 0
+Season: 0, Scheduled - 11:30 PM
+
+Detailed player statistics not available for games that haven't been completed.
+
+Record in these games: 1-0 (avg. point differential: +6.0)"#;
+
+#[test]
+fn test_cli_slate_json() -> Result<()> {
+    let mut mlb_server = mockito::Server::new();
+    let mut nba_server = mockito::Server::new();
+    let _mocks = mock_slate(&mut mlb_server, &mut nba_server);
+
+    let output = Command::cargo_bin("plaintext-sports")?
+        .env("MLB_API_BASE_URL", mlb_server.url())
+        .env("NBA_API_BASE_URL", nba_server.url())
+        .env("NBA_API_KEY", "test-key")
+        .env("PLAINTEXT_SPORTS_CACHE_DIR", temp_cache_dir())
+        .args(["--format", "json", "--todays-games", "--leagues", "MLB,NBA"])
+        .output()?;
+    assert!(output.status.success());
+    let document: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let day = &document["days"][0];
+    assert_eq!(day["mlb"].as_array().map(Vec::len), Some(2));
+    assert_eq!(day["nba"][0]["id"], 1001);
+    Ok(())
+}
+
+#[test]
+fn test_cli_slate_color() -> Result<()> {
+    // Output that isn't a terminal is plain unless color is asked for
+    let colored = slate_output(&["--todays-games", "--leagues", "MLB", "--color", "always"])?;
+    assert!(colored.contains("  Home:  Chicago Cubs (\x1b[32m5\x1b[0m)"));
+    assert!(colored.contains("\x1b[2m  Away:  Chicago White Sox (3)\x1b[0m"));
+    assert!(!slate_output(&["--todays-games", "--leagues", "MLB"])?.contains('\x1b'));
+
+    // A JSON document never is
+    let json = slate_output(&["--format", "json", "--color", "always", "--todays-games"])?;
+    assert!(!json.contains('\x1b'));
+    serde_json::from_str::<serde_json::Value>(&json)?;
+    Ok(())
+}
+
+#[test]
+fn test_cli_slate_output() -> Result<()> {
+    assert_eq!(slate_output(&["--todays-games"])?, TODAYS_SLATE);
+    // The NHL is only listed when asked for
+    assert!(slate_output(&["--todays-games", "--leagues", "MLB,NBA,NHL"])?
+        .contains("Today's NHL Games:\nNo NHL games scheduled for today.\n"));
+    assert_eq!(slate_output(&["nba", "team", "--id", "14", "--schedule"])?, NBA_TEAM_SCHEDULE);
+    Ok(())
+}
+
+#[test]
+fn test_cli_nba_player_without_season_averages() -> Result<()> {
+    let mut nba_server = mockito::Server::new();
+    let player_mock = nba_server
+        .mock("GET", "/players/237")
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": 237, "first_name": "LeBron", "last_name": "James", "position": "F", "team": {"id": 14, "abbreviation": "LAL", "full_name": "Los Angeles Lakers"}}}"#)
+        .create();
+    let injuries_mock = nba_server
+        .mock("GET", "/player_injuries")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": [], "meta": {"per_page": 100}}"#)
+        .create();
+    let averages_mock = nba_server
+        .mock("GET", "/season_averages")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("season".into(), "2002".into()),
+            mockito::Matcher::UrlEncoded("player_ids[]".into(), "237".into()),
+        ]))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": []}"#)
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("NBA_API_BASE_URL", nba_server.url())
+        .env("NBA_API_KEY", "test-key")
+        .args(["nba", "player", "--id", "237", "--season", "2002"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("LeBron James"))
+        .stdout(predicate::str::contains("No 2002 season averages: LeBron James didn't play that season."));
+    player_mock.assert();
+    injuries_mock.assert();
+    averages_mock.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_nba_player_search() -> Result<()> {
+    let mut nba_server = mockito::Server::new();
+    let player = |id: u32, first: &str, last: &str, position: &str, team: &str| format!(
+        r#"{{"id": {id}, "first_name": "{first}", "last_name": "{last}", "position": "{position}", "team": {team}}}"#
+    );
+    let lakers = r#"{"id": 14, "abbreviation": "LAL", "full_name": "Los Angeles Lakers"}"#;
+    let first_page = nba_server
+        .mock("GET", "/players")
+        .match_query(mockito::Matcher::Regex("^search=james&per_page=100$".to_string()))
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"data": [{}, {}], "meta": {{"next_cursor": 7, "per_page": 100}}}}"#,
+            player(237, "LeBron", "James", "F", lakers),
+            player(1028029, "Bronny", "James", "G", lakers)
+        ))
+        .create();
+    let second_page = nba_server
+        .mock("GET", "/players")
+        .match_query(mockito::Matcher::Regex("cursor=7".to_string()))
+        .with_header("content-type", "application/json")
+        .with_body(format!(r#"{{"data": [{}], "meta": {{"per_page": 100}}}}"#, player(221, "Mike", "James", "", "null")))
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("NBA_API_BASE_URL", nba_server.url())
+        .env("NBA_API_KEY", "test-key")
+        .args(["nba", "player", "--search", " james "]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("237: LeBron James — Los Angeles Lakers (F)"))
+        .stdout(predicate::str::contains("1028029: Bronny James — Los Angeles Lakers (G)"))
+        .stdout(predicate::str::contains("221: Mike James — No team (-)"));
+    first_page.assert();
+    second_page.assert();
+
+    // Too short a search is rejected without a request
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("NBA_API_BASE_URL", nba_server.url())
+        .env("NBA_API_KEY", "test-key")
+        .args(["nba", "player", "--search", " j "]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Player search needs at least 2 characters, got 'j'"));
+    Ok(())
+}
+
+#[test]
+fn test_cli_scorebug_rejects_out_of_range_id() -> Result<()> {
+    // 2^32 + 1 would wrap around to game 1 if it were cut down to a u32
+    let mut mlb_server = mockito::Server::new();
+    let any_mock = mlb_server
+        .mock("GET", mockito::Matcher::Any)
+        .expect(0)
+        .create();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.env("MLB_API_BASE_URL", mlb_server.url())
+        .args(["scorebug", "--league", "mlb", "--id", "4294967297"]);
+    cmd.assert()
+        .stdout(predicate::str::contains("Invalid MLB game ID: 4294967297"));
+    any_mock.assert();
+    Ok(())
+}
+
+#[test]
+fn test_cli_scorebug_start_time_in_timezone() -> Result<()> {
+    let mut nba_server = mockito::Server::new();
+    let game_mock = nba_server
+        .mock("GET", "/games/1001")
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"id": 1001, "date": "2024-07-04", "status": "2024-07-04T23:05:00Z", "period": 0,
+            "home_team": {"id": 14, "abbreviation": "LAL"}, "visitor_team": {"id": 2, "abbreviation": "BOS"}}}"#)
+        .expect(2)
+        .create();
+
+    // A 7:05pm ET start shows in Pacific time when asked, and the flag works after the subcommand too
+    for args in [
+        vec!["--timezone", "America/Los_Angeles", "scorebug", "--league", "nba", "--id", "1001"],
+        vec!["scorebug", "--league", "nba", "--id", "1001", "--timezone", "America/Los_Angeles"],
+    ] {
+        let mut cmd = Command::cargo_bin("plaintext-sports")?;
+        cmd.env("NBA_API_BASE_URL", nba_server.url())
+            .env("NBA_API_KEY", "test-key")
+            .args(args);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("4:05 PM"));
+    }
+    game_mock.assert();
+
+    let mut cmd = Command::cargo_bin("plaintext-sports")?;
+    cmd.args(["--timezone", "Pacific", "--todays-games"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown timezone 'Pacific'"));
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use anyhow::Result;
-use plaintext_sports::mlb::{Game, Team, GameState, GameTeams, Venue};
+use plaintext_sports::mlb::{self, Game, Team, GameState, GameTeams, Venue};
+use plaintext_sports::types::Record;
+use plaintext_sports::TeamId;
 
 #[test]
 fn test_game_state_parsing() -> Result<()> {
@@ -7,6 +9,15 @@ fn test_game_state_parsing() -> Result<()> {
     assert_eq!(serde_json::from_str::<GameState>(r#""Live""#)?, GameState::Live);
     assert_eq!(serde_json::from_str::<GameState>(r#""Scheduled""#)?, GameState::Scheduled);
     assert_eq!(serde_json::from_str::<GameState>(r#""Unknown""#)?, GameState::Unknown);
+    assert_eq!(serde_json::from_str::<GameState>(r#""In Progress""#)?, GameState::InProgress);
+    
+    // Every state survives a round trip through its serialized form
+    for state in [GameState::Scheduled, GameState::Live, GameState::InProgress, GameState::Final, GameState::Postponed] {
+        assert_eq!(serde_json::from_str::<GameState>(&serde_json::to_string(&state)?)?, state);
+    }
+    assert!(GameState::InProgress.is_live() && GameState::InProgress.has_started());
+    assert!(!GameState::InProgress.is_final());
+    assert_eq!(GameState::InProgress.abstract_game_state(), "Live");
     Ok(())
 }
 
@@ -28,6 +39,7 @@ fn test_team_creation() -> Result<()> {
         venue: None,
         league: None,
         division: None,
+        record: None,
     };
     
     assert_eq!(team.name, "New York Yankees");
@@ -54,6 +66,7 @@ fn test_game_creation() -> Result<()> {
         venue: None,
         league: None,
         division: None,
+        record: None,
     };
     
     let away_team = Team {
@@ -72,6 +85,7 @@ fn test_game_creation() -> Result<()> {
         venue: None,
         league: None,
         division: None,
+        record: None,
     };
     
     let game = Game {
@@ -83,17 +97,28 @@ fn test_game_creation() -> Result<()> {
                 score: Some(6),
                 team: home_team,
                 is_winner: Some(true),
+                probable_pitcher: None,
             },
             away: plaintext_sports::mlb::GameTeam {
                 score: Some(5),
                 team: away_team,
                 is_winner: Some(false),
+                probable_pitcher: None,
             },
         },
         venue: Some(Venue {
             id: 1,
             name: String::from("Fenway Park"),
+            city: None,
+            state: None,
+            timezone: None,
         }),
+        series_game_number: None,
+        games_in_series: None,
+        game_number: None,
+        doubleheader: false,
+        current_inning: None,
+        game_type: None,
     };
     
     assert_eq!(game.game_pk, 12345);
@@ -101,4 +126,37 @@ fn test_game_creation() -> Result<()> {
     assert_eq!(game.teams.home.score, Some(6));
     assert_eq!(game.teams.away.score, Some(5));
     Ok(())
-} 
\ No newline at end of file
+} 
+#[tokio::test]
+async fn test_get_team_by_typed_id() -> Result<()> {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/teams/145")
+        .match_query(mockito::Matcher::UrlEncoded("hydrate".into(), "venue(location,timezone)".into()))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"teams": [{"id": 145, "name": "Chicago White Sox", "abbreviation": "CWS"}]}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    // The record comes from the standings of both leagues, since the team's league isn't known
+    let standings = server
+        .mock("GET", "/standings")
+        .match_query(mockito::Matcher::UrlEncoded("leagueId".into(), "103,104".into()))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"records": [{"division": {"name": "American League Central"}, "teamRecords": [
+            {"team": {"id": 145, "name": "Chicago White Sox"}, "wins": 41, "losses": 121, "gamesBack": "58.0"}
+        ]}]}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    std::env::set_var("MLB_API_BASE_URL", server.url());
+    
+    // A TeamId and a bare u32 reach the same team
+    let team = mlb::get_team(TeamId(145)).await?;
+    assert_eq!(team.name, "Chicago White Sox");
+    assert_eq!(team.record, Some(Record::new(41, 121)));
+    assert_eq!(mlb::get_team(145).await?.id, team.id);
+    mock.assert_async().await;
+    standings.assert_async().await;
+    Ok(())
+}
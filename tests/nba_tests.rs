@@ -1,7 +1,10 @@
 use anyhow::Result;
-use plaintext_sports::nba::{Game, Team};
+use chrono::NaiveDate;
+use plaintext_sports::clock::FixedClock;
+use plaintext_sports::nba::{Game, NbaApi, Team};
 use plaintext_sports::mlb::GameState;
 use serde_json::json;
+use std::sync::Arc;
 
 #[test]
 fn test_nba_game_status_parsing() -> Result<()> {
@@ -15,7 +18,7 @@ fn test_nba_game_status_parsing() -> Result<()> {
     assert_eq!(live_state, GameState::Live);
     assert_eq!(scheduled_state, GameState::Scheduled);
     assert_eq!(unknown_state, GameState::Unknown);
-    assert_eq!(in_progress_state, GameState::Unknown); // "In Progress" is mapped to Unknown
+    assert_eq!(in_progress_state, GameState::InProgress);
     Ok(())
 }
 
@@ -72,6 +75,7 @@ fn test_nba_game_creation() -> Result<()> {
         time: None,
         visitor_team,
         visitor_team_score: 110,
+        quarters: Default::default(),
     };
     
     assert_eq!(game.id, 12345);
@@ -79,4 +83,31 @@ fn test_nba_game_creation() -> Result<()> {
     assert_eq!(game.home_team_score, 115);
     assert_eq!(game.visitor_team_score, 110);
     Ok(())
-} 
\ No newline at end of file
+} 
+#[tokio::test]
+async fn test_games_use_injected_clock() -> Result<()> {
+    let mut server = mockito::Server::new_async().await;
+    let mut games = |date: &str| {
+        server
+            .mock("GET", "/games")
+            .match_query(mockito::Matcher::UrlEncoded("dates[]".into(), date.into()))
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": [], "meta": {"per_page": 100}}"#)
+            .expect(1)
+            .create()
+    };
+    // Yesterday crosses into February of a leap year
+    let today_mock = games("2024-03-01");
+    let yesterday_mock = games("2024-02-29");
+
+    // The shared configuration reads these on first use
+    std::env::set_var("NBA_API_BASE_URL", server.url());
+    std::env::set_var("NBA_API_KEY", "test-key");
+
+    let api = NbaApi::with_clock(Arc::new(FixedClock(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap())))?;
+    api.get_todays_games().await?;
+    api.get_yesterdays_games().await?;
+    today_mock.assert_async().await;
+    yesterday_mock.assert_async().await;
+    Ok(())
+}
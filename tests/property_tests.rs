@@ -28,8 +28,11 @@ proptest! {
         prop_assert!(avg.is_ok());
         let avg = avg.unwrap();
         let formatted = avg.format();
-        prop_assert!(formatted.starts_with('.'));
-        prop_assert_eq!(formatted.len(), 4);
+        // Anything that rounds up to a perfect average keeps its leading digit
+        if formatted != "1.000" {
+            prop_assert!(formatted.starts_with('.'));
+            prop_assert_eq!(formatted.len(), 4);
+        }
     }
 
     #[test]
@@ -81,11 +84,14 @@ proptest! {
         max_len in 1usize..100
     ) {
         let truncated = truncate_string(&s, max_len);
-        prop_assert!(truncated.len() <= max_len);
-        if s.len() <= max_len {
+        prop_assert!(truncated.chars().count() <= max_len);
+        if s.chars().count() <= max_len {
             prop_assert_eq!(truncated, s);
-        } else {
+        } else if max_len >= 3 {
             prop_assert!(truncated.ends_with("..."));
+        } else {
+            // Too short for the ellipsis, so just the leading characters
+            prop_assert!(s.starts_with(&truncated));
         }
     }
 
@@ -140,7 +146,7 @@ proptest! {
     fn test_average_roundtrip(avg in 0.0f32..=1.0f32) {
         let average = Average::new(avg).unwrap();
         let formatted = average.format();
-        let parsed = formatted[1..].parse::<f32>().unwrap() / 1000.0;
+        let parsed = formatted.parse::<f32>().unwrap();
         prop_assert!((avg - parsed).abs() < 0.001);
     }
 
@@ -169,8 +175,10 @@ proptest! {
         prop_assert!(truncated.chars().count() <= max_len);
         if s.chars().count() <= max_len {
             prop_assert_eq!(truncated, s);
-        } else {
+        } else if max_len >= 3 {
             prop_assert!(truncated.ends_with("..."));
+        } else {
+            prop_assert_eq!(truncated, s.chars().take(max_len).collect::<String>());
         }
     }
 }
@@ -258,8 +266,9 @@ fn test_string_manipulation_edge_cases() {
     // Test exact length
     assert_eq!(truncate_string("12345", 5), "12345");
     
-    // Test unicode characters
-    assert_eq!(truncate_string("🏆⚾🎯", 2), "🏆...");
+    // Test unicode characters, with no room for an ellipsis
+    assert_eq!(truncate_string("🏆⚾🎯", 2), "🏆⚾");
+    assert_eq!(truncate_string("🏆⚾🎯⛳", 3), "...");
     
     // Test mixed ASCII and unicode
     assert_eq!(truncate_string("MLB⚾2024", 6), "MLB...");